@@ -19,6 +19,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use astar_primitives::{
+    ethereum_checked::AccessList,
     xvm::{FailureError, FailureReason, FailureRevert},
     Balance,
 };
@@ -49,6 +50,7 @@ impl From<FailureReason> for XvmExecutionResult {
             FailureReason::Error(FailureError::ReentranceDenied) => 130,
             FailureReason::Error(FailureError::VmError(_)) => 131,
             FailureReason::Error(FailureError::OutOfGas) => 132,
+            FailureReason::Error(FailureError::StateChangeDenied) => 133,
         };
         Self::Err(error_code)
     }
@@ -74,3 +76,27 @@ pub struct XvmCallArgs {
     /// Value to transfer
     pub value: Balance,
 }
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug)]
+pub struct XvmCallWithAccessListArgs {
+    /// virtual machine identifier
+    pub vm_id: u8,
+    /// Call destination (e.g. address)
+    pub to: Vec<u8>,
+    /// Encoded call params
+    pub input: Vec<u8>,
+    /// Value to transfer
+    pub value: Balance,
+    /// EIP-2930 access list, only used by EVM calls
+    pub access_list: AccessList,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug)]
+pub struct XvmStaticCallArgs {
+    /// virtual machine identifier
+    pub vm_id: u8,
+    /// Call destination (e.g. address)
+    pub to: Vec<u8>,
+    /// Encoded call params
+    pub input: Vec<u8>,
+}
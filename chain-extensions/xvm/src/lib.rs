@@ -34,10 +34,14 @@ use pallet_unified_accounts::WeightInfo;
 use parity_scale_codec::Encode;
 use sp_runtime::DispatchError;
 use sp_std::marker::PhantomData;
-use xvm_chain_extension_types::{XvmCallArgs, XvmExecutionResult};
+use xvm_chain_extension_types::{
+    XvmCallArgs, XvmCallWithAccessListArgs, XvmExecutionResult, XvmStaticCallArgs,
+};
 
 enum XvmFuncId {
     Call,
+    StaticCall,
+    CallWithAccessList,
 }
 
 impl TryFrom<u16> for XvmFuncId {
@@ -46,6 +50,8 @@ impl TryFrom<u16> for XvmFuncId {
     fn try_from(value: u16) -> Result<Self, Self::Error> {
         match value {
             1 => Ok(XvmFuncId::Call),
+            2 => Ok(XvmFuncId::StaticCall),
+            3 => Ok(XvmFuncId::CallWithAccessList),
             _ => Err(DispatchError::Other(
                 "Unsupported func id in Xvm chain extension",
             )),
@@ -75,18 +81,33 @@ where
         let func_id = env.func_id().try_into()?;
         let mut env = env.buf_in_buf_out();
 
-        match func_id {
-            XvmFuncId::Call => {
+        let (charged_weight, mut actual_weight, call_result) = match func_id {
+            XvmFuncId::Call | XvmFuncId::CallWithAccessList => {
                 // We need to immediately charge for the worst case scenario. Gas equals Weight in pallet-contracts context.
                 let weight_limit = env.ext().gas_meter().gas_left();
                 let charged_weight = env.charge_weight(weight_limit)?;
 
-                let XvmCallArgs {
-                    vm_id,
-                    to,
-                    input,
-                    value,
-                } = env.read_as_unbounded(env.in_len())?;
+                let (vm_id, to, input, value, access_list) = match func_id {
+                    XvmFuncId::CallWithAccessList => {
+                        let XvmCallWithAccessListArgs {
+                            vm_id,
+                            to,
+                            input,
+                            value,
+                            access_list,
+                        } = env.read_as_unbounded(env.in_len())?;
+                        (vm_id, to, input, value, Some(access_list))
+                    }
+                    _ => {
+                        let XvmCallArgs {
+                            vm_id,
+                            to,
+                            input,
+                            value,
+                        } = env.read_as_unbounded(env.in_len())?;
+                        (vm_id, to, input, value, None)
+                    }
+                };
 
                 // Similar to EVM behavior, the `source` should be (limited to) the
                 // contract address. Otherwise contracts would be able to do arbitrary
@@ -141,41 +162,82 @@ where
                         }
                     }
                 };
-                let call_result = XC::call(xvm_context, vm_id, source, to, input, value, None);
+                let call_result = XC::call(
+                    xvm_context,
+                    vm_id,
+                    source,
+                    to,
+                    input,
+                    value,
+                    None,
+                    access_list,
+                );
 
-                let used_weight = match call_result {
-                    Ok(ref info) => info.used_weight,
-                    Err(ref err) => err.used_weight,
+                (charged_weight, actual_weight, call_result)
+            }
+            XvmFuncId::StaticCall => {
+                // We need to immediately charge for the worst case scenario. Gas equals Weight in pallet-contracts context.
+                let weight_limit = env.ext().gas_meter().gas_left();
+                let charged_weight = env.charge_weight(weight_limit)?;
+
+                let XvmStaticCallArgs { vm_id, to, input } = env.read_as_unbounded(env.in_len())?;
+
+                // Same as `Call`, the `source` is limited to the contract address.
+                // No value is transferred, so there is no need to claim the default EVM address.
+                let source = env.ext().address().clone();
+
+                let xvm_context = Context {
+                    source_vm_id: VmId::Wasm,
+                    weight_limit,
                 };
-                actual_weight.saturating_accrue(used_weight);
-                env.adjust_weight(charged_weight, actual_weight);
-
-                match call_result {
-                    Ok(info) => {
-                        log::trace!(
-                            target: "xvm-extension::xvm_call",
-                            "info: {:?}", info
-                        );
-
-                        let buffer: sp_std::vec::Vec<_> = info.output.encode();
-                        env.write(&buffer, false, None)?;
-                        Ok(RetVal::Converging(XvmExecutionResult::Ok.into()))
+                let vm_id = {
+                    match TryInto::<VmId>::try_into(vm_id) {
+                        Ok(id) => id,
+                        Err(err) => {
+                            return Ok(RetVal::Diverging {
+                                flags: ReturnFlags::REVERT,
+                                data: format!("{:?}", err).into(),
+                            });
+                        }
                     }
+                };
+                let call_result = XC::static_call(xvm_context, vm_id, source, to, input);
 
-                    Err(err) => {
-                        log::trace!(
-                            target: "xvm-extension::xvm_call",
-                            "err: {:?}", err
-                        );
-
-                        // `Diverging` is used instead of `Err` to make sure the control
-                        // doesn't return to the caller.
-                        Ok(RetVal::Diverging {
-                            flags: ReturnFlags::REVERT,
-                            data: format!("{:?}", err).into(),
-                        })
-                    }
-                }
+                (charged_weight, Weight::zero(), call_result)
+            }
+        };
+
+        let used_weight = match call_result {
+            Ok(ref info) => info.used_weight,
+            Err(ref err) => err.used_weight,
+        };
+        actual_weight.saturating_accrue(used_weight);
+        env.adjust_weight(charged_weight, actual_weight);
+
+        match call_result {
+            Ok(info) => {
+                log::trace!(
+                    target: "xvm-extension::xvm_call",
+                    "info: {:?}", info
+                );
+
+                let buffer: sp_std::vec::Vec<_> = info.output.encode();
+                env.write(&buffer, false, None)?;
+                Ok(RetVal::Converging(XvmExecutionResult::Ok.into()))
+            }
+
+            Err(err) => {
+                log::trace!(
+                    target: "xvm-extension::xvm_call",
+                    "err: {:?}", err
+                );
+
+                // `Diverging` is used instead of `Err` to make sure the control
+                // doesn't return to the caller.
+                Ok(RetVal::Diverging {
+                    flags: ReturnFlags::REVERT,
+                    data: format!("{:?}", err).into(),
+                })
             }
         }
     }
@@ -19,10 +19,11 @@
 //! Chain specifications.
 
 use local_runtime::{
-    wasm_binary_unwrap, AccountId, AuraConfig, AuraId, BalancesConfig,
-    CommunityCouncilMembershipConfig, CommunityTreasuryPalletId, CouncilMembershipConfig,
-    EVMConfig, GrandpaConfig, GrandpaId, InflationConfig, InflationParameters,
-    Precompiles, RuntimeGenesisConfig, Signature, SudoConfig,
+    collective_proxy::{CollectiveProxyCallFilter, CollectiveProxyOrigin},
+    wasm_binary_unwrap, AccountId, AuraConfig, AuraId, BalancesConfig, CollectiveProxyConfig,
+    CommunityCouncilMembershipConfig, CommunityTreasuryAccountId, CommunityTreasuryPalletId,
+    CouncilMembershipConfig, EVMConfig, GrandpaConfig, GrandpaId, InflationConfig,
+    InflationParameters, Precompiles, RuntimeGenesisConfig, Signature, SudoConfig,
     TechnicalCommitteeMembershipConfig, TreasuryPalletId, VestingConfig, AST,
 };
 use sc_service::ChainType;
@@ -174,9 +175,18 @@ fn testnet_genesis(
         council: Default::default(),
         technical_committee: Default::default(),
         community_council: Default::default(),
+        signed_transact_membership: Default::default(),
         democracy: Default::default(),
         treasury: Default::default(),
         community_treasury: Default::default(),
+        collective_proxy: CollectiveProxyConfig {
+            aliases: vec![(
+                0,
+                CommunityTreasuryAccountId::get(),
+                CollectiveProxyOrigin::CommunityCouncil,
+                CollectiveProxyCallFilter::CommunityCouncil,
+            )],
+        },
     };
     serde_json::to_value(&config).expect("Could not build genesis config.")
 }
@@ -190,4 +200,18 @@ pub(crate) mod tests {
     fn test_create_development_chain_spec() {
         development_config().build_storage().unwrap();
     }
+
+    #[test]
+    fn test_development_genesis_skips_democracy_migration() {
+        use frame_support::traits::GetStorageVersion;
+        use local_runtime::{governance::pallet_custom_origins, Origins};
+
+        let storage = development_config().build_storage().unwrap();
+        sp_io::TestExternalities::from(storage).execute_with(|| {
+            assert_eq!(
+                Origins::on_chain_storage_version(),
+                pallet_custom_origins::STORAGE_VERSION
+            );
+        });
+    }
 }
@@ -55,8 +55,15 @@ use moonbeam_rpc_txpool::{TxPool as MoonbeamTxPool, TxPoolServer};
 
 use astar_primitives::*;
 
+pub mod base_fee;
+pub mod inflation;
 #[cfg(feature = "evm-tracing")]
 pub mod tracing;
+pub mod unified_accounts;
+
+use base_fee::{BaseFee, BaseFeeApiServer};
+use inflation::{Inflation, InflationRpcApiServer};
+use unified_accounts::{UnifiedAccounts, UnifiedAccountsApiServer};
 
 #[cfg(feature = "evm-tracing")]
 #[derive(Clone)]
@@ -170,6 +177,9 @@ where
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
+        + unified_accounts_runtime_api::UnifiedAccountsApi<Block, AccountId, BlockNumber>
+        + dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block>
+        + inflation_runtime_api::InflationApi<Block>
         + AuraApi<Block, AuraId>
         + moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
         + moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>,
@@ -235,6 +245,9 @@ where
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
+        + unified_accounts_runtime_api::UnifiedAccountsApi<Block, AccountId, BlockNumber>
+        + dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block>
+        + inflation_runtime_api::InflationApi<Block>
         + AuraApi<Block, AuraId>,
     P: TransactionPool<Block = Block> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
@@ -272,6 +285,9 @@ where
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
+        + unified_accounts_runtime_api::UnifiedAccountsApi<Block, AccountId, BlockNumber>
+        + dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block>
+        + inflation_runtime_api::InflationApi<Block>
         + AuraApi<Block, AuraId>,
     P: TransactionPool<Block = Block> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
@@ -301,6 +317,9 @@ where
 
     io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    io.merge(UnifiedAccounts::new(client.clone()).into_rpc())?;
+    io.merge(BaseFee::new(client.clone(), fee_history_limit).into_rpc())?;
+    io.merge(Inflation::new(client.clone()).into_rpc())?;
     io.merge(sc_rpc::dev::Dev::new(client.clone(), deny_unsafe).into_rpc())?;
 
     #[cfg(feature = "manual-seal")]
@@ -0,0 +1,144 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Dynamic EVM base fee RPC support.
+use astar_primitives::{Block, Hash};
+use dynamic_evm_base_fee_runtime_api::{BaseFeeInfo, DynamicEvmBaseFeeApi};
+use fc_rpc::{err, internal_err};
+use fp_rpc::EthereumRuntimeRPCApi;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::INVALID_PARAMS_CODE};
+use pallet_ethereum::Transaction;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::U256;
+use sp_runtime::traits::{Header as HeaderT, Zero};
+use std::sync::Arc;
+
+#[rpc(server)]
+pub trait BaseFeeApi {
+    /// Current 'base fee per gas', its predicted next value & the bounds in force.
+    #[method(name = "baseFee_info")]
+    fn info(&self, at: Option<Hash>) -> RpcResult<BaseFeeInfo>;
+
+    /// Priority fee per gas paid at the given `percentile` by the transactions
+    /// of the last `block_count` blocks.
+    #[method(name = "baseFee_priorityFee")]
+    fn priority_fee(&self, block_count: u64, percentile: u8, at: Option<Hash>) -> RpcResult<U256>;
+}
+
+pub struct BaseFee<C> {
+    client: Arc<C>,
+    max_block_count: u64,
+}
+
+impl<C> BaseFee<C> {
+    pub fn new(client: Arc<C>, max_block_count: u64) -> Self {
+        Self {
+            client,
+            max_block_count,
+        }
+    }
+}
+
+/// Priority fee per gas paid by `transaction`, on top of the `base_fee`.
+fn effective_priority_fee(transaction: &Transaction, base_fee: U256) -> U256 {
+    match transaction {
+        Transaction::Legacy(t) => t.gas_price.saturating_sub(base_fee),
+        Transaction::EIP2930(t) => t.gas_price.saturating_sub(base_fee),
+        Transaction::EIP1559(t) => t
+            .max_priority_fee_per_gas
+            .min(t.max_fee_per_gas.saturating_sub(base_fee)),
+    }
+}
+
+impl<C> BaseFeeApiServer for BaseFee<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DynamicEvmBaseFeeApi<Block> + EthereumRuntimeRPCApi<Block>,
+{
+    fn info(&self, at: Option<Hash>) -> RpcResult<BaseFeeInfo> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .base_fee_info(at)
+            .map_err(|e| internal_err(format!("Runtime error: {:?}", e)))
+    }
+
+    fn priority_fee(&self, block_count: u64, percentile: u8, at: Option<Hash>) -> RpcResult<U256> {
+        if percentile > 100 {
+            return Err(err(
+                INVALID_PARAMS_CODE,
+                "Percentile must be in range [0, 100]",
+                None,
+            ));
+        }
+        if block_count.is_zero() || block_count > self.max_block_count {
+            return Err(err(
+                INVALID_PARAMS_CODE,
+                format!("Block count must be in range [1, {}]", self.max_block_count),
+                None,
+            ));
+        }
+
+        let api = self.client.runtime_api();
+        let mut hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let mut priority_fees = Vec::new();
+
+        for _ in 0..block_count {
+            let header = match self
+                .client
+                .header(hash)
+                .map_err(|e| internal_err(format!("Client error: {:?}", e)))?
+            {
+                Some(header) => header,
+                None => break,
+            };
+            // Transactions of the block paid the base fee set by its parent
+            let parent_hash = *header.parent_hash();
+
+            if let Some(block) = api
+                .current_block(hash)
+                .map_err(|e| internal_err(format!("Runtime error: {:?}", e)))?
+            {
+                let base_fee = api
+                    .gas_price(parent_hash)
+                    .map_err(|e| internal_err(format!("Runtime error: {:?}", e)))?;
+                priority_fees.extend(
+                    block
+                        .transactions
+                        .iter()
+                        .map(|t| effective_priority_fee(t, base_fee)),
+                );
+            }
+
+            if header.number().is_zero() {
+                break;
+            }
+            hash = parent_hash;
+        }
+
+        if priority_fees.is_empty() {
+            return Ok(U256::zero());
+        }
+
+        priority_fees.sort_unstable();
+        let index = (priority_fees.len() - 1) * usize::from(percentile) / 100;
+        Ok(priority_fees[index])
+    }
+}
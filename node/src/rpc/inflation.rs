@@ -0,0 +1,102 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Inflation RPC support.
+use astar_primitives::{Balance, Block, Hash};
+use fc_rpc::internal_err;
+use inflation_runtime_api::{CycleNumber, InflationApi, InflationInfo};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::Perquintill;
+use std::sync::Arc;
+
+#[rpc(server)]
+pub trait InflationRpcApi {
+    /// Active config & params, current era & cycle and the blocks remaining until the recalculation.
+    #[method(name = "inflation_info")]
+    fn info(&self, at: Option<Hash>) -> RpcResult<InflationInfo>;
+
+    /// Max inflation rates of the `count` cycles following the current one.
+    #[method(name = "inflation_upcomingRates")]
+    fn upcoming_rates(
+        &self,
+        count: u32,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<(CycleNumber, Perquintill)>>;
+
+    /// Projected total issuance at the end of the `count` cycles following the current one,
+    /// assuming max emission.
+    #[method(name = "inflation_projectedIssuance")]
+    fn projected_issuance(
+        &self,
+        count: u32,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<(CycleNumber, Balance)>>;
+}
+
+pub struct Inflation<C> {
+    client: Arc<C>,
+}
+
+impl<C> Inflation<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> InflationRpcApiServer for Inflation<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: InflationApi<Block>,
+{
+    fn info(&self, at: Option<Hash>) -> RpcResult<InflationInfo> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .inflation_info(at)
+            .map_err(|e| internal_err(format!("Runtime error: {:?}", e)))
+    }
+
+    fn upcoming_rates(
+        &self,
+        count: u32,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<(CycleNumber, Perquintill)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .upcoming_inflation_rates(at, count)
+            .map_err(|e| internal_err(format!("Runtime error: {:?}", e)))
+    }
+
+    fn projected_issuance(
+        &self,
+        count: u32,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<(CycleNumber, Balance)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .projected_issuance(at, count)
+            .map_err(|e| internal_err(format!("Runtime error: {:?}", e)))
+    }
+}
@@ -0,0 +1,121 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Unified accounts RPC support.
+use astar_primitives::{evm::EvmAddress, AccountId, Block, BlockNumber, Hash};
+use fc_rpc::{err, internal_err};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::INVALID_PARAMS_CODE};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use std::sync::Arc;
+use unified_accounts_runtime_api::{
+    AccountMapping, UnifiedAccountsApi as UnifiedAccountsRuntimeApi,
+};
+
+#[rpc(server)]
+pub trait UnifiedAccountsApi {
+    /// EIP-712 typed data of the claim payload for `account_id`,
+    /// which can be passed to `eth_signTypedData_v4` as is.
+    #[method(name = "unifiedAccounts_claimTypedData")]
+    fn claim_typed_data(
+        &self,
+        account_id: AccountId,
+        at: Option<Hash>,
+    ) -> RpcResult<serde_json::Value>;
+
+    /// Recover the evm address which signed the claim payload of `account_id`.
+    #[method(name = "unifiedAccounts_verifyClaimSignature")]
+    fn verify_claim_signature(
+        &self,
+        account_id: AccountId,
+        signature: Bytes,
+        at: Option<Hash>,
+    ) -> RpcResult<Option<EvmAddress>>;
+
+    /// The current mapping state of `account_id`.
+    #[method(name = "unifiedAccounts_accountMapping")]
+    fn account_mapping(
+        &self,
+        account_id: AccountId,
+        at: Option<Hash>,
+    ) -> RpcResult<AccountMapping<BlockNumber>>;
+}
+
+pub struct UnifiedAccounts<C> {
+    client: Arc<C>,
+}
+
+impl<C> UnifiedAccounts<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> UnifiedAccountsApiServer for UnifiedAccounts<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: UnifiedAccountsRuntimeApi<Block, AccountId, BlockNumber>,
+{
+    fn claim_typed_data(
+        &self,
+        account_id: AccountId,
+        at: Option<Hash>,
+    ) -> RpcResult<serde_json::Value> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let typed_data = self
+            .client
+            .runtime_api()
+            .claim_typed_data(at, account_id)
+            .map_err(|e| internal_err(format!("Runtime error: {:?}", e)))?;
+
+        serde_json::from_slice(&typed_data)
+            .map_err(|e| internal_err(format!("Malformed typed data: {:?}", e)))
+    }
+
+    fn verify_claim_signature(
+        &self,
+        account_id: AccountId,
+        signature: Bytes,
+        at: Option<Hash>,
+    ) -> RpcResult<Option<EvmAddress>> {
+        let signature: [u8; 65] = signature
+            .0
+            .try_into()
+            .map_err(|_| err(INVALID_PARAMS_CODE, "Signature must be 65 bytes long", None))?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .verify_claim_signature(at, account_id, signature)
+            .map_err(|e| internal_err(format!("Runtime error: {:?}", e)))
+    }
+
+    fn account_mapping(
+        &self,
+        account_id: AccountId,
+        at: Option<Hash>,
+    ) -> RpcResult<AccountMapping<BlockNumber>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .account_mapping(at, account_id)
+            .map_err(|e| internal_err(format!("Runtime error: {:?}", e)))
+    }
+}
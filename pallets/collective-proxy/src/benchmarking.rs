@@ -25,23 +25,172 @@ pub(super) fn assert_last_event<T: Config>(generic_event: <T as Config>::Runtime
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Create alias `0` with `caps` spending caps, each with a spent amount in the current period.
+fn create_alias<T: Config>(caps: u32) -> T::AliasOrigin {
+    let (origin, filter) = T::BenchmarkHelper::alias_origin_and_filter();
+    let spending_caps: Vec<_> = (0..caps)
+        .map(|i| {
+            (
+                T::BenchmarkHelper::asset_kind(i),
+                SpendingCap {
+                    amount: 1_000_000u32.into(),
+                    period: 10u32.into(),
+                },
+            )
+        })
+        .collect();
+    for (asset_kind, _) in &spending_caps {
+        Spent::<T>::insert(0, asset_kind, SpentInPeriod::default());
+    }
+
+    Aliases::<T>::insert(
+        0,
+        ProxyAlias {
+            account: account("alias", 0, 0),
+            origin: origin.clone(),
+            filter,
+            spending_caps: spending_caps
+                .try_into()
+                .expect("Must not exceed the maximum number of spending caps."),
+        },
+    );
+
+    origin
+}
+
 #[benchmarks()]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
     fn execute_call() {
-        let origin = T::CollectiveProxy::try_successful_origin()
+        let alias_origin = create_alias::<T>(0);
+        let origin = T::ProxyOrigin::try_successful_origin(&alias_origin)
             .expect("Must succeed in order to run benchmarks.");
 
-        // A bit dirty, but runtime should ensure to allow the `remark` call.
+        // A bit dirty, but runtime should ensure the alias filter allows the `remark` call.
         let call: <T as Config>::RuntimeCall =
             frame_system::Call::<T>::remark { remark: vec![] }.into();
+        let call_hash = T::Hashing::hash_of(&call);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0, Box::new(call));
+
+        assert_last_event::<T>(
+            Event::<T>::CollectiveProxyExecuted {
+                alias: 0,
+                call_hash,
+                result: Ok(()),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn allow_contract_call() {
+        let origin = T::ContractCallManager::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        create_alias::<T>(0);
+        let target = ContractTarget::Evm(H160::repeat_byte(0x01));
+        let selector = [0xAB; 4];
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0, target.clone(), selector);
+
+        assert_last_event::<T>(
+            Event::<T>::ContractCallAllowed {
+                alias: 0,
+                target,
+                selector,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn disallow_contract_call() {
+        let origin = T::ContractCallManager::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let target = ContractTarget::Evm(H160::repeat_byte(0x01));
+        let selector = [0xAB; 4];
+        AllowedContractCalls::<T>::insert((0, &target, selector), ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0, target.clone(), selector);
+
+        assert_last_event::<T>(
+            Event::<T>::ContractCallDisallowed {
+                alias: 0,
+                target,
+                selector,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_alias() {
+        let origin = T::AliasManager::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let alias_origin = create_alias::<T>(T::MaxSpendingCaps::get());
+        let (_, filter) = T::BenchmarkHelper::alias_origin_and_filter();
+        let account: T::AccountId = account("alias", 1, 0);
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            0,
+            account.clone(),
+            alias_origin.clone(),
+            filter.clone(),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::AliasSet {
+                alias: 0,
+                account,
+                origin: alias_origin,
+                filter,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn remove_alias(c: Linear<0, { T::MaxSpendingCaps::get() }>) {
+        let origin = T::AliasManager::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        create_alias::<T>(c);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0);
+
+        assert_last_event::<T>(Event::<T>::AliasRemoved { alias: 0 }.into());
+    }
+
+    #[benchmark]
+    fn set_spending_cap() {
+        let origin = T::AliasManager::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let max_caps = T::MaxSpendingCaps::get();
+        create_alias::<T>(max_caps.saturating_sub(1));
+        let asset_kind = T::BenchmarkHelper::asset_kind(max_caps);
+        let cap = Some(SpendingCap {
+            amount: 1_000_000u32.into(),
+            period: 10u32.into(),
+        });
 
         #[extrinsic_call]
-        _(origin as T::RuntimeOrigin, Box::new(call));
+        _(origin as T::RuntimeOrigin, 0, asset_kind.clone(), cap);
 
-        assert_last_event::<T>(Event::<T>::CollectiveProxyExecuted { result: Ok(()) }.into());
+        assert_last_event::<T>(
+            Event::<T>::SpendingCapSet {
+                alias: 0,
+                asset_kind,
+                cap,
+            }
+            .into(),
+        );
     }
 
     impl_benchmark_test_suite!(
@@ -21,10 +21,11 @@
 use frame_support::{
     dispatch::GetDispatchInfo,
     pallet_prelude::*,
-    traits::{InstanceFilter, IsType, OriginTrait},
+    traits::{tokens, EnsureOriginWithArg, InstanceFilter, IsType, OriginTrait},
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::traits::Dispatchable;
+use sp_core::H160;
+use sp_runtime::traits::{Dispatchable, Hash, MaybeSerializeDeserialize, Saturating, Zero};
 use sp_std::prelude::*;
 
 pub use pallet::*;
@@ -40,6 +41,106 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Contract function selector, i.e. the first 4 bytes of the call input.
+pub type Selector = [u8; 4];
+
+/// Identifier of an aliased account.
+pub type AliasId = u32;
+
+/// Smart contract which can be called on behalf of the collective.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ContractTarget<AccountId> {
+    /// EVM contract address.
+    Evm(H160),
+    /// Wasm contract address.
+    Wasm(AccountId),
+}
+
+/// Maximum amount of an asset an alias can transfer per period.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SpendingCap<Balance, BlockNumber> {
+    /// Maximum amount which can be transferred during a period.
+    pub amount: Balance,
+    /// Length of a period, in blocks.
+    pub period: BlockNumber,
+}
+
+/// Amount of an asset transferred by an alias during a period.
+#[derive(
+    PartialEq, Eq, Clone, Copy, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct SpentInPeriod<Balance, BlockNumber> {
+    /// Index of the period, i.e. block number divided by the period length.
+    pub period: BlockNumber,
+    /// Amount transferred during the period.
+    pub amount: Balance,
+}
+
+pub type SpendingCapOf<T> = SpendingCap<<T as Config>::Balance, BlockNumberFor<T>>;
+pub type SpentInPeriodOf<T> = SpentInPeriod<<T as Config>::Balance, BlockNumberFor<T>>;
+
+/// Account on whose behalf an origin can dispatch calls.
+#[derive(
+    PartialEqNoBound,
+    EqNoBound,
+    CloneNoBound,
+    Encode,
+    Decode,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct ProxyAlias<T: Config> {
+    /// Aliased account, used as the signed origin of the dispatched calls.
+    pub account: T::AccountId,
+    /// Origin which can dispatch calls on behalf of the aliased account.
+    pub origin: T::AliasOrigin,
+    /// Filter of the calls which can be dispatched.
+    pub filter: T::CallFilter,
+    /// Spending caps per asset kind. Transfers of other assets are not capped.
+    pub spending_caps: BoundedVec<(T::AssetKind, SpendingCapOf<T>), T::MaxSpendingCaps>,
+}
+
+/// Inspect runtime calls which call smart contracts.
+pub trait InspectContractCall<RuntimeCall, AccountId> {
+    /// Target contract and call input, if `call` is a smart contract call.
+    fn inspect(call: &RuntimeCall) -> Option<(ContractTarget<AccountId>, &[u8])>;
+
+    /// Number of calls checked against the allowed contract calls when `call` is dispatched,
+    /// i.e. `call` itself and the calls nested in it, e.g. in batches.
+    fn checked_calls(_call: &RuntimeCall) -> u32 {
+        1
+    }
+}
+
+impl<RuntimeCall, AccountId> InspectContractCall<RuntimeCall, AccountId> for () {
+    fn inspect(_call: &RuntimeCall) -> Option<(ContractTarget<AccountId>, &[u8])> {
+        None
+    }
+}
+
+/// Inspect runtime calls which transfer native currency or assets.
+pub trait InspectTransfer<RuntimeCall, AssetKind, Balance> {
+    /// Asset kinds & amounts transferred by `call`, including its nested calls.
+    fn inspect(call: &RuntimeCall) -> Vec<(AssetKind, Balance)>;
+}
+
+impl<RuntimeCall, AssetKind, Balance> InspectTransfer<RuntimeCall, AssetKind, Balance> for () {
+    fn inspect(_call: &RuntimeCall) -> Vec<(AssetKind, Balance)> {
+        Vec::new()
+    }
+}
+
+/// Values needed to benchmark the pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AliasOrigin, CallFilter, AssetKind> {
+    /// Alias origin & a call filter allowing `frame_system::remark`.
+    fn alias_origin_and_filter() -> (AliasOrigin, CallFilter);
+    /// Asset kind identified by `seed`.
+    fn asset_kind(seed: u32) -> AssetKind;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -47,11 +148,6 @@ pub mod pallet {
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
-    // TODO: The pallet is intentionally very basic. It could be improved to handle more origins, more aliases, etc.
-    // There could also be different instances, if such approach was needed.
-    // However, it's supposed to be the simplest solution possible to cover a specific scenario.
-    // Pallet is stateless and can easily be upgraded in the future.
-
     /// Configuration trait.
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -65,61 +161,468 @@ pub mod pallet {
             + From<frame_system::Call<Self>>
             + IsType<<Self as frame_system::Config>::RuntimeCall>;
 
-        /// Origin that can act on behalf of the collective.
-        type CollectiveProxy: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+        /// Origin which can act on behalf of an alias, e.g. a collective, as stored in `Aliases`.
+        type AliasOrigin: Parameter + MaxEncodedLen + MaybeSerializeDeserialize;
 
-        /// Account representing the collective treasury.
-        type ProxyAccountId: Get<Self::AccountId>;
+        /// Ensures the dispatch origin matches the `AliasOrigin` of an alias.
+        type ProxyOrigin: EnsureOriginWithArg<
+            <Self as frame_system::Config>::RuntimeOrigin,
+            Self::AliasOrigin,
+        >;
 
-        /// Filter to determine whether a call can be executed or not.
-        type CallFilter: InstanceFilter<<Self as Config>::RuntimeCall> + Default;
+        /// Filter to determine whether a call can be executed or not, as stored in `Aliases`.
+        type CallFilter: InstanceFilter<<Self as Config>::RuntimeCall>
+            + Parameter
+            + MaxEncodedLen
+            + MaybeSerializeDeserialize;
+
+        /// Kind of asset whose transfers can be capped, e.g. native currency or an asset.
+        type AssetKind: Parameter + MaxEncodedLen;
+
+        /// Balance type of the capped transfers.
+        type Balance: tokens::Balance;
+
+        /// Used to recognize transfers, which count towards the spending caps of the alias.
+        type TransferInspector: InspectTransfer<
+            <Self as Config>::RuntimeCall,
+            Self::AssetKind,
+            Self::Balance,
+        >;
+
+        /// Maximum number of spending caps per alias.
+        #[pallet::constant]
+        type MaxSpendingCaps: Get<u32>;
+
+        /// Used to recognize smart contract calls, which are allowed if their target & selector
+        /// are in `AllowedContractCalls` for the dispatching alias, regardless of `CallFilter`.
+        type ContractCallInspector: InspectContractCall<
+            <Self as Config>::RuntimeCall,
+            Self::AccountId,
+        >;
+
+        /// Origin that can manage the allowed contract calls.
+        type ContractCallManager: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Origin that can manage the aliases & their spending caps.
+        type AliasManager: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
         /// Weight info
         type WeightInfo: WeightInfo;
+
+        /// Helper to provide the values needed by the benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AliasOrigin, Self::CallFilter, Self::AssetKind>;
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Community proxy call executed successfully.
-        CollectiveProxyExecuted { result: DispatchResult },
+        CollectiveProxyExecuted {
+            alias: AliasId,
+            call_hash: T::Hash,
+            result: DispatchResult,
+        },
+        /// Community proxy smart contract call executed.
+        CollectiveProxyContractCallExecuted {
+            alias: AliasId,
+            call_hash: T::Hash,
+            target: ContractTarget<T::AccountId>,
+            selector: Selector,
+            result: DispatchResult,
+        },
+        /// Contract call has been allowed.
+        ContractCallAllowed {
+            alias: AliasId,
+            target: ContractTarget<T::AccountId>,
+            selector: Selector,
+        },
+        /// Contract call is no longer allowed.
+        ContractCallDisallowed {
+            alias: AliasId,
+            target: ContractTarget<T::AccountId>,
+            selector: Selector,
+        },
+        /// Alias has been created or updated.
+        AliasSet {
+            alias: AliasId,
+            account: T::AccountId,
+            origin: T::AliasOrigin,
+            filter: T::CallFilter,
+        },
+        /// Alias has been removed.
+        AliasRemoved { alias: AliasId },
+        /// Spending cap of an alias has been set, or removed if `cap` is `None`.
+        SpendingCapSet {
+            alias: AliasId,
+            asset_kind: T::AssetKind,
+            cap: Option<SpendingCapOf<T>>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Contract call is already allowed.
+        ContractCallAlreadyAllowed,
+        /// Contract call is not allowed.
+        ContractCallNotAllowed,
+        /// Alias doesn't exist.
+        AliasNotFound,
+        /// Call would transfer more than the spending cap of the alias allows in this period.
+        SpendingCapExceeded,
+        /// Spending cap period must not be zero.
+        ZeroSpendingPeriod,
+        /// Alias already has the maximum number of spending caps.
+        TooManySpendingCaps,
+        /// Alias still has allowed contract calls, which must be disallowed first.
+        AliasHasAllowedContractCalls,
+    }
+
+    /// Contract targets & selectors which each alias can call, regardless of its `CallFilter`.
+    #[pallet::storage]
+    pub type AllowedContractCalls<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, AliasId>,
+            NMapKey<Blake2_128Concat, ContractTarget<T::AccountId>>,
+            NMapKey<Blake2_128Concat, Selector>,
+        ),
+        (),
+        OptionQuery,
+    >;
+
+    /// Aliased accounts, with the origin & filter of the calls dispatched on their behalf.
+    #[pallet::storage]
+    pub type Aliases<T: Config> = StorageMap<_, Twox64Concat, AliasId, ProxyAlias<T>, OptionQuery>;
+
+    /// Amounts transferred by each alias during the current period of its spending caps.
+    #[pallet::storage]
+    pub type Spent<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        AliasId,
+        Blake2_128Concat,
+        T::AssetKind,
+        SpentInPeriodOf<T>,
+        ValueQuery,
+    >;
+
+    #[pallet::genesis_config]
+    #[derive(DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Aliases as `(alias, account, origin, filter)`, without spending caps.
+        pub aliases: Vec<(AliasId, T::AccountId, T::AliasOrigin, T::CallFilter)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (alias, account, origin, filter) in &self.aliases {
+                Aliases::<T>::insert(
+                    alias,
+                    ProxyAlias {
+                        account: account.clone(),
+                        origin: origin.clone(),
+                        filter: filter.clone(),
+                        spending_caps: BoundedVec::default(),
+                    },
+                );
+            }
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Executes the call on a behalf of an aliased account.
+        /// Executes the call on a behalf of the aliased account `alias`.
         ///
         /// The `origin` of the call is supposed to be a _collective_ (but can be anything) which can dispatch `call` on behalf of the aliased account.
         /// It's essentially a proxy call that can be made by arbitrary origin type.
+        ///
+        /// Transfers made by `call` count towards the spending caps of the alias, and the call
+        /// fails without being dispatched if they would be exceeded.
         #[pallet::call_index(0)]
         #[pallet::weight({
 			let di = call.get_dispatch_info();
-			(T::WeightInfo::execute_call().saturating_add(di.weight), di.class)
+			let checked_calls = T::ContractCallInspector::checked_calls(call) as u64;
+			let transfers = T::TransferInspector::inspect(call).len() as u64;
+			// Allowed contract calls read per checked call, and spent amount read & write per transfer.
+			(T::WeightInfo::execute_call()
+				.saturating_add(T::DbWeight::get().reads_writes(checked_calls + transfers, transfers))
+				.saturating_add(di.weight), di.class)
 		})]
         pub fn execute_call(
             origin: OriginFor<T>,
+            alias: AliasId,
             call: Box<<T as Config>::RuntimeCall>,
         ) -> DispatchResult {
+            let proxy_alias = Aliases::<T>::get(alias).ok_or(Error::<T>::AliasNotFound)?;
+
             // Ensure origin is valid.
-            T::CollectiveProxy::ensure_origin(origin)?;
+            T::ProxyOrigin::ensure_origin(origin, &proxy_alias.origin)?;
+
+            let spent = Self::spent_after(alias, &proxy_alias, &call)?;
 
-            // Account authentication is ensured by the `CollectiveProxy` origin check.
+            // Account authentication is ensured by the `ProxyOrigin` origin check.
             let mut origin: T::RuntimeOrigin =
-                frame_system::RawOrigin::Signed(T::ProxyAccountId::get()).into();
+                frame_system::RawOrigin::Signed(proxy_alias.account).into();
 
-            // Ensure custom filter is applied.
+            // Ensure alias filter is applied, allowed contract calls are let through.
+            let filter = proxy_alias.filter;
             origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
                 let c = <T as Config>::RuntimeCall::from_ref(c);
-                T::CallFilter::default().filter(c)
+                filter.filter(c) || Self::is_allowed_contract_call(alias, c)
             });
 
+            let call_hash = T::Hashing::hash_of(&call);
+            let contract_call = T::ContractCallInspector::inspect(&call)
+                .and_then(|(target, input)| Some((target, Self::selector(input)?)));
+
             // Dispatch the call.
-            let e = call.dispatch(origin);
-            Self::deposit_event(Event::CollectiveProxyExecuted {
-                result: e.map(|_| ()).map_err(|e| e.error),
+            let result = call.dispatch(origin).map(|_| ()).map_err(|e| e.error);
+
+            // Only successfully dispatched transfers count towards the spending caps.
+            if result.is_ok() {
+                for (asset_kind, spent) in spent {
+                    Spent::<T>::insert(alias, asset_kind, spent);
+                }
+            }
+
+            match contract_call {
+                Some((target, selector)) => {
+                    Self::deposit_event(Event::CollectiveProxyContractCallExecuted {
+                        alias,
+                        call_hash,
+                        target,
+                        selector,
+                        result,
+                    })
+                }
+                None => Self::deposit_event(Event::CollectiveProxyExecuted {
+                    alias,
+                    call_hash,
+                    result,
+                }),
+            }
+
+            Ok(())
+        }
+
+        /// Allow the alias `alias` to call `selector` of the `target` contract.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::allow_contract_call())]
+        pub fn allow_contract_call(
+            origin: OriginFor<T>,
+            alias: AliasId,
+            target: ContractTarget<T::AccountId>,
+            selector: Selector,
+        ) -> DispatchResult {
+            T::ContractCallManager::ensure_origin(origin)?;
+
+            ensure!(Aliases::<T>::contains_key(alias), Error::<T>::AliasNotFound);
+            let key = (alias, &target, selector);
+            ensure!(
+                !AllowedContractCalls::<T>::contains_key(key),
+                Error::<T>::ContractCallAlreadyAllowed
+            );
+            AllowedContractCalls::<T>::insert(key, ());
+
+            Self::deposit_event(Event::ContractCallAllowed {
+                alias,
+                target,
+                selector,
+            });
+
+            Ok(())
+        }
+
+        /// Disallow the alias `alias` to call `selector` of the `target` contract.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::disallow_contract_call())]
+        pub fn disallow_contract_call(
+            origin: OriginFor<T>,
+            alias: AliasId,
+            target: ContractTarget<T::AccountId>,
+            selector: Selector,
+        ) -> DispatchResult {
+            T::ContractCallManager::ensure_origin(origin)?;
+
+            let key = (alias, &target, selector);
+            ensure!(
+                AllowedContractCalls::<T>::contains_key(key),
+                Error::<T>::ContractCallNotAllowed
+            );
+            AllowedContractCalls::<T>::remove(key);
+
+            Self::deposit_event(Event::ContractCallDisallowed {
+                alias,
+                target,
+                selector,
+            });
+
+            Ok(())
+        }
+
+        /// Create or update the alias `alias`, letting `alias_origin` dispatch calls allowed by
+        /// `filter` on behalf of `account`.
+        ///
+        /// Spending caps of an existing alias are kept.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_alias())]
+        pub fn set_alias(
+            origin: OriginFor<T>,
+            alias: AliasId,
+            account: T::AccountId,
+            alias_origin: T::AliasOrigin,
+            filter: T::CallFilter,
+        ) -> DispatchResult {
+            T::AliasManager::ensure_origin(origin)?;
+
+            Aliases::<T>::mutate(alias, |maybe_alias| {
+                let spending_caps = maybe_alias
+                    .take()
+                    .map(|proxy_alias| proxy_alias.spending_caps)
+                    .unwrap_or_default();
+                *maybe_alias = Some(ProxyAlias {
+                    account: account.clone(),
+                    origin: alias_origin.clone(),
+                    filter: filter.clone(),
+                    spending_caps,
+                });
+            });
+
+            Self::deposit_event(Event::AliasSet {
+                alias,
+                account,
+                origin: alias_origin,
+                filter,
+            });
+
+            Ok(())
+        }
+
+        /// Remove the alias `alias`, together with its spending caps.
+        ///
+        /// Its allowed contract calls must have been disallowed beforehand.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::remove_alias(T::MaxSpendingCaps::get()))]
+        pub fn remove_alias(origin: OriginFor<T>, alias: AliasId) -> DispatchResultWithPostInfo {
+            T::AliasManager::ensure_origin(origin)?;
+
+            ensure!(
+                !AllowedContractCalls::<T>::contains_prefix((alias,)),
+                Error::<T>::AliasHasAllowedContractCalls
+            );
+            let proxy_alias = Aliases::<T>::take(alias).ok_or(Error::<T>::AliasNotFound)?;
+            // There is at most one spent entry per spending cap.
+            let caps = proxy_alias.spending_caps.len() as u32;
+            let _ = Spent::<T>::clear_prefix(alias, caps, None);
+
+            Self::deposit_event(Event::AliasRemoved { alias });
+
+            Ok(Some(T::WeightInfo::remove_alias(caps)).into())
+        }
+
+        /// Set the spending cap of `alias` for `asset_kind`, or remove it if `cap` is `None`.
+        ///
+        /// The amount spent during the current period is reset.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_spending_cap())]
+        pub fn set_spending_cap(
+            origin: OriginFor<T>,
+            alias: AliasId,
+            asset_kind: T::AssetKind,
+            cap: Option<SpendingCapOf<T>>,
+        ) -> DispatchResult {
+            T::AliasManager::ensure_origin(origin)?;
+
+            Aliases::<T>::try_mutate(alias, |maybe_alias| -> DispatchResult {
+                let proxy_alias = maybe_alias.as_mut().ok_or(Error::<T>::AliasNotFound)?;
+                proxy_alias
+                    .spending_caps
+                    .retain(|(kind, _)| *kind != asset_kind);
+
+                if let Some(cap) = cap {
+                    ensure!(!cap.period.is_zero(), Error::<T>::ZeroSpendingPeriod);
+                    proxy_alias
+                        .spending_caps
+                        .try_push((asset_kind.clone(), cap))
+                        .map_err(|_| Error::<T>::TooManySpendingCaps)?;
+                }
+                Ok(())
+            })?;
+            Spent::<T>::remove(alias, &asset_kind);
+
+            Self::deposit_event(Event::SpendingCapSet {
+                alias,
+                asset_kind,
+                cap,
             });
 
             Ok(())
         }
     }
 }
+
+impl<T: Config> Pallet<T> {
+    /// `true` if `call` is a smart contract call which is in `AllowedContractCalls` for `alias`.
+    fn is_allowed_contract_call(alias: AliasId, call: &<T as Config>::RuntimeCall) -> bool {
+        T::ContractCallInspector::inspect(call)
+            .and_then(|(target, input)| Some((target, Self::selector(input)?)))
+            .map_or(false, |(target, selector)| {
+                AllowedContractCalls::<T>::contains_key((alias, target, selector))
+            })
+    }
+
+    /// Function selector of the contract call input.
+    fn selector(input: &[u8]) -> Option<Selector> {
+        input.get(0..4)?.try_into().ok()
+    }
+
+    /// Amounts spent by `alias` during the current periods once the transfers of `call` are
+    /// accounted for, for each capped asset kind transferred by `call`.
+    ///
+    /// Fails if any spending cap would be exceeded.
+    fn spent_after(
+        alias: AliasId,
+        proxy_alias: &ProxyAlias<T>,
+        call: &<T as Config>::RuntimeCall,
+    ) -> Result<Vec<(T::AssetKind, SpentInPeriodOf<T>)>, DispatchError> {
+        let now = frame_system::Pallet::<T>::block_number();
+        let mut spent: Vec<(T::AssetKind, SpentInPeriodOf<T>)> = Vec::new();
+
+        for (asset_kind, amount) in T::TransferInspector::inspect(call) {
+            let Some(cap) = proxy_alias
+                .spending_caps
+                .iter()
+                .find_map(|(kind, cap)| (*kind == asset_kind).then_some(cap))
+            else {
+                continue;
+            };
+
+            let index = match spent.iter().position(|(kind, _)| *kind == asset_kind) {
+                Some(index) => index,
+                None => {
+                    let period = now / cap.period;
+                    let mut spent_in_period = Spent::<T>::get(alias, &asset_kind);
+                    if spent_in_period.period != period {
+                        spent_in_period = SpentInPeriod {
+                            period,
+                            amount: Zero::zero(),
+                        };
+                    }
+                    spent.push((asset_kind, spent_in_period));
+                    spent.len() - 1
+                }
+            };
+
+            let spent_in_period = &mut spent[index].1;
+            spent_in_period.amount = spent_in_period.amount.saturating_add(amount);
+            ensure!(
+                spent_in_period.amount <= cap.amount,
+                Error::<T>::SpendingCapExceeded
+            );
+        }
+
+        Ok(spent)
+    }
+}
@@ -16,28 +16,34 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_collective_proxy};
+use crate::{
+    self as pallet_collective_proxy, ContractTarget, InspectContractCall, InspectTransfer,
+};
 
-use astar_primitives::{Balance, BlockNumber};
+use astar_primitives::{treasury::TreasuryAssetKind, Balance, BlockNumber};
 use frame_support::{
-    construct_runtime, ord_parameter_types, parameter_types,
-    traits::{ConstU128, ConstU32, InstanceFilter},
+    construct_runtime, parameter_types,
+    traits::{ConstU128, ConstU32, EnsureOriginWithArg, InstanceFilter},
     weights::Weight,
 };
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, RuntimeDebug,
 };
 
-use frame_system::EnsureSignedBy;
+use frame_system::EnsureRoot;
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 type AccountId = u64;
 
 pub(crate) const COMMUNITY_ACCOUNT: AccountId = 1337;
 pub(crate) const PRIVILEGED_ACCOUNT: AccountId = 365;
+pub(crate) const MOCK_CONTRACT: AccountId = 42;
 
 construct_runtime!(
     pub struct Test {
@@ -96,32 +102,119 @@ impl pallet_balances::Config for Test {
     type WeightInfo = ();
 }
 
-parameter_types! {
-    pub const ProxyAccountId: AccountId = COMMUNITY_ACCOUNT;
-}
-ord_parameter_types! {
-    pub const CollectiveProxyManager: AccountId = PRIVILEGED_ACCOUNT;
+pub(crate) type AssetKind = TreasuryAssetKind<u32>;
+
+/// Ensures the origin is signed by the alias origin account.
+pub struct EnsureSignedByArg;
+impl EnsureOriginWithArg<RuntimeOrigin, AccountId> for EnsureSignedByArg {
+    type Success = ();
+
+    fn try_origin(o: RuntimeOrigin, arg: &AccountId) -> Result<Self::Success, RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(frame_system::RawOrigin::Signed(who)) if who == *arg => Ok(()),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(arg: &AccountId) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(*arg))
+    }
 }
 
-#[derive(Default)]
-pub struct MockCallFilter;
+#[derive(
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Serialize,
+    Deserialize,
+)]
+pub enum MockCallFilter {
+    /// Native transfers & remarks.
+    Transfers,
+    /// Remarks only.
+    Remarks,
+}
 impl InstanceFilter<RuntimeCall> for MockCallFilter {
     fn filter(&self, c: &RuntimeCall) -> bool {
-        matches!(
-            c,
-            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
-                | RuntimeCall::System(frame_system::Call::remark { .. })
-        )
+        match self {
+            MockCallFilter::Transfers => matches!(
+                c,
+                RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
+                    | RuntimeCall::System(frame_system::Call::remark { .. })
+            ),
+            MockCallFilter::Remarks => {
+                matches!(c, RuntimeCall::System(frame_system::Call::remark { .. }))
+            }
+        }
+    }
+}
+
+/// `transfer_allow_death` transfers native currency.
+pub struct MockTransferInspector;
+impl InspectTransfer<RuntimeCall, AssetKind, Balance> for MockTransferInspector {
+    fn inspect(call: &RuntimeCall) -> Vec<(AssetKind, Balance)> {
+        match call {
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                value, ..
+            }) => {
+                vec![(AssetKind::Native, *value)]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// `remark_with_event` is treated as a call to `MOCK_CONTRACT`, with the remark as input.
+pub struct MockContractCallInspector;
+impl InspectContractCall<RuntimeCall, AccountId> for MockContractCallInspector {
+    fn inspect(call: &RuntimeCall) -> Option<(ContractTarget<AccountId>, &[u8])> {
+        match call {
+            RuntimeCall::System(frame_system::Call::remark_with_event { remark }) => {
+                Some((ContractTarget::Wasm(MOCK_CONTRACT), &remark[..]))
+            }
+            _ => None,
+        }
     }
 }
 
 impl pallet_collective_proxy::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
-    type CollectiveProxy = EnsureSignedBy<CollectiveProxyManager, AccountId>;
-    type ProxyAccountId = ProxyAccountId;
+    type AliasOrigin = AccountId;
+    type ProxyOrigin = EnsureSignedByArg;
     type CallFilter = MockCallFilter;
+    type AssetKind = AssetKind;
+    type Balance = Balance;
+    type TransferInspector = MockTransferInspector;
+    type MaxSpendingCaps = ConstU32<2>;
+    type ContractCallInspector = MockContractCallInspector;
+    type ContractCallManager = EnsureRoot<AccountId>;
+    type AliasManager = EnsureRoot<AccountId>;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_collective_proxy::BenchmarkHelper<AccountId, MockCallFilter, AssetKind>
+    for MockBenchmarkHelper
+{
+    fn alias_origin_and_filter() -> (AccountId, MockCallFilter) {
+        (PRIVILEGED_ACCOUNT, MockCallFilter::Remarks)
+    }
+
+    fn asset_kind(seed: u32) -> AssetKind {
+        AssetKind::Asset(seed)
+    }
 }
 
 pub struct ExtBuilder;
@@ -142,6 +235,17 @@ impl ExtBuilder {
             .assimilate_storage(&mut storage)
             .ok();
 
+        pallet_collective_proxy::GenesisConfig::<Test> {
+            aliases: vec![(
+                0,
+                COMMUNITY_ACCOUNT,
+                PRIVILEGED_ACCOUNT,
+                MockCallFilter::Transfers,
+            )],
+        }
+        .assimilate_storage(&mut storage)
+        .ok();
+
         let mut ext = TestExternalities::from(storage);
         ext.execute_with(|| {
             System::set_block_number(1);
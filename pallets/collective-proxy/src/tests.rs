@@ -16,23 +16,43 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{mock::*, Event};
+use crate::{
+    mock::*, Aliases, AllowedContractCalls, ContractTarget, Error, Event, SpendingCap, Spent,
+    SpentInPeriod,
+};
 
+use astar_primitives::Balance;
 use frame_support::{assert_noop, assert_ok, error::BadOrigin};
 use pallet_balances::Call as BalancesCall;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn transfer(value: Balance) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+        dest: 2,
+        value,
+    }))
+}
 
 #[test]
 fn execute_call_fails_for_invalid_origin() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            CollectiveProxy::execute_call(RuntimeOrigin::signed(1), 0, transfer(10)),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn execute_call_fails_for_unknown_alias() {
     ExtBuilder::build().execute_with(|| {
         assert_noop!(
             CollectiveProxy::execute_call(
-                RuntimeOrigin::signed(1),
-                Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
-                    dest: 2,
-                    value: 10
-                }))
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                1,
+                transfer(10)
             ),
-            BadOrigin
+            Error::<Test>::AliasNotFound
         );
     });
 }
@@ -42,18 +62,24 @@ fn execute_call_filters_not_allowed_call() {
     ExtBuilder::build().execute_with(|| {
         let init_balance = Balances::free_balance(COMMUNITY_ACCOUNT);
 
+        let call = Box::new(RuntimeCall::Balances(BalancesCall::transfer_keep_alive {
+            dest: 2,
+            value: 10,
+        }));
+        let call_hash = BlakeTwo256::hash_of(&call);
+
         // Call is filtered, but `execute_call` succeeds.
         assert_ok!(CollectiveProxy::execute_call(
             RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
-            Box::new(RuntimeCall::Balances(BalancesCall::transfer_keep_alive {
-                dest: 2,
-                value: 10
-            }))
+            0,
+            call
         ));
 
         // Ensure event with error is emitted.
         System::assert_last_event(
             Event::<Test>::CollectiveProxyExecuted {
+                alias: 0,
+                call_hash,
                 result: Err(frame_system::Error::<Test>::CallFiltered.into()),
             }
             .into(),
@@ -74,16 +100,19 @@ fn execute_call_succeeds() {
         let init_balance = Balances::free_balance(COMMUNITY_ACCOUNT);
         let transfer_value = init_balance / 3;
 
+        let call = transfer(transfer_value);
+        let call_hash = BlakeTwo256::hash_of(&call);
+
         assert_ok!(CollectiveProxy::execute_call(
             RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
-            Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
-                dest: 2,
-                value: transfer_value
-            }))
+            0,
+            call
         ));
 
         System::assert_last_event(
             Event::<Test>::CollectiveProxyExecuted {
+                alias: 0,
+                call_hash,
                 result: Ok(().into()),
             }
             .into(),
@@ -94,3 +123,592 @@ fn execute_call_succeeds() {
         assert_eq!(init_balance, after_balance + transfer_value,);
     });
 }
+
+#[test]
+fn allow_contract_call_works() {
+    ExtBuilder::build().execute_with(|| {
+        let target = ContractTarget::Wasm(MOCK_CONTRACT);
+        let selector = [1, 2, 3, 4];
+
+        assert_noop!(
+            CollectiveProxy::allow_contract_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                0,
+                target.clone(),
+                selector
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            CollectiveProxy::allow_contract_call(
+                RuntimeOrigin::root(),
+                1,
+                target.clone(),
+                selector
+            ),
+            Error::<Test>::AliasNotFound
+        );
+
+        assert_ok!(CollectiveProxy::allow_contract_call(
+            RuntimeOrigin::root(),
+            0,
+            target.clone(),
+            selector
+        ));
+        System::assert_last_event(
+            Event::<Test>::ContractCallAllowed {
+                alias: 0,
+                target: target.clone(),
+                selector,
+            }
+            .into(),
+        );
+        assert!(AllowedContractCalls::<Test>::contains_key((
+            0, &target, selector
+        )));
+
+        assert_noop!(
+            CollectiveProxy::allow_contract_call(RuntimeOrigin::root(), 0, target, selector),
+            Error::<Test>::ContractCallAlreadyAllowed
+        );
+    });
+}
+
+#[test]
+fn disallow_contract_call_works() {
+    ExtBuilder::build().execute_with(|| {
+        let target = ContractTarget::Wasm(MOCK_CONTRACT);
+        let selector = [1, 2, 3, 4];
+
+        assert_noop!(
+            CollectiveProxy::disallow_contract_call(
+                RuntimeOrigin::root(),
+                0,
+                target.clone(),
+                selector
+            ),
+            Error::<Test>::ContractCallNotAllowed
+        );
+
+        assert_ok!(CollectiveProxy::allow_contract_call(
+            RuntimeOrigin::root(),
+            0,
+            target.clone(),
+            selector
+        ));
+        assert_ok!(CollectiveProxy::disallow_contract_call(
+            RuntimeOrigin::root(),
+            0,
+            target.clone(),
+            selector
+        ));
+        System::assert_last_event(
+            Event::<Test>::ContractCallDisallowed {
+                alias: 0,
+                target: target.clone(),
+                selector,
+            }
+            .into(),
+        );
+        assert!(!AllowedContractCalls::<Test>::contains_key((
+            0, &target, selector
+        )));
+    });
+}
+
+#[test]
+fn execute_call_allows_contract_calls() {
+    ExtBuilder::build().execute_with(|| {
+        let target = ContractTarget::Wasm(MOCK_CONTRACT);
+        let contract_call = |input: Vec<u8>| {
+            Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+                remark: input,
+            }))
+        };
+
+        // Not allowed yet, the call is filtered.
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            0,
+            contract_call(vec![1, 2, 3, 4, 5])
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyContractCallExecuted {
+                alias: 0,
+                call_hash: BlakeTwo256::hash_of(&contract_call(vec![1, 2, 3, 4, 5])),
+                target: target.clone(),
+                selector: [1, 2, 3, 4],
+                result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+            }
+            .into(),
+        );
+
+        assert_ok!(CollectiveProxy::allow_contract_call(
+            RuntimeOrigin::root(),
+            0,
+            target.clone(),
+            [1, 2, 3, 4]
+        ));
+
+        // Allowed target & selector.
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            0,
+            contract_call(vec![1, 2, 3, 4, 5])
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyContractCallExecuted {
+                alias: 0,
+                call_hash: BlakeTwo256::hash_of(&contract_call(vec![1, 2, 3, 4, 5])),
+                target: target.clone(),
+                selector: [1, 2, 3, 4],
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        // Other selector is still filtered.
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            0,
+            contract_call(vec![4, 3, 2, 1])
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyContractCallExecuted {
+                alias: 0,
+                call_hash: BlakeTwo256::hash_of(&contract_call(vec![4, 3, 2, 1])),
+                target,
+                selector: [4, 3, 2, 1],
+                result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn allowed_contract_calls_are_per_alias() {
+    ExtBuilder::build().execute_with(|| {
+        let target = ContractTarget::Wasm(MOCK_CONTRACT);
+        let contract_call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+            remark: vec![1, 2, 3, 4],
+        }));
+        assert_ok!(CollectiveProxy::set_alias(
+            RuntimeOrigin::root(),
+            1,
+            3,
+            4,
+            MockCallFilter::Remarks
+        ));
+        assert_ok!(CollectiveProxy::allow_contract_call(
+            RuntimeOrigin::root(),
+            0,
+            target.clone(),
+            [1, 2, 3, 4]
+        ));
+
+        // Contract call allowed for alias 0 is filtered for alias 1.
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(4),
+            1,
+            contract_call.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyContractCallExecuted {
+                alias: 1,
+                call_hash: BlakeTwo256::hash_of(&contract_call),
+                target,
+                selector: [1, 2, 3, 4],
+                result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn genesis_aliases_are_set() {
+    ExtBuilder::build().execute_with(|| {
+        let alias = Aliases::<Test>::get(0).expect("Alias 0 is set in genesis.");
+        assert_eq!(alias.account, COMMUNITY_ACCOUNT);
+        assert_eq!(alias.origin, PRIVILEGED_ACCOUNT);
+        assert_eq!(alias.filter, MockCallFilter::Transfers);
+        assert!(alias.spending_caps.is_empty());
+    });
+}
+
+#[test]
+fn set_alias_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            CollectiveProxy::set_alias(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                1,
+                3,
+                4,
+                MockCallFilter::Remarks
+            ),
+            BadOrigin
+        );
+
+        assert_ok!(CollectiveProxy::set_alias(
+            RuntimeOrigin::root(),
+            1,
+            3,
+            4,
+            MockCallFilter::Remarks
+        ));
+        System::assert_last_event(
+            Event::<Test>::AliasSet {
+                alias: 1,
+                account: 3,
+                origin: 4,
+                filter: MockCallFilter::Remarks,
+            }
+            .into(),
+        );
+
+        // Only the alias origin can use the alias.
+        assert_noop!(
+            CollectiveProxy::execute_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                1,
+                transfer(10)
+            ),
+            BadOrigin
+        );
+
+        // The alias filter is applied.
+        let init_balance = Balances::free_balance(3);
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(4),
+            1,
+            transfer(10)
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyExecuted {
+                alias: 1,
+                call_hash: BlakeTwo256::hash_of(&transfer(10)),
+                result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(3), init_balance);
+    });
+}
+
+#[test]
+fn set_alias_keeps_spending_caps() {
+    ExtBuilder::build().execute_with(|| {
+        let cap = SpendingCap {
+            amount: 100,
+            period: 10,
+        };
+        assert_ok!(CollectiveProxy::set_spending_cap(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            Some(cap)
+        ));
+
+        assert_ok!(CollectiveProxy::set_alias(
+            RuntimeOrigin::root(),
+            0,
+            3,
+            4,
+            MockCallFilter::Remarks
+        ));
+
+        let alias = Aliases::<Test>::get(0).unwrap();
+        assert_eq!(alias.account, 3);
+        assert_eq!(alias.origin, 4);
+        assert_eq!(
+            alias.spending_caps.into_inner(),
+            vec![(AssetKind::Native, cap)]
+        );
+    });
+}
+
+#[test]
+fn remove_alias_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(CollectiveProxy::set_spending_cap(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            Some(SpendingCap {
+                amount: 100,
+                period: 10,
+            })
+        ));
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            0,
+            transfer(10)
+        ));
+        assert!(Spent::<Test>::contains_key(0, AssetKind::Native));
+
+        assert_noop!(
+            CollectiveProxy::remove_alias(RuntimeOrigin::signed(PRIVILEGED_ACCOUNT), 0),
+            BadOrigin
+        );
+
+        // Allowed contract calls must be disallowed first.
+        let target = ContractTarget::Wasm(MOCK_CONTRACT);
+        assert_ok!(CollectiveProxy::allow_contract_call(
+            RuntimeOrigin::root(),
+            0,
+            target.clone(),
+            [1, 2, 3, 4]
+        ));
+        assert_noop!(
+            CollectiveProxy::remove_alias(RuntimeOrigin::root(), 0),
+            Error::<Test>::AliasHasAllowedContractCalls
+        );
+        assert_ok!(CollectiveProxy::disallow_contract_call(
+            RuntimeOrigin::root(),
+            0,
+            target,
+            [1, 2, 3, 4]
+        ));
+
+        assert_ok!(CollectiveProxy::remove_alias(RuntimeOrigin::root(), 0));
+        System::assert_last_event(Event::<Test>::AliasRemoved { alias: 0 }.into());
+        assert!(!Aliases::<Test>::contains_key(0));
+        assert!(!Spent::<Test>::contains_key(0, AssetKind::Native));
+
+        assert_noop!(
+            CollectiveProxy::remove_alias(RuntimeOrigin::root(), 0),
+            Error::<Test>::AliasNotFound
+        );
+        assert_noop!(
+            CollectiveProxy::execute_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                0,
+                transfer(10)
+            ),
+            Error::<Test>::AliasNotFound
+        );
+    });
+}
+
+#[test]
+fn set_spending_cap_works() {
+    ExtBuilder::build().execute_with(|| {
+        let cap = SpendingCap {
+            amount: 100,
+            period: 10,
+        };
+
+        assert_noop!(
+            CollectiveProxy::set_spending_cap(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                0,
+                AssetKind::Native,
+                Some(cap)
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            CollectiveProxy::set_spending_cap(
+                RuntimeOrigin::root(),
+                1,
+                AssetKind::Native,
+                Some(cap)
+            ),
+            Error::<Test>::AliasNotFound
+        );
+        assert_noop!(
+            CollectiveProxy::set_spending_cap(
+                RuntimeOrigin::root(),
+                0,
+                AssetKind::Native,
+                Some(SpendingCap {
+                    amount: 100,
+                    period: 0,
+                })
+            ),
+            Error::<Test>::ZeroSpendingPeriod
+        );
+
+        assert_ok!(CollectiveProxy::set_spending_cap(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            Some(cap)
+        ));
+        System::assert_last_event(
+            Event::<Test>::SpendingCapSet {
+                alias: 0,
+                asset_kind: AssetKind::Native,
+                cap: Some(cap),
+            }
+            .into(),
+        );
+        assert_ok!(CollectiveProxy::set_spending_cap(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Asset(1),
+            Some(cap)
+        ));
+
+        // Updating an existing cap doesn't need an extra slot.
+        let new_cap = SpendingCap {
+            amount: 200,
+            period: 20,
+        };
+        assert_ok!(CollectiveProxy::set_spending_cap(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            Some(new_cap)
+        ));
+        assert_noop!(
+            CollectiveProxy::set_spending_cap(
+                RuntimeOrigin::root(),
+                0,
+                AssetKind::Asset(2),
+                Some(cap)
+            ),
+            Error::<Test>::TooManySpendingCaps
+        );
+
+        // Removing a cap frees its slot.
+        assert_ok!(CollectiveProxy::set_spending_cap(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Asset(1),
+            None
+        ));
+        System::assert_last_event(
+            Event::<Test>::SpendingCapSet {
+                alias: 0,
+                asset_kind: AssetKind::Asset(1),
+                cap: None,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Aliases::<Test>::get(0).unwrap().spending_caps.into_inner(),
+            vec![(AssetKind::Native, new_cap)]
+        );
+    });
+}
+
+#[test]
+fn execute_call_respects_spending_caps() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(CollectiveProxy::set_spending_cap(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            Some(SpendingCap {
+                amount: 100,
+                period: 10,
+            })
+        ));
+        let init_balance = Balances::free_balance(COMMUNITY_ACCOUNT);
+
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            0,
+            transfer(60)
+        ));
+        assert_noop!(
+            CollectiveProxy::execute_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                0,
+                transfer(50)
+            ),
+            Error::<Test>::SpendingCapExceeded
+        );
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            0,
+            transfer(40)
+        ));
+        assert_eq!(
+            Spent::<Test>::get(0, AssetKind::Native),
+            SpentInPeriod {
+                period: 0,
+                amount: 100,
+            }
+        );
+        assert_eq!(
+            Balances::free_balance(COMMUNITY_ACCOUNT),
+            init_balance - 100
+        );
+
+        // Cap is reset in the next period.
+        System::set_block_number(10);
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            0,
+            transfer(100)
+        ));
+        assert_eq!(
+            Spent::<Test>::get(0, AssetKind::Native),
+            SpentInPeriod {
+                period: 1,
+                amount: 100,
+            }
+        );
+        assert_eq!(
+            Balances::free_balance(COMMUNITY_ACCOUNT),
+            init_balance - 200
+        );
+    });
+}
+
+#[test]
+fn failed_transfers_do_not_count_towards_spending_caps() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(CollectiveProxy::set_spending_cap(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Native,
+            Some(SpendingCap {
+                amount: 10_000,
+                period: 10,
+            })
+        ));
+
+        // Transfer exceeds the alias balance, dispatch fails.
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            0,
+            transfer(5_000)
+        ));
+        assert!(!Spent::<Test>::contains_key(0, AssetKind::Native));
+    });
+}
+
+#[test]
+fn spending_caps_only_apply_to_their_asset_kind() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(CollectiveProxy::set_spending_cap(
+            RuntimeOrigin::root(),
+            0,
+            AssetKind::Asset(1),
+            Some(SpendingCap {
+                amount: 1,
+                period: 10,
+            })
+        ));
+
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            0,
+            transfer(100)
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyExecuted {
+                alias: 0,
+                call_hash: BlakeTwo256::hash_of(&transfer(100)),
+                result: Ok(()),
+            }
+            .into(),
+        );
+        assert!(!Spent::<Test>::contains_key(0, AssetKind::Native));
+    });
+}
@@ -50,27 +50,172 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_collective_proxy.
 pub trait WeightInfo {
 	fn execute_call() -> Weight;
+	fn allow_contract_call() -> Weight;
+	fn disallow_contract_call() -> Weight;
+	fn set_alias() -> Weight;
+	fn remove_alias(c: u32, ) -> Weight;
+	fn set_spending_cap() -> Weight;
 }
 
 /// Weights for pallet_collective_proxy using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:0)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
 	fn execute_call() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 7_732_000 picoseconds.
-		Weight::from_parts(7_950_000, 0)
+		//  Measured:  `93`
+		//  Estimated: `3636`
+		// Minimum execution time: 10_814_000 picoseconds.
+		Weight::from_parts(11_203_000, 3636)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:0)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::AllowedContractCalls` (r:1 w:1)
+	/// Proof: `CollectiveProxy::AllowedContractCalls` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn allow_contract_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3636`
+		// Minimum execution time: 12_106_000 picoseconds.
+		Weight::from_parts(12_538_000, 3636)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::AllowedContractCalls` (r:1 w:1)
+	/// Proof: `CollectiveProxy::AllowedContractCalls` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn disallow_contract_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3563`
+		// Minimum execution time: 10_391_000 picoseconds.
+		Weight::from_parts(10_782_000, 3563)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	fn set_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `3636`
+		// Minimum execution time: 11_482_000 picoseconds.
+		Weight::from_parts(11_870_000, 3636)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::AllowedContractCalls` (r:1 w:0)
+	/// Proof: `CollectiveProxy::AllowedContractCalls` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::Spent` (r:0 w:4)
+	/// Proof: `CollectiveProxy::Spent` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 4]`.
+	fn remove_alias(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93 + c * (48 ±0)`
+		//  Estimated: `3636`
+		// Minimum execution time: 15_327_000 picoseconds.
+		Weight::from_parts(15_894_105, 3636)
+			// Standard Error: 9_128
+			.saturating_add(Weight::from_parts(1_874_602, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::Spent` (r:0 w:1)
+	/// Proof: `CollectiveProxy::Spent` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	fn set_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `165`
+		//  Estimated: `3636`
+		// Minimum execution time: 14_237_000 picoseconds.
+		Weight::from_parts(14_715_000, 3636)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:0)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
 	fn execute_call() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 7_732_000 picoseconds.
-		Weight::from_parts(7_950_000, 0)
+		//  Measured:  `93`
+		//  Estimated: `3636`
+		// Minimum execution time: 10_814_000 picoseconds.
+		Weight::from_parts(11_203_000, 3636)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:0)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::AllowedContractCalls` (r:1 w:1)
+	/// Proof: `CollectiveProxy::AllowedContractCalls` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn allow_contract_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93`
+		//  Estimated: `3636`
+		// Minimum execution time: 12_106_000 picoseconds.
+		Weight::from_parts(12_538_000, 3636)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::AllowedContractCalls` (r:1 w:1)
+	/// Proof: `CollectiveProxy::AllowedContractCalls` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn disallow_contract_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `133`
+		//  Estimated: `3563`
+		// Minimum execution time: 10_391_000 picoseconds.
+		Weight::from_parts(10_782_000, 3563)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	fn set_alias() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `3636`
+		// Minimum execution time: 11_482_000 picoseconds.
+		Weight::from_parts(11_870_000, 3636)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::AllowedContractCalls` (r:1 w:0)
+	/// Proof: `CollectiveProxy::AllowedContractCalls` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::Spent` (r:0 w:4)
+	/// Proof: `CollectiveProxy::Spent` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 4]`.
+	fn remove_alias(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `93 + c * (48 ±0)`
+		//  Estimated: `3636`
+		// Minimum execution time: 15_327_000 picoseconds.
+		Weight::from_parts(15_894_105, 3636)
+			// Standard Error: 9_128
+			.saturating_add(Weight::from_parts(1_874_602, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	/// Storage: `CollectiveProxy::Aliases` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Aliases` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::Spent` (r:0 w:1)
+	/// Proof: `CollectiveProxy::Spent` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	fn set_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `165`
+		//  Estimated: `3636`
+		// Minimum execution time: 14_237_000 picoseconds.
+		Weight::from_parts(14_715_000, 3636)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
@@ -0,0 +1,49 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::U256;
+
+/// Current & predicted 'base fee per gas' values.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BaseFeeInfo {
+    /// 'Base fee per gas' paid by the transactions of the next block.
+    pub base_fee_per_gas: U256,
+    /// Predicted 'base fee per gas' after the next block, given the current state.
+    pub next_base_fee_per_gas: U256,
+    /// Lower limit the next 'base fee per gas' is clamped to.
+    pub min_next_base_fee_per_gas: U256,
+    /// Upper limit the next 'base fee per gas' is clamped to.
+    pub max_next_base_fee_per_gas: U256,
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// API for predicting the dynamic EVM 'base fee per gas'.
+    pub trait DynamicEvmBaseFeeApi {
+        /// Current 'base fee per gas', its predicted next value & the bounds in force.
+        fn base_fee_info() -> BaseFeeInfo;
+    }
+}
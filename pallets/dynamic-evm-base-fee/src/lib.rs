@@ -39,10 +39,22 @@
 //!
 //! The implementation doesn't make any hard requirements on these values, and only requires that a type implementing `Get<_>` provides them.
 //!
+//! ## EIP-1559 Mode
+//!
+//! Alternatively, the pallet can be configured to use `BaseFeeMode::Eip1559`, in which case the
+//! `base_fee_per_gas` moves toward the gas target based on the EVM gas actually used in the block,
+//! as provided by `BlockGasUsage`:
+//!
+//! base_fee_per_gas = old_base_fee_per_gas +/- max_step * |gas_used - gas_target| / gas_target
+//!
+//! Where **max_step** is derived from `StepLimitRatio`, so a block using twice the gas target
+//! (or more) moves the fee by the full step, while a block exactly at the target keeps it unchanged.
+//!
 //! ## Implementation
 //!
 //! The core logic is implemented in `on_finalize` hook, which is called at the end of each block.
-//! This pallet's hook should be called AFTER whichever pallet's hook is responsible for updating **adjustment factor**.
+//! This pallet's hook should be called AFTER whichever pallet's hook is responsible for updating **adjustment factor**,
+//! or, in the `EIP-1559` mode, the one responsible for tracking the block's **gas used** (e.g. `pallet-ethereum`).
 //!
 //! The hook will calculate the ideal new `base_fee_per_gas` value, and then clamp it in between the allowed limits.
 //!
@@ -76,8 +88,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::weights::Weight;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_core::U256;
-use sp_runtime::{traits::UniqueSaturatedInto, FixedPointNumber, FixedU128, Perquintill};
+use sp_runtime::{
+    traits::UniqueSaturatedInto, FixedPointNumber, FixedU128, Perquintill, RuntimeDebug,
+};
 
 pub use self::pallet::*;
 
@@ -92,6 +108,37 @@ mod benchmarking;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Algorithm used to calculate the new `base fee per gas` at the end of each block.
+#[derive(
+    Encode, Decode, MaxEncodedLen, Copy, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo,
+)]
+pub enum BaseFeeMode {
+    /// Align with the native transaction fee, based on the `AdjustmentFactor` & `WeightFactor`.
+    #[default]
+    AdjustmentFactor,
+    /// Move toward the gas target based on the block's EVM gas used, similar to `EIP-1559`.
+    Eip1559,
+}
+
+/// Provides EVM gas usage of the current block, used by `BaseFeeMode::Eip1559`.
+pub trait BlockGasUsage {
+    /// Total gas used by the EVM transactions of the current block.
+    fn gas_used() -> U256;
+    /// Gas usage the `base fee per gas` is aligned toward.
+    /// Blocks using more gas increase the fee, blocks using less decrease it.
+    fn gas_target() -> U256;
+}
+
+impl BlockGasUsage for () {
+    fn gas_used() -> U256 {
+        U256::zero()
+    }
+
+    fn gas_target() -> U256 {
+        U256::zero()
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
@@ -124,6 +171,10 @@ pub mod pallet {
         /// It's expressed as percentage, and used to calculate the delta between the old and new value.
         /// E.g. if the current 'base fee per gas' is 100, and the limit is 10%, then the new base fee per gas can be between 90 and 110.
         type StepLimitRatio: Get<Perquintill>;
+        /// Algorithm used to calculate the new 'base fee per gas'.
+        type BaseFeeMode: Get<BaseFeeMode>;
+        /// Gas usage of the current block, only used in `BaseFeeMode::Eip1559`.
+        type BlockGasUsage: BlockGasUsage;
         /// Weight information for extrinsics & functions of this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -156,45 +207,7 @@ pub mod pallet {
         }
 
         fn on_finalize(_n: BlockNumberFor<T>) {
-            BaseFeePerGas::<T>::mutate(|base_fee_per_gas| {
-                let old_bfpg = *base_fee_per_gas;
-
-                // Maximum step we're allowed to move the base fee per gas by.
-                let max_step = {
-                    let old_bfpg_u128: u128 = old_bfpg.unique_saturated_into();
-                    let step = T::StepLimitRatio::get() * old_bfpg_u128;
-                    U256::from(step)
-                };
-
-                // It's possible current base fee per gas is outside of the allowed range.
-                // This can & will happen when this solution is deployed on live networks.
-                //
-                // In such scenario, we will discard the lower & upper bounds configured in the runtime.
-                // Once these bounds are reached ONCE, the runtime logic will prevent them from going out of bounds again.
-                let apply_configured_bounds = old_bfpg >= T::MinBaseFeePerGas::get()
-                    && old_bfpg <= T::MaxBaseFeePerGas::get();
-                let (lower_limit, upper_limit) = if apply_configured_bounds {
-                    (
-                        T::MinBaseFeePerGas::get().max(old_bfpg.saturating_sub(max_step)),
-                        T::MaxBaseFeePerGas::get().min(old_bfpg.saturating_add(max_step)),
-                    )
-                } else {
-                    (
-                        old_bfpg.saturating_sub(max_step),
-                        old_bfpg.saturating_add(max_step),
-                    )
-                };
-
-                // Calculate ideal new 'base_fee_per_gas' according to the formula
-                let ideal_new_bfpg = T::AdjustmentFactor::get()
-                    // Weight factor should be multiplied first since it's a larger number, to avoid precision loss.
-                    .saturating_mul_int(T::WeightFactor::get())
-                    .saturating_mul(25)
-                    .saturating_div(98974);
-
-                // Clamp the ideal value in between the allowed limits
-                *base_fee_per_gas = U256::from(ideal_new_bfpg).clamp(lower_limit, upper_limit);
-            })
+            BaseFeePerGas::<T>::put(Self::next_base_fee_per_gas());
         }
 
         fn integrity_test() {
@@ -231,6 +244,94 @@ pub mod pallet {
     }
 }
 
+impl<T: Config> Pallet<T> {
+    /// Maximum step the current 'base fee per gas' is allowed to move by in-between two blocks.
+    fn max_step() -> U256 {
+        let bfpg: u128 = BaseFeePerGas::<T>::get().unique_saturated_into();
+        U256::from(T::StepLimitRatio::get() * bfpg)
+    }
+
+    /// Lower & upper limits the next 'base fee per gas' is clamped to.
+    pub fn next_base_fee_per_gas_bounds() -> (U256, U256) {
+        let old_bfpg = BaseFeePerGas::<T>::get();
+        let max_step = Self::max_step();
+
+        // It's possible current base fee per gas is outside of the allowed range.
+        // This can & will happen when this solution is deployed on live networks.
+        //
+        // In such scenario, we will discard the lower & upper bounds configured in the runtime.
+        // Once these bounds are reached ONCE, the runtime logic will prevent them from going out of bounds again.
+        let apply_configured_bounds =
+            old_bfpg >= T::MinBaseFeePerGas::get() && old_bfpg <= T::MaxBaseFeePerGas::get();
+        if apply_configured_bounds {
+            (
+                T::MinBaseFeePerGas::get().max(old_bfpg.saturating_sub(max_step)),
+                T::MaxBaseFeePerGas::get().min(old_bfpg.saturating_add(max_step)),
+            )
+        } else {
+            (
+                old_bfpg.saturating_sub(max_step),
+                old_bfpg.saturating_add(max_step),
+            )
+        }
+    }
+
+    /// The 'base fee per gas' which will be set at the end of the block, given the current state.
+    pub fn next_base_fee_per_gas() -> U256 {
+        let (lower_limit, upper_limit) = Self::next_base_fee_per_gas_bounds();
+
+        // Calculate ideal new 'base_fee_per_gas' according to the configured mode
+        let ideal_new_bfpg = match T::BaseFeeMode::get() {
+            BaseFeeMode::AdjustmentFactor => Self::adjustment_factor_bfpg(),
+            BaseFeeMode::Eip1559 => Self::eip1559_bfpg(BaseFeePerGas::<T>::get(), Self::max_step()),
+        };
+
+        // Clamp the ideal value in between the allowed limits
+        ideal_new_bfpg.clamp(lower_limit, upper_limit)
+    }
+
+    /// The 'base fee per gas' predicted for the end of the next block, given the state after the
+    /// current block has been finalized (e.g. in a runtime API call).
+    ///
+    /// In the `EIP-1559` mode, the gas usage of the current block has already been accounted for
+    /// by `on_finalize` and the one of the next block isn't known yet, so no change is predicted.
+    pub fn predicted_next_base_fee_per_gas() -> U256 {
+        match T::BaseFeeMode::get() {
+            BaseFeeMode::AdjustmentFactor => Self::next_base_fee_per_gas(),
+            BaseFeeMode::Eip1559 => BaseFeePerGas::<T>::get(),
+        }
+    }
+
+    /// Ideal 'base fee per gas' according to the fee alignment formula.
+    fn adjustment_factor_bfpg() -> U256 {
+        let ideal_bfpg = T::AdjustmentFactor::get()
+            // Weight factor should be multiplied first since it's a larger number, to avoid precision loss.
+            .saturating_mul_int(T::WeightFactor::get())
+            .saturating_mul(25)
+            .saturating_div(98974);
+
+        U256::from(ideal_bfpg)
+    }
+
+    /// Ideal 'base fee per gas' moving from `old_bfpg` toward the gas target, proportionally to
+    /// the block's gas usage deviation from it. The change is at most `max_step`.
+    fn eip1559_bfpg(old_bfpg: U256, max_step: U256) -> U256 {
+        let gas_target = T::BlockGasUsage::gas_target();
+        if gas_target.is_zero() {
+            return old_bfpg;
+        }
+        let gas_used = T::BlockGasUsage::gas_used();
+
+        let delta = |gas_diff: U256| max_step.saturating_mul(gas_diff.min(gas_target)) / gas_target;
+
+        if gas_used >= gas_target {
+            old_bfpg.saturating_add(delta(gas_used - gas_target))
+        } else {
+            old_bfpg.saturating_sub(delta(gas_target - gas_used))
+        }
+    }
+}
+
 impl<T: Config> fp_evm::FeeCalculator for Pallet<T> {
     fn min_gas_price() -> (U256, Weight) {
         (BaseFeePerGas::<T>::get(), T::WeightInfo::min_gas_price())
@@ -112,6 +112,8 @@ impl pallet_dynamic_evm_base_fee::Config for TestRuntime {
     type AdjustmentFactor = GetAdjustmentFactor;
     type WeightFactor = ConstU128<30_000_000_000_000_000>;
     type StepLimitRatio = StepLimitRation;
+    type BaseFeeMode = GetBaseFeeMode;
+    type BlockGasUsage = MockBlockGasUsage;
     type WeightInfo = ();
 }
 
@@ -140,6 +142,40 @@ impl Get<FixedU128> for GetAdjustmentFactor {
     }
 }
 
+const BASE_FEE_MODE: &[u8] = b":base_fee_mode";
+const BLOCK_GAS_USED: &[u8] = b":block_gas_used";
+
+/// Gas target used by the mock in `EIP-1559` mode.
+pub const BLOCK_GAS_TARGET: u64 = 15_000_000;
+
+/// Helper method to set the base fee mode used by the pallet.
+pub fn set_base_fee_mode(mode: BaseFeeMode) {
+    storage::unhashed::put_raw(&BASE_FEE_MODE, &mode.encode());
+}
+
+/// Helper method to set the gas used by the current block.
+pub fn set_block_gas_used(gas_used: u64) {
+    storage::unhashed::put_raw(&BLOCK_GAS_USED, &U256::from(gas_used).encode());
+}
+
+pub struct GetBaseFeeMode;
+impl Get<BaseFeeMode> for GetBaseFeeMode {
+    fn get() -> BaseFeeMode {
+        storage::unhashed::get::<BaseFeeMode>(&BASE_FEE_MODE).unwrap_or_default()
+    }
+}
+
+pub struct MockBlockGasUsage;
+impl BlockGasUsage for MockBlockGasUsage {
+    fn gas_used() -> U256 {
+        storage::unhashed::get::<U256>(&BLOCK_GAS_USED).unwrap_or_default()
+    }
+
+    fn gas_target() -> U256 {
+        U256::from(BLOCK_GAS_TARGET)
+    }
+}
+
 pub struct ExtBuilder;
 impl ExtBuilder {
     pub fn build() -> TestExternalities {
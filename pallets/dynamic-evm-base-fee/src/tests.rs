@@ -323,3 +323,191 @@ fn lower_upper_bounds_ignored_if_bfpg_is_outside() {
         );
     });
 }
+
+#[test]
+fn eip1559_target_gas_usage_no_change() {
+    ExtBuilder::build().execute_with(|| {
+        set_base_fee_mode(BaseFeeMode::Eip1559);
+        // Adjustment factor must be ignored in this mode
+        set_adjustment_factor(FixedU128::max_value());
+
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        set_block_gas_used(BLOCK_GAS_TARGET);
+
+        DynamicEvmBaseFee::on_finalize(1);
+        assert_eq!(
+            BaseFeePerGas::<TestRuntime>::get(),
+            init_bfpg,
+            "bfpg should remain the same if gas target is hit exactly."
+        );
+    });
+}
+
+#[test]
+fn eip1559_partial_gas_usage_works() {
+    ExtBuilder::build().execute_with(|| {
+        set_base_fee_mode(BaseFeeMode::Eip1559);
+
+        // Half-way between target and double target, should move half of the max step up
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let step_limit = get_max_step_limit();
+        set_block_gas_used(BLOCK_GAS_TARGET * 3 / 2);
+
+        DynamicEvmBaseFee::on_finalize(1);
+        assert_eq!(
+            BaseFeePerGas::<TestRuntime>::get(),
+            init_bfpg + step_limit / 2
+        );
+
+        // Half of the target, should move half of the max step down
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        let step_limit = get_max_step_limit();
+        set_block_gas_used(BLOCK_GAS_TARGET / 2);
+
+        DynamicEvmBaseFee::on_finalize(2);
+        assert_eq!(
+            BaseFeePerGas::<TestRuntime>::get(),
+            init_bfpg - step_limit / 2
+        );
+    });
+}
+
+#[test]
+fn eip1559_sustained_full_blocks_reach_upper_bound() {
+    ExtBuilder::build().execute_with(|| {
+        set_base_fee_mode(BaseFeeMode::Eip1559);
+        // Adjustment factor must be ignored in this mode
+        set_adjustment_factor(FixedU128::zero());
+
+        let max_bfpg = <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get();
+        let mut counter = 1;
+        let iter_limit = 500_000; // safety limit to avoid endless loop
+        while counter <= iter_limit && BaseFeePerGas::<TestRuntime>::get() < max_bfpg {
+            // Full blocks use twice the gas target
+            set_block_gas_used(BLOCK_GAS_TARGET * 2);
+            let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+            let step_limit = get_max_step_limit();
+
+            DynamicEvmBaseFee::on_finalize(counter);
+            assert_eq!(
+                BaseFeePerGas::<TestRuntime>::get(),
+                (init_bfpg + step_limit).min(max_bfpg),
+                "Each full block should increase bfpg by the max step."
+            );
+            counter += 1;
+        }
+
+        assert_eq!(
+            BaseFeePerGas::<TestRuntime>::get(),
+            max_bfpg,
+            "bfpg upper bound not reached with sustained full blocks."
+        );
+
+        // Going over the block gas target must not exceed the step limit nor upper bound
+        set_block_gas_used(BLOCK_GAS_TARGET * 10);
+        DynamicEvmBaseFee::on_finalize(counter);
+        assert_eq!(BaseFeePerGas::<TestRuntime>::get(), max_bfpg);
+    });
+}
+
+#[test]
+fn eip1559_sustained_empty_blocks_reach_lower_bound() {
+    ExtBuilder::build().execute_with(|| {
+        set_base_fee_mode(BaseFeeMode::Eip1559);
+        // Adjustment factor must be ignored in this mode
+        set_adjustment_factor(FixedU128::max_value());
+
+        let min_bfpg = <TestRuntime as pallet::Config>::MinBaseFeePerGas::get();
+        let mut counter = 1;
+        let iter_limit = 500_000; // safety limit to avoid endless loop
+        while counter <= iter_limit && BaseFeePerGas::<TestRuntime>::get() > min_bfpg {
+            set_block_gas_used(0);
+            let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+            let step_limit = get_max_step_limit();
+
+            DynamicEvmBaseFee::on_finalize(counter);
+            assert_eq!(
+                BaseFeePerGas::<TestRuntime>::get(),
+                (init_bfpg - step_limit).max(min_bfpg),
+                "Each empty block should decrease bfpg by the max step."
+            );
+            counter += 1;
+        }
+
+        assert_eq!(
+            BaseFeePerGas::<TestRuntime>::get(),
+            min_bfpg,
+            "bfpg lower bound not reached with sustained empty blocks."
+        );
+    });
+}
+
+#[test]
+fn next_base_fee_per_gas_prediction_works() {
+    ExtBuilder::build().execute_with(|| {
+        for (idx, factor) in [FixedU128::zero(), FixedU128::one(), FixedU128::max_value()]
+            .into_iter()
+            .enumerate()
+        {
+            set_adjustment_factor(factor);
+            let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+            let step_limit = get_max_step_limit();
+
+            // Bounds should respect both the step limit and the configured min & max values
+            let (lower_limit, upper_limit) = DynamicEvmBaseFee::next_base_fee_per_gas_bounds();
+            assert_eq!(
+                lower_limit,
+                <TestRuntime as pallet::Config>::MinBaseFeePerGas::get()
+                    .max(init_bfpg - step_limit)
+            );
+            assert_eq!(
+                upper_limit,
+                <TestRuntime as pallet::Config>::MaxBaseFeePerGas::get()
+                    .min(init_bfpg + step_limit)
+            );
+
+            // Prediction must match the value set at the end of the block
+            let predicted_bfpg = DynamicEvmBaseFee::next_base_fee_per_gas();
+            assert_eq!(
+                BaseFeePerGas::<TestRuntime>::get(),
+                init_bfpg,
+                "Prediction must not change the state."
+            );
+            DynamicEvmBaseFee::on_finalize(idx as u32 + 1);
+            assert_eq!(BaseFeePerGas::<TestRuntime>::get(), predicted_bfpg);
+        }
+    });
+}
+
+#[test]
+fn eip1559_prediction_does_not_count_finalized_gas_usage_twice() {
+    ExtBuilder::build().execute_with(|| {
+        set_base_fee_mode(BaseFeeMode::Eip1559);
+
+        // Full block increases bfpg once finalized
+        let init_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        set_block_gas_used(BLOCK_GAS_TARGET * 2);
+        DynamicEvmBaseFee::on_finalize(1);
+        let finalized_bfpg = BaseFeePerGas::<TestRuntime>::get();
+        assert!(finalized_bfpg > init_bfpg);
+
+        // Gas usage of the finalized block must not move the prediction again
+        assert!(DynamicEvmBaseFee::next_base_fee_per_gas() > finalized_bfpg);
+        assert_eq!(
+            DynamicEvmBaseFee::predicted_next_base_fee_per_gas(),
+            finalized_bfpg
+        );
+    });
+}
+
+#[test]
+fn adjustment_factor_prediction_matches_next_base_fee_per_gas() {
+    ExtBuilder::build().execute_with(|| {
+        set_adjustment_factor(FixedU128::max_value());
+
+        assert_eq!(
+            DynamicEvmBaseFee::predicted_next_base_fee_per_gas(),
+            DynamicEvmBaseFee::next_base_fee_per_gas()
+        );
+    });
+}
@@ -0,0 +1,186 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Emergency Origin Pallet
+//!
+//! ## Overview
+//!
+//! Provides a replacement for the `Sudo` key once it has been removed, e.g. by a democracy
+//! referendum dispatching `Sudo::remove_key` as `Root`.
+//!
+//! `EmergencyOrigin`, which is expected to be the technical committee, can schedule any call to
+//! be dispatched as `Root` through the scheduler. The call is never dispatched immediately, but only
+//! after the mandatory `EmergencyDelay` has passed, which leaves time for `CancelOrigin` to cancel
+//! it if needed.
+//!
+//! The scheduler reports the dispatch result of the call with its own `Dispatched` event, which
+//! carries the task name returned by [`Pallet::task_name`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        schedule::{v3::Named as ScheduleNamed, DispatchTime, HARD_DEADLINE},
+        QueryPreimage, StorePreimage,
+    },
+};
+use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Identifier of a scheduled emergency call.
+pub type EmergencyCallId = u32;
+
+/// Call type dispatched by the pallet.
+pub type CallOf<T> = <T as frame_system::Config>::RuntimeCall;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The caller origin, overarching type of all pallets origins.
+        type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+        /// The scheduler used to dispatch emergency calls after the delay.
+        type Scheduler: ScheduleNamed<
+            BlockNumberFor<Self>,
+            CallOf<Self>,
+            Self::PalletsOrigin,
+            Hasher = Self::Hashing,
+        >;
+
+        /// The preimage provider used to bound emergency calls.
+        type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
+        /// Origin that can schedule a call to be dispatched as `Root`.
+        type EmergencyOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Origin that can cancel a scheduled emergency call.
+        type CancelOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Mandatory number of blocks between scheduling an emergency call and its dispatch.
+        #[pallet::constant]
+        type EmergencyDelay: Get<BlockNumberFor<Self>>;
+
+        /// Weight info
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Emergency call has been scheduled to be dispatched as `Root`.
+        EmergencyCallScheduled {
+            id: EmergencyCallId,
+            call_hash: T::Hash,
+            dispatch_at: BlockNumberFor<T>,
+        },
+        /// Scheduled emergency call has been cancelled.
+        EmergencyCallCancelled { id: EmergencyCallId },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Emergency call doesn't exist or has already been dispatched.
+        EmergencyCallNotFound,
+    }
+
+    /// Identifier of the next scheduled emergency call.
+    #[pallet::storage]
+    pub type NextEmergencyCallId<T: Config> = StorageValue<_, EmergencyCallId, ValueQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Schedule `call` to be dispatched as `Root` once `EmergencyDelay` blocks have passed.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::schedule_emergency_call())]
+        pub fn schedule_emergency_call(
+            origin: OriginFor<T>,
+            call: Box<CallOf<T>>,
+        ) -> DispatchResult {
+            T::EmergencyOrigin::ensure_origin(origin)?;
+
+            let id = NextEmergencyCallId::<T>::get();
+            let dispatch_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::EmergencyDelay::get());
+            let call = T::Preimages::bound(*call)?;
+            let call_hash = call.hash();
+
+            T::Scheduler::schedule_named(
+                Self::task_name(id),
+                DispatchTime::At(dispatch_at),
+                None,
+                HARD_DEADLINE,
+                frame_system::RawOrigin::Root.into(),
+                call,
+            )?;
+            NextEmergencyCallId::<T>::put(id.saturating_add(1));
+
+            Self::deposit_event(Event::EmergencyCallScheduled {
+                id,
+                call_hash,
+                dispatch_at,
+            });
+
+            Ok(())
+        }
+
+        /// Cancel the emergency call `id` before it's dispatched.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::cancel_emergency_call())]
+        pub fn cancel_emergency_call(origin: OriginFor<T>, id: EmergencyCallId) -> DispatchResult {
+            T::CancelOrigin::ensure_origin(origin)?;
+
+            T::Scheduler::cancel_named(Self::task_name(id))
+                .map_err(|_| Error::<T>::EmergencyCallNotFound)?;
+
+            Self::deposit_event(Event::EmergencyCallCancelled { id });
+
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Name of the scheduler task dispatching the emergency call `id`.
+    pub fn task_name(id: EmergencyCallId) -> [u8; 32] {
+        (b"emergency", id).using_encoded(sp_io::hashing::blake2_256)
+    }
+}
@@ -0,0 +1,164 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{mock::*, Error, Event, NextEmergencyCallId};
+
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use pallet_balances::Call as BalancesCall;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+#[test]
+fn schedule_emergency_call_fails_for_invalid_origin() {
+    ExtBuilder::build().execute_with(|| {
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+
+        assert_noop!(
+            EmergencyOrigin::schedule_emergency_call(RuntimeOrigin::signed(1), Box::new(call)),
+            BadOrigin
+        );
+        assert_eq!(NextEmergencyCallId::<Test>::get(), 0);
+    });
+}
+
+#[test]
+fn root_only_call_is_dispatched_after_delay() {
+    ExtBuilder::build().execute_with(|| {
+        let call = RuntimeCall::Balances(BalancesCall::force_set_balance {
+            who: 2,
+            new_free: 42,
+        });
+        let dispatch_at = System::block_number() + EMERGENCY_DELAY;
+
+        assert_ok!(EmergencyOrigin::schedule_emergency_call(
+            RuntimeOrigin::signed(TECHNICAL_COMMITTEE),
+            Box::new(call.clone())
+        ));
+        System::assert_last_event(
+            Event::<Test>::EmergencyCallScheduled {
+                id: 0,
+                call_hash: BlakeTwo256::hash_of(&call),
+                dispatch_at,
+            }
+            .into(),
+        );
+        assert_eq!(NextEmergencyCallId::<Test>::get(), 1);
+
+        // Nothing happens before the delay has passed.
+        run_to_block(dispatch_at - 1);
+        assert_eq!(Balances::free_balance(2), 1000);
+
+        // Call is dispatched as root once the delay has passed.
+        run_to_block(dispatch_at);
+        assert_eq!(Balances::free_balance(2), 42);
+        System::assert_has_event(
+            pallet_scheduler::Event::<Test>::Dispatched {
+                task: (dispatch_at, 0),
+                id: Some(EmergencyOrigin::task_name(0)),
+                result: Ok(()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn sudo_key_can_be_removed_through_emergency_origin() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(Sudo::key(), Some(SUDO_KEY));
+
+        let call = RuntimeCall::Sudo(pallet_sudo::Call::remove_key {});
+        assert_ok!(EmergencyOrigin::schedule_emergency_call(
+            RuntimeOrigin::signed(TECHNICAL_COMMITTEE),
+            Box::new(call)
+        ));
+
+        let dispatch_at = System::block_number() + EMERGENCY_DELAY;
+        run_to_block(dispatch_at);
+        assert_eq!(Sudo::key(), None);
+        System::assert_has_event(
+            pallet_scheduler::Event::<Test>::Dispatched {
+                task: (dispatch_at, 0),
+                id: Some(EmergencyOrigin::task_name(0)),
+                result: Ok(()),
+            }
+            .into(),
+        );
+
+        // Root-only calls still work through the emergency origin.
+        let call = RuntimeCall::Balances(BalancesCall::force_set_balance {
+            who: 3,
+            new_free: 7,
+        });
+        assert_ok!(EmergencyOrigin::schedule_emergency_call(
+            RuntimeOrigin::signed(TECHNICAL_COMMITTEE),
+            Box::new(call)
+        ));
+        run_to_block(System::block_number() + EMERGENCY_DELAY);
+        assert_eq!(Balances::free_balance(3), 7);
+    });
+}
+
+#[test]
+fn cancel_emergency_call_works() {
+    ExtBuilder::build().execute_with(|| {
+        let call = RuntimeCall::Balances(BalancesCall::force_set_balance {
+            who: 2,
+            new_free: 42,
+        });
+        assert_ok!(EmergencyOrigin::schedule_emergency_call(
+            RuntimeOrigin::signed(TECHNICAL_COMMITTEE),
+            Box::new(call)
+        ));
+
+        assert_noop!(
+            EmergencyOrigin::cancel_emergency_call(RuntimeOrigin::signed(TECHNICAL_COMMITTEE), 0),
+            BadOrigin
+        );
+        assert_ok!(EmergencyOrigin::cancel_emergency_call(
+            RuntimeOrigin::root(),
+            0
+        ));
+        System::assert_last_event(Event::<Test>::EmergencyCallCancelled { id: 0 }.into());
+
+        // Cancelled call is never dispatched.
+        run_to_block(System::block_number() + EMERGENCY_DELAY);
+        assert_eq!(Balances::free_balance(2), 1000);
+    });
+}
+
+#[test]
+fn cancel_emergency_call_fails_for_unknown_call() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            EmergencyOrigin::cancel_emergency_call(RuntimeOrigin::root(), 0),
+            Error::<Test>::EmergencyCallNotFound
+        );
+
+        // Dispatched call can no longer be cancelled.
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        assert_ok!(EmergencyOrigin::schedule_emergency_call(
+            RuntimeOrigin::signed(TECHNICAL_COMMITTEE),
+            Box::new(call)
+        ));
+        run_to_block(System::block_number() + EMERGENCY_DELAY);
+        assert_noop!(
+            EmergencyOrigin::cancel_emergency_call(RuntimeOrigin::root(), 0),
+            Error::<Test>::EmergencyCallNotFound
+        );
+    });
+}
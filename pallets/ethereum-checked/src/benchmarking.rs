@@ -53,6 +53,37 @@ mod benchmarks {
         assert_eq!(Nonce::<T>::get(), U256::one())
     }
 
+    #[benchmark]
+    fn signed_transact_without_apply() {
+        let caller = T::BenchmarkHelper::signed_transact_account();
+        let target =
+            H160::from_slice(&hex::decode("dfb975d018f03994a3b943808e3aa0964bd78463").unwrap());
+        // Calling `store(3)`
+        let input = EthereumTxInput::try_from(
+            hex::decode("6057361d0000000000000000000000000000000000000000000000000000000000000003")
+                .unwrap(),
+        )
+        .unwrap();
+        let checked_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target,
+            value: U256::zero(),
+            input,
+            maybe_access_list: None,
+        };
+
+        #[block]
+        {
+            Pallet::<T>::signed_transact_without_apply(
+                frame_system::RawOrigin::Signed(caller).into(),
+                checked_tx,
+            )
+            .unwrap();
+        }
+
+        assert_eq!(Nonce::<T>::get(), U256::one())
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
@@ -29,16 +29,23 @@
 //! will add the same dummy signature to them. To avoid transaction hash collisions, a global
 //! nonce shared with all users are used.
 //!
+//! Cross-VM transactions pay for gas at the current EVM base fee, same as regular Ethereum
+//! transactions. XCM transactions are already paid for on XCM execution, and signed transactions
+//! by the fee of the extrinsic, whose weight includes the gas limit, so their gas price is zero.
+//!
 //! ## Interface
 //!
 //! ### Dispatch-able calls
 //!
 //! - `transact`: transact an Ethereum transaction. Similar to `pallet_ethereum::Transact`,
 //! but is only for XCM remote call.
+//! - `signed_transact`: transact an Ethereum transaction from a signed account, as its mapped
+//! H160 address. Allows Substrate accounts, multisigs and proxies to call EVM contracts.
 //!
 //! ### Implementation
 //!
-//! - Implements `CheckedEthereumTransact` trait.
+//! - Implements `CheckedEthereumTransact` trait. XVM static calls are executed directly in EVM
+//! static context, without being applied as Ethereum transactions.
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -47,16 +54,21 @@ use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
 use ethereum_types::{H160, U256};
+use evm::{
+    executor::stack::{StackExecutor, StackSubstateMetadata},
+    Capture, Context as EvmContext, Handler,
+};
 use fp_ethereum::{TransactionData, ValidatedTransaction};
 use fp_evm::{
     CallInfo, CallOrCreateInfo, CheckEvmTransaction, CheckEvmTransactionConfig, ExitReason,
-    ExitSucceed, TransactionValidationError,
+    ExitSucceed, TransactionValidationError, UsedGas, Vicinity, WeightInfo as EvmWeightInfo,
 };
-use pallet_evm::GasWeightMapping;
+use pallet_evm::{runner::stack::SubstrateStackState, FeeCalculator, GasWeightMapping};
 
 use frame_support::{
     dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
     pallet_prelude::*,
+    traits::Contains,
 };
 use frame_system::pallet_prelude::*;
 #[cfg(feature = "runtime-benchmarks")]
@@ -90,6 +102,13 @@ pub enum RawOrigin<AccountId> {
     XcmEthereumTx(AccountId),
 }
 
+/// Values needed to benchmark the pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+    /// An account allowed to `signed_transact`, with a mapped H160 address.
+    fn signed_transact_account() -> AccountId;
+}
+
 /// Ensure the origin is with XCM calls.
 pub struct EnsureXcmEthereumTx<AccountId>(PhantomData<AccountId>);
 impl<O: Into<Result<RawOrigin<AccountId>, O>> + From<RawOrigin<AccountId>>, AccountId: Decode>
@@ -118,6 +137,8 @@ pub enum CheckedEthereumTxKind {
     Xcm,
     /// The tx is from cross-VM call.
     Xvm,
+    /// The tx is from a signed Substrate account.
+    Signed,
 }
 
 #[frame_support::pallet]
@@ -147,8 +168,15 @@ pub mod pallet {
         /// Origin for `transact` call.
         type XcmTransactOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
+        /// Filter of accounts allowed to use `signed_transact` call.
+        type SignedTransactFilter: Contains<Self::AccountId>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
+
+        /// Helper to provide the values needed by the benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId>;
     }
 
     #[pallet::origin]
@@ -159,6 +187,14 @@ pub mod pallet {
     #[pallet::storage]
     pub type Nonce<T: Config> = StorageValue<_, U256, ValueQuery>;
 
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The account is not allowed to transact.
+        SignedTransactFiltered,
+        /// The account has no mapped H160 address.
+        UnmappedAccount,
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Transact an Ethereum transaction. Similar to `pallet_ethereum::Transact`,
@@ -178,10 +214,38 @@ pub mod pallet {
             )
             .map(|(post_info, _)| post_info)
         }
+
+        /// Transact an Ethereum transaction from a signed account, as its mapped H160 address.
+        ///
+        /// The account must be allowed by `SignedTransactFilter` and have a mapped H160 address.
+        /// Gas is paid by the fee of the extrinsic, the EVM gas price is zero.
+        #[pallet::call_index(1)]
+        #[pallet::weight({
+            let weight_limit = T::GasWeightMapping::gas_to_weight(tx.gas_limit.unique_saturated_into(), false);
+            weight_limit.saturating_add(WeightInfoOf::<T>::signed_transact_without_apply())
+        })]
+        pub fn signed_transact(
+            origin: OriginFor<T>,
+            tx: CheckedEthereumTx,
+        ) -> DispatchResultWithPostInfo {
+            let source = Self::ensure_signed_source(origin)?;
+            Self::do_transact(source, tx, CheckedEthereumTxKind::Signed, false)
+                .map(|(post_info, _)| post_info)
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Ensure the origin is a signed account allowed to transact, returning its mapped H160.
+    fn ensure_signed_source(origin: OriginFor<T>) -> Result<H160, DispatchError> {
+        let who = ensure_signed(origin)?;
+        ensure!(
+            T::SignedTransactFilter::contains(&who),
+            Error::<T>::SignedTransactFiltered
+        );
+        T::AddressMapper::to_h160(&who).ok_or_else(|| Error::<T>::UnmappedAccount.into())
+    }
+
     /// Validate and execute the checked tx. Only `Call` transaction action is allowed.
     fn do_transact(
         source: H160,
@@ -191,8 +255,10 @@ impl<T: Config> Pallet<T> {
     ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo> {
         let chain_id = T::ChainId::get();
         let nonce = Nonce::<T>::get();
-        let tx = checked_tx.into_ethereum_tx(Nonce::<T>::get(), chain_id);
+        let gas_price = Self::gas_price(&tx_kind);
+        let tx = checked_tx.into_ethereum_tx(Nonce::<T>::get(), chain_id, gas_price);
         let tx_data: TransactionData = (&tx).into();
+        let (who, _) = pallet_evm::Pallet::<T>::account_basic(&source);
 
         let (weight_limit, proof_size_base_cost) =
             match <T as pallet_evm::Config>::GasWeightMapping::gas_to_weight(
@@ -212,7 +278,7 @@ impl<T: Config> Pallet<T> {
             CheckEvmTransactionConfig {
                 evm_config: T::config(),
                 block_gas_limit: U256::from(Self::block_gas_limit(&tx_kind)),
-                base_fee: U256::zero(),
+                base_fee: gas_price,
                 chain_id,
                 is_transactional: true,
             },
@@ -220,8 +286,10 @@ impl<T: Config> Pallet<T> {
             weight_limit,
             proof_size_base_cost,
         )
-        // Gas limit validation. The fee payment has been validated as the tx is `checked`.
+        // Gas limit and fee payment validation. Other checks are not needed as the tx is `checked`.
         .validate_common()
+        .and_then(|v| v.with_base_fee())
+        .and_then(|v| v.with_balance_for(&who))
         .map_err(|_| DispatchErrorWithPostInfo {
             post_info: PostDispatchInfo {
                 // actual_weight = overhead - nonce_write_1
@@ -266,11 +334,99 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Call the tx target in static context. Nothing is applied, so there is no nonce
+    /// increment, fee payment or pending Ethereum transaction.
+    fn do_static_call(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo> {
+        let block_gas_limit = Self::block_gas_limit(&CheckedEthereumTxKind::Xvm);
+        let gas_limit: u64 = checked_tx
+            .gas_limit
+            .min(U256::from(block_gas_limit))
+            .unique_saturated_into();
+        let weight_limit = T::GasWeightMapping::gas_to_weight(gas_limit, true);
+        let weight_info = EvmWeightInfo::new_from_weight_limit(
+            (weight_limit.proof_size() > 0).then_some(weight_limit),
+            Some(WeightInfoOf::<T>::transact_without_apply().proof_size()),
+        )
+        .map_err(|_| DispatchErrorWithPostInfo {
+            post_info: PostDispatchInfo {
+                actual_weight: Some(Weight::zero()),
+                pays_fee: Pays::Yes,
+            },
+            error: DispatchError::Other("Invalid static call weight limit"),
+        })?;
+
+        let vicinity = Vicinity {
+            gas_price: U256::zero(),
+            origin: source,
+        };
+        let config = T::config();
+        let precompiles = T::PrecompilesValue::get();
+        let state = SubstrateStackState::<T>::new(
+            &vicinity,
+            StackSubstateMetadata::new(gas_limit, config),
+            weight_info,
+        );
+        let mut executor = StackExecutor::new_with_precompiles(state, config, &precompiles);
+
+        // `is_static` makes the EVM reject any state modification, same as `STATICCALL`.
+        let context = EvmContext {
+            address: checked_tx.target,
+            caller: source,
+            apparent_value: U256::zero(),
+        };
+        let (exit_reason, value) = match executor.call(
+            checked_tx.target,
+            None,
+            checked_tx.input.into_inner(),
+            Some(gas_limit),
+            true,
+            context,
+        ) {
+            Capture::Exit(result) => result,
+            Capture::Trap(interrupt) => match interrupt {},
+        };
+        let used_gas = executor.used_gas();
+
+        Ok((
+            PostDispatchInfo {
+                actual_weight: Some(T::GasWeightMapping::gas_to_weight(used_gas, true)),
+                pays_fee: Pays::Yes,
+            },
+            CallInfo {
+                exit_reason,
+                value,
+                used_gas: UsedGas {
+                    standard: used_gas.into(),
+                    effective: used_gas.into(),
+                },
+                weight_info: None,
+                logs: Default::default(),
+            },
+        ))
+    }
+
+    /// Gas price paid by the tx, based on the tx kind.
+    fn gas_price(tx_kind: &CheckedEthereumTxKind) -> U256 {
+        match tx_kind {
+            // XCM remote calls are already charged by XCM execution, and signed transactions
+            // by the extrinsic fee.
+            CheckedEthereumTxKind::Xcm | CheckedEthereumTxKind::Signed => U256::zero(),
+            CheckedEthereumTxKind::Xvm => T::FeeCalculator::min_gas_price().0,
+        }
+    }
+
     /// Block gas limit calculation based on the tx kind.
     fn block_gas_limit(tx_kind: &CheckedEthereumTxKind) -> u64 {
         let weight_limit = match tx_kind {
             CheckedEthereumTxKind::Xcm => T::ReservedXcmpWeight::get(),
             CheckedEthereumTxKind::Xvm => T::XvmTxWeightLimit::get(),
+            // Signed transactions have the same limit as regular Ethereum transactions.
+            CheckedEthereumTxKind::Signed => {
+                return T::BlockGasLimit::get().unique_saturated_into();
+            }
         };
         T::GasWeightMapping::weight_to_gas(weight_limit)
     }
@@ -291,6 +447,18 @@ impl<T: Config> Pallet<T> {
         )
         .map(|(post_info, _)| post_info)
     }
+
+    /// Similar to `signed_transact` dispatch-able call, but not to apply the transaction.
+    /// This is to benchmark the weight overhead in addition to `gas_limit`.
+    #[cfg(feature = "runtime-benchmarks")]
+    pub fn signed_transact_without_apply(
+        origin: OriginFor<T>,
+        tx: CheckedEthereumTx,
+    ) -> DispatchResultWithPostInfo {
+        let source = Self::ensure_signed_source(origin)?;
+        Self::do_transact(source, tx, CheckedEthereumTxKind::Signed, true)
+            .map(|(post_info, _)| post_info)
+    }
 }
 
 impl<T: Config> CheckedEthereumTransact for Pallet<T> {
@@ -300,4 +468,11 @@ impl<T: Config> CheckedEthereumTransact for Pallet<T> {
     ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo> {
         Self::do_transact(source, checked_tx, CheckedEthereumTxKind::Xvm, false)
     }
+
+    fn xvm_static_call(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo> {
+        Self::do_static_call(source, checked_tx)
+    }
 }
@@ -25,7 +25,7 @@ use ethereum_types::H256;
 
 use frame_support::{
     assert_ok, construct_runtime, parameter_types,
-    traits::{ConstU128, ConstU64, FindAuthor},
+    traits::{ConstU128, ConstU64, Contains, FindAuthor},
     weights::Weight,
 };
 use pallet_ethereum::PostLogContent;
@@ -204,6 +204,13 @@ parameter_types! {
     pub TxWeightLimit: Weight = Weight::from_parts(u64::max_value(), 0);
 }
 
+pub struct SignedTransactFilter;
+impl Contains<AccountId32> for SignedTransactFilter {
+    fn contains(account_id: &AccountId32) -> bool {
+        account_id != &CHARLIE
+    }
+}
+
 impl pallet_ethereum_checked::Config for TestRuntime {
     type ReservedXcmpWeight = TxWeightLimit;
     type XvmTxWeightLimit = TxWeightLimit;
@@ -211,7 +218,19 @@ impl pallet_ethereum_checked::Config for TestRuntime {
     type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
     type AddressMapper = MockAddressMapper;
     type XcmTransactOrigin = EnsureXcmEthereumTx<AccountId32>;
+    type SignedTransactFilter = SignedTransactFilter;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_ethereum_checked::BenchmarkHelper<AccountId32> for MockBenchmarkHelper {
+    fn signed_transact_account() -> AccountId32 {
+        BOB
+    }
 }
 
 pub(crate) type AccountId = AccountId32;
@@ -220,6 +239,7 @@ pub(crate) type Balance = u128;
 pub const ALICE: AccountId32 = AccountId32::new([0u8; 32]);
 pub const BOB: AccountId32 = AccountId32::new([1u8; 32]);
 pub const CHARLIE: AccountId32 = AccountId32::new([2u8; 32]);
+pub const DAVE: AccountId32 = AccountId32::new([3u8; 32]);
 
 type Block = frame_system::mocking::MockBlock<TestRuntime>;
 
@@ -280,6 +300,7 @@ impl Default for ExtBuilder {
                 (ALICE, 1_000_000_000_000),
                 (BOB, 1_000_000_000_000),
                 (CHARLIE, 1_000_000_000_000),
+                (DAVE, 1_000_000_000_000),
             ],
         }
     }
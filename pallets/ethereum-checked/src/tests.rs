@@ -23,6 +23,7 @@ use mock::*;
 
 use astar_primitives::ethereum_checked::EthereumTxInput;
 use ethereum::{ReceiptV3 as Receipt, TransactionV2 as Transaction};
+use ethereum_types::H256;
 pub use fp_rpc::TransactionStatus;
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::DispatchError;
@@ -152,3 +153,194 @@ fn no_hash_collision() {
         assert_eq!(tx_hashes.len(), 15);
     });
 }
+
+#[test]
+fn xvm_transact_pays_base_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: Some(vec![(contract_address(), vec![H256::zero()])]),
+        };
+
+        // XCM transactions are free.
+        let balance_before = Balances::free_balance(ALICE);
+        assert_ok!(EthereumChecked::transact(
+            RawOrigin::XcmEthereumTx(ALICE).into(),
+            store_tx.clone()
+        ));
+        assert_eq!(Balances::free_balance(ALICE), balance_before);
+
+        // XVM transactions pay the base fee for the used gas.
+        let (_, call_info) =
+            EthereumChecked::xvm_transact(ALICE_H160, store_tx).expect("failed to transact");
+        let (base_fee, _) = MockFeeCalculator::min_gas_price();
+        assert_eq!(
+            Balances::free_balance(ALICE),
+            balance_before - (base_fee * call_info.used_gas.effective).as_u128()
+        );
+
+        let pending = pallet_ethereum::Pending::<TestRuntime>::get();
+        match pending[1] {
+            (Transaction::EIP1559(ref t), _, _) => {
+                assert_eq!(t.max_fee_per_gas, base_fee);
+                assert_eq!(t.access_list.len(), 1);
+            }
+            _ => panic!("unexpected transaction type"),
+        }
+    });
+}
+
+#[test]
+fn xvm_transact_fails_if_fee_cannot_be_paid() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000_000_000_000u128),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+        };
+        assert!(EthereumChecked::xvm_transact(ALICE_H160, store_tx).is_err());
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::zero());
+    });
+}
+
+#[test]
+fn signed_transact_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+        };
+        let balance_before = Balances::free_balance(BOB);
+        assert_ok!(EthereumChecked::signed_transact(
+            RuntimeOrigin::signed(BOB),
+            store_tx
+        ));
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::one());
+
+        // Executed as the mapped address, gas is paid by the extrinsic fee only.
+        let pending = pallet_ethereum::Pending::<TestRuntime>::get();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].1.from, BOB_H160);
+        match pending[0].0 {
+            Transaction::EIP1559(ref t) => assert_eq!(t.max_fee_per_gas, U256::zero()),
+            _ => panic!("unexpected transaction type"),
+        }
+        assert_eq!(Balances::free_balance(BOB), balance_before);
+    });
+}
+
+#[test]
+fn signed_transact_checks_account() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+        };
+        assert_noop!(
+            EthereumChecked::signed_transact(RuntimeOrigin::signed(CHARLIE), store_tx.clone()),
+            Error::<TestRuntime>::SignedTransactFiltered
+        );
+        assert_noop!(
+            EthereumChecked::signed_transact(RuntimeOrigin::signed(DAVE), store_tx.clone()),
+            Error::<TestRuntime>::UnmappedAccount
+        );
+        assert_noop!(
+            EthereumChecked::signed_transact(RuntimeOrigin::root(), store_tx),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn xvm_static_call_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `store(3)`
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+        };
+        assert_ok!(EthereumChecked::xvm_transact(ALICE_H160, store_tx));
+
+        let retrieve_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `retrieve`
+            input: bounded_input("2e64cec1"),
+            maybe_access_list: None,
+        };
+        let balance_before = Balances::free_balance(ALICE);
+        let (post_info, call_info) =
+            EthereumChecked::xvm_static_call(ALICE_H160, retrieve_tx).expect("failed to retrieve");
+        assert_eq!(
+            call_info.exit_reason,
+            ExitReason::Succeed(ExitSucceed::Returned)
+        );
+        assert_eq!(U256::from_big_endian(&(call_info.value)), 3.into());
+        assert!(post_info.actual_weight.unwrap().ref_time() > 0);
+
+        // Static calls are not applied as transactions.
+        assert_eq!(Nonce::<TestRuntime>::get(), U256::one());
+        assert_eq!(pallet_ethereum::Pending::<TestRuntime>::get().len(), 1);
+        assert_eq!(Balances::free_balance(ALICE), balance_before);
+    });
+}
+
+#[test]
+fn xvm_static_call_rejects_state_changes() {
+    ExtBuilder::default().build().execute_with(|| {
+        let store_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `store(3)`, which `SSTORE`s the number
+            input: bounded_input(
+                "6057361d0000000000000000000000000000000000000000000000000000000000000003",
+            ),
+            maybe_access_list: None,
+        };
+        let (_, call_info) =
+            EthereumChecked::xvm_static_call(ALICE_H160, store_tx).expect("failed to call");
+        assert!(matches!(call_info.exit_reason, ExitReason::Error(_)));
+
+        let retrieve_tx = CheckedEthereumTx {
+            gas_limit: U256::from(1_000_000),
+            target: contract_address(),
+            value: U256::zero(),
+            // Calling `retrieve`
+            input: bounded_input("2e64cec1"),
+            maybe_access_list: None,
+        };
+        let (_, call_info) =
+            EthereumChecked::xvm_transact(ALICE_H160, retrieve_tx).expect("failed to retrieve");
+        assert_eq!(U256::from_big_endian(&(call_info.value)), U256::zero());
+    });
+}
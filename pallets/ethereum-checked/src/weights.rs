@@ -49,6 +49,7 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_ethereum_checked.
 pub trait WeightInfo {
 	fn transact_without_apply() -> Weight;
+	fn signed_transact_without_apply() -> Weight;
 }
 
 /// Weights for pallet_ethereum_checked using the Substrate node and recommended hardware.
@@ -67,6 +68,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: EthereumChecked Nonce (r:1 w:1)
+	/// Proof: EthereumChecked Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn signed_transact_without_apply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3533`
+		// Minimum execution time: 12_417_000 picoseconds.
+		Weight::from_parts(12_874_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -84,4 +100,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: EthereumChecked Nonce (r:1 w:1)
+	/// Proof: EthereumChecked Nonce (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn signed_transact_without_apply() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `3533`
+		// Minimum execution time: 12_417_000 picoseconds.
+		Weight::from_parts(12_874_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,204 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! # Fee Distribution Pallet
+//!
+//! ## Overview
+//!
+//! Handles the transaction fees of both **Substrate native transactions** and **EVM transactions**,
+//! by implementing `OnUnbalanced` for the fee & tip imbalances:
+//!
+//! * `BaseFeeBurnRatio` share of the base fee is burned.
+//! * The rest of the base fee is deposited into the treasury account, derived from `TreasuryPalletId`.
+//! * Tips are paid to the block author, as found by `FindAuthor`.
+//!   In case the author cannot be found, tips are deposited into the treasury as well.
+//!
+//! The amounts are accumulated during the block, and a single `FeesDistributed` event with the
+//! per-block totals is deposited in `on_finalize`.
+//!
+//! ## Interface
+//!
+//! The pallet implements `OnUnbalanced`, expecting fees first & tips second (`fees_then_tips`),
+//! which is the convention used by `pallet-transaction-payment`'s `CurrencyAdapter`.
+//!
+//! `distribute_tips` can be used by fee handlers which pay tips separately from the base fee,
+//! e.g. `pallet-evm`'s `OnChargeEVMTransaction::pay_priority_fee`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Currency, FindAuthor, Imbalance, OnUnbalanced},
+    PalletId,
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+    traits::{AccountIdConversion, Saturating, Zero},
+    Perbill,
+};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+    <T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
+/// Fees distributed during a single block.
+#[derive(
+    Encode, Decode, MaxEncodedLen, Copy, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo,
+)]
+pub struct FeeTotals<Balance> {
+    /// Amount of base fee burned.
+    pub burned: Balance,
+    /// Amount of base fee deposited into the treasury.
+    pub treasury: Balance,
+    /// Amount of tips paid to the block author.
+    pub tips: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency in which the fees are paid.
+        type Currency: Currency<Self::AccountId>;
+
+        /// Share of the base fee which is burned. The rest is deposited into the treasury.
+        #[pallet::constant]
+        type BaseFeeBurnRatio: Get<Perbill>;
+
+        /// Treasury pallet Id, used to derive the treasury account.
+        #[pallet::constant]
+        type TreasuryPalletId: Get<PalletId>;
+
+        /// Used to find the block author, who receives the tips.
+        type FindAuthor: FindAuthor<Self::AccountId>;
+    }
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Fees of the block have been distributed.
+        FeesDistributed {
+            burned: BalanceOf<T>,
+            treasury: BalanceOf<T>,
+            tips: BalanceOf<T>,
+        },
+    }
+
+    /// Fees distributed in the current block, cleared in `on_finalize`.
+    #[pallet::storage]
+    pub type BlockFeeTotals<T: Config> = StorageValue<_, FeeTotals<BalanceOf<T>>, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+            // Account for `on_finalize`
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        fn on_finalize(_: BlockNumberFor<T>) {
+            let totals = BlockFeeTotals::<T>::take();
+            if totals != Default::default() {
+                Self::deposit_event(Event::<T>::FeesDistributed {
+                    burned: totals.burned,
+                    treasury: totals.treasury,
+                    tips: totals.tips,
+                });
+            }
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Account of the treasury receiving its share of the base fee.
+    pub fn treasury_account_id() -> T::AccountId {
+        T::TreasuryPalletId::get().into_account_truncating()
+    }
+
+    /// Burn `BaseFeeBurnRatio` of the `fees`, and deposit the rest into the treasury.
+    pub fn distribute_fees(fees: NegativeImbalanceOf<T>) {
+        let burn_amount = T::BaseFeeBurnRatio::get() * fees.peek();
+        let (to_burn, to_treasury) = fees.split(burn_amount);
+        let (burned, treasury) = (to_burn.peek(), to_treasury.peek());
+
+        // Dropping the imbalance reduces the total issuance
+        drop(to_burn);
+        T::Currency::resolve_creating(&Self::treasury_account_id(), to_treasury);
+
+        BlockFeeTotals::<T>::mutate(|totals| {
+            totals.burned.saturating_accrue(burned);
+            totals.treasury.saturating_accrue(treasury);
+        });
+    }
+
+    /// Pay the `tips` to the block author, or deposit them into the treasury if the author is unknown.
+    pub fn distribute_tips(tips: NegativeImbalanceOf<T>) {
+        let amount = tips.peek();
+        if amount.is_zero() {
+            return;
+        }
+
+        let digest = frame_system::Pallet::<T>::digest();
+        let pre_runtime_digests = digest.logs().iter().filter_map(|d| d.as_pre_runtime());
+        match T::FindAuthor::find_author(pre_runtime_digests) {
+            Some(author) => {
+                T::Currency::resolve_creating(&author, tips);
+                BlockFeeTotals::<T>::mutate(|totals| totals.tips.saturating_accrue(amount));
+            }
+            None => {
+                T::Currency::resolve_creating(&Self::treasury_account_id(), tips);
+                BlockFeeTotals::<T>::mutate(|totals| totals.treasury.saturating_accrue(amount));
+            }
+        }
+    }
+}
+
+impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Pallet<T> {
+    // A single imbalance, e.g. the EVM base fee, is distributed as fees.
+    fn on_nonzero_unbalanced(fees: NegativeImbalanceOf<T>) {
+        Self::distribute_fees(fees);
+    }
+
+    fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalanceOf<T>>)
+    where
+        NegativeImbalanceOf<T>: Imbalance<B>,
+    {
+        if let Some(fees) = fees_then_tips.next() {
+            Self::distribute_fees(fees);
+            if let Some(tips) = fees_then_tips.next() {
+                Self::distribute_tips(tips);
+            }
+        }
+    }
+}
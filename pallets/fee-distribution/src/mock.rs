@@ -0,0 +1,165 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{self as pallet_fee_distribution, *};
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConsensusEngineId, ConstU128, ConstU32},
+    weights::constants::RocksDbWeight,
+};
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, ConsensusEngineId,
+};
+use sp_std::cell::RefCell;
+
+pub(crate) type AccountId = u64;
+pub(crate) type Balance = u128;
+
+pub(crate) const ALICE: AccountId = 1;
+pub(crate) const AUTHOR: AccountId = 100;
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type Nonce = u64;
+    type RuntimeCall = RuntimeCall;
+    type Block = Block;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU32<250>;
+    type DbWeight = RocksDbWeight;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type RuntimeTask = RuntimeTask;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<4>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type FreezeIdentifier = ();
+    type RuntimeFreezeReason = ();
+    type MaxFreezes = ConstU32<0>;
+}
+
+thread_local! {
+    static BLOCK_AUTHOR: RefCell<Option<AccountId>> = RefCell::new(Some(AUTHOR));
+}
+
+/// Helper method to set the block author found by the pallet.
+pub fn set_block_author(author: Option<AccountId>) {
+    BLOCK_AUTHOR.with(|v| *v.borrow_mut() = author);
+}
+
+pub struct MockFindAuthor;
+impl FindAuthor<AccountId> for MockFindAuthor {
+    fn find_author<'a, I>(_digests: I) -> Option<AccountId>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        BLOCK_AUTHOR.with(|v| *v.borrow())
+    }
+}
+
+parameter_types! {
+    pub const BaseFeeBurnRatio: Perbill = Perbill::from_percent(80);
+    pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+}
+
+impl pallet_fee_distribution::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BaseFeeBurnRatio = BaseFeeBurnRatio;
+    type TreasuryPalletId = TreasuryPalletId;
+    type FindAuthor = MockFindAuthor;
+}
+
+type Block = frame_system::mocking::MockBlockU32<Test>;
+
+construct_runtime!(
+    pub struct Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        FeeDistribution: pallet_fee_distribution,
+    }
+);
+
+pub struct ExtBuilder;
+impl ExtBuilder {
+    pub fn build() -> TestExternalities {
+        let mut storage = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+
+        pallet_balances::GenesisConfig::<Test> {
+            balances: vec![(ALICE, 1_000_000)],
+        }
+        .assimilate_storage(&mut storage)
+        .ok();
+
+        let mut ext = TestExternalities::from(storage);
+        ext.execute_with(|| {
+            set_block_author(Some(AUTHOR));
+            System::set_block_number(1);
+        });
+        ext
+    }
+}
+
+/// Withdraw `amount` from `ALICE` as a fee imbalance.
+pub fn withdraw_fee(amount: Balance) -> NegativeImbalanceOf<Test> {
+    use frame_support::traits::{ExistenceRequirement, WithdrawReasons};
+
+    Balances::withdraw(
+        &ALICE,
+        amount,
+        WithdrawReasons::TRANSACTION_PAYMENT,
+        ExistenceRequirement::KeepAlive,
+    )
+    .expect("Alice has enough funds")
+}
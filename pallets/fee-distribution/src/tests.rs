@@ -0,0 +1,114 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use super::*;
+use mock::*;
+
+use frame_support::traits::{Hooks, OnUnbalanced};
+
+#[test]
+fn fees_are_burned_and_sent_to_treasury() {
+    ExtBuilder::build().execute_with(|| {
+        let init_issuance = Balances::total_issuance();
+        let treasury = FeeDistribution::treasury_account_id();
+
+        FeeDistribution::on_unbalanced(withdraw_fee(1_000));
+
+        // 80% burned, 20% to treasury
+        assert_eq!(Balances::free_balance(treasury), 200);
+        assert_eq!(Balances::total_issuance(), init_issuance - 800);
+        assert_eq!(
+            BlockFeeTotals::<Test>::get(),
+            FeeTotals {
+                burned: 800,
+                treasury: 200,
+                tips: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn tips_are_paid_to_block_author() {
+    ExtBuilder::build().execute_with(|| {
+        let treasury = FeeDistribution::treasury_account_id();
+
+        FeeDistribution::on_unbalanceds([withdraw_fee(1_000), withdraw_fee(50)].into_iter());
+
+        assert_eq!(Balances::free_balance(AUTHOR), 50);
+        assert_eq!(Balances::free_balance(treasury), 200);
+        assert_eq!(
+            BlockFeeTotals::<Test>::get(),
+            FeeTotals {
+                burned: 800,
+                treasury: 200,
+                tips: 50,
+            }
+        );
+    });
+}
+
+#[test]
+fn tips_go_to_treasury_if_author_unknown() {
+    ExtBuilder::build().execute_with(|| {
+        set_block_author(None);
+        let treasury = FeeDistribution::treasury_account_id();
+
+        FeeDistribution::distribute_tips(withdraw_fee(50));
+
+        assert_eq!(Balances::free_balance(AUTHOR), 0);
+        assert_eq!(Balances::free_balance(treasury), 50);
+        assert_eq!(
+            BlockFeeTotals::<Test>::get(),
+            FeeTotals {
+                burned: 0,
+                treasury: 50,
+                tips: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn block_totals_are_accumulated_and_reported() {
+    ExtBuilder::build().execute_with(|| {
+        // Native transaction with a tip
+        FeeDistribution::on_unbalanceds([withdraw_fee(1_000), withdraw_fee(50)].into_iter());
+        // EVM transaction, base fee & tip are handled separately
+        FeeDistribution::on_unbalanced(withdraw_fee(2_000));
+        FeeDistribution::distribute_tips(withdraw_fee(30));
+
+        FeeDistribution::on_finalize(1);
+        System::assert_last_event(
+            Event::<Test>::FeesDistributed {
+                burned: 2_400,
+                treasury: 600,
+                tips: 80,
+            }
+            .into(),
+        );
+        assert!(!BlockFeeTotals::<Test>::exists());
+
+        // No event if no fees were distributed in the block
+        System::reset_events();
+        FeeDistribution::on_finalize(2);
+        assert!(System::events().is_empty());
+    });
+}
@@ -0,0 +1,63 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::{Balance, BlockNumber};
+pub use pallet_inflation::{CycleNumber, EraInfo, InflationConfiguration, InflationParameters};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::Perquintill;
+use sp_std::vec::Vec;
+
+/// Current state of the inflation.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct InflationInfo {
+    /// Active inflation configuration.
+    pub config: InflationConfiguration,
+    /// Inflation parameters used for the next recalculation.
+    pub params: InflationParameters,
+    /// Current era, period & subperiod.
+    pub era_info: EraInfo,
+    /// Current cycle number.
+    pub cycle: CycleNumber,
+    /// Number of blocks until the recalculation era starts.
+    pub blocks_until_recalculation: BlockNumber,
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// API for querying the state of the inflation pallet.
+    pub trait InflationApi {
+        /// Current era, period & subperiod.
+        fn current_era_info() -> EraInfo;
+
+        /// Max inflation rates of the `count` cycles following the current one, according to the inflation curve.
+        fn upcoming_inflation_rates(count: u32) -> Vec<(CycleNumber, Perquintill)>;
+
+        /// Active config & params, current era & cycle and the blocks remaining until the recalculation.
+        fn inflation_info() -> InflationInfo;
+
+        /// Projected total issuance at the end of the `count` cycles following the current one, assuming max emission.
+        fn projected_issuance(count: u32) -> Vec<(CycleNumber, Balance)>;
+    }
+}
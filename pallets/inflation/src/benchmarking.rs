@@ -44,6 +44,10 @@ fn initial_config<T: Config>() {
     };
     assert!(params.is_valid());
 
+    // Create some issuance so it's not zero
+    let dummy_account = whitelisted_caller();
+    T::Currency::make_free_balance_be(&dummy_account, 1_000_000_000_000_000_000_000);
+
     // Some dummy inflation config
     let total_issuance = T::Currency::total_issuance();
     let issuance_safety_cap =
@@ -62,10 +66,6 @@ fn initial_config<T: Config>() {
 
     InflationParams::<T>::put(params);
     ActiveInflationConfig::<T>::put(config);
-
-    // Create some issuance so it's not zero
-    let dummy_account = whitelisted_caller();
-    T::Currency::make_free_balance_be(&dummy_account, 1_000_000_000_000_000_000_000);
 }
 
 #[benchmarks]
@@ -96,8 +96,6 @@ mod benchmarks {
         assert_last_event::<T>(Event::<T>::ForcedInflationRecalculation { config }.into());
     }
 
-    
-
     #[benchmark]
     fn hooks_without_recalculation() {
         initial_config::<T>();
@@ -105,6 +103,7 @@ mod benchmarks {
         let init_config = ActiveInflationConfig::<T>::get();
         let init_issuance = T::Currency::total_issuance();
         DoRecalculation::<T>::kill();
+        CurrentEraInfo::<T>::put(EraInfo::default().next::<T::CycleConfiguration>(1));
 
         let block = 1u32.into();
         #[block]
@@ -119,6 +118,46 @@ mod benchmarks {
         assert!(T::Currency::total_issuance() > init_issuance);
     }
 
+    #[benchmark]
+    fn era_payout() {
+        initial_config::<T>();
+
+        // Worst case - last era of a period ends, so the bonus reward pool is paid out too.
+        let ended_era_info = EraInfo {
+            era: T::CycleConfiguration::eras_per_period(),
+            period: 1,
+            subperiod: Subperiod::BuildAndEarn,
+            next_period_start_era: T::CycleConfiguration::eras_per_period() + 1,
+            next_era_start: 1,
+        };
+        let era_info = ended_era_info.next::<T::CycleConfiguration>(1);
+        let init_issuance = T::Currency::total_issuance();
+
+        #[block]
+        {
+            Pallet::<T>::payout_era_rewards(&ended_era_info, &era_info);
+        }
+
+        assert!(T::Currency::total_issuance() >= init_issuance);
+    }
+
+    #[benchmark]
+    fn set_inflation_curve() {
+        initial_config::<T>();
+
+        // Worst case - full schedule needs to be validated.
+        let schedule: Vec<_> = (1..=MAX_INFLATION_SCHEDULE_LEN)
+            .map(|cycle| (cycle, Perquintill::from_percent(2)))
+            .collect();
+        let curve = InflationCurve::Scheduled(schedule.try_into().expect("Within the bound."));
+        assert!(curve.is_valid());
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, curve.clone());
+
+        assert_last_event::<T>(Event::<T>::InflationCurveChanged { curve }.into());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::benchmarking::tests::new_test_ext(),
@@ -43,6 +43,9 @@
 //!
 //! Era's length is expressed in blocks. E.g. an era can last for 7200 blocks, which is approximately 1 day for 12 second block time.
 //!
+//! Eras, periods & subperiods are tracked by this pallet from block numbers, according to the `CycleConfiguration`.
+//! Current state is available in `CurrentEraInfo` storage, and every era change is announced via the `NewEra` event.
+//!
 //! `Build&Earn` subperiod length is expressed in eras. E.g. if `Build&Earn` subperiod lasts for 5 eras, it means that during that subperiod,
 //! dApp rewards will be calculated & assigned 5 times in total. Also, 5 distinct eras will change during that subperiod. If e.g. `Build&Earn` started at era 100,
 //! with 5 eras per `Build&Earn` subperiod, then the subperiod will end at era 105.
@@ -63,10 +66,13 @@
 //! ## Recalculation
 //!
 //! When new cycle begins, inflation configuration is recalculated according to the inflation parameters & total issuance at that point in time.
+//! Recalculation is scheduled in the block right before the `recalculation_era` starts, and is done at the end of that block.
 //! Based on the max inflation rate, rewards for different network actors are calculated.
 //!
 //! Some rewards are calculated to be paid out per block, while some are per era or per period.
 //!
+//! All rewards are issued within the `issuance_safety_cap`. Once it's reached, no more rewards are issued until the next recalculation.
+//!
 //! ## Rewards
 //!
 //! ### Collator & Treasury Rewards
@@ -93,29 +99,33 @@
 //!
 //! This pallet implements `StakingRewardHandler` trait, which is used by the dApp staking protocol to get reward pools & distribute rewards.
 //!
+//! ## PayoutPerEra
+//!
+//! If there's no dApp staking protocol to consume the reward pools, they can be routed via `PayoutPerEra` instead.
+//! At the end of each `Build&Earn` era, dApp & staker reward pools are issued and handed over to it,
+//! together with the bonus reward pool if the era also ends the period.
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
 use astar_primitives::{
-    inflation::{
-        CycleConfiguration, EraNumber
-    },
-    Balance,
+    inflation::{CycleConfiguration, EraNumber, PeriodNumber},
+    Balance, BlockNumber,
 };
 use frame_support::{
     pallet_prelude::*,
-    traits::{Currency, GetStorageVersion, OnRuntimeUpgrade},
-    DefaultNoBound,
+    traits::{Currency, FindAuthor, GetStorageVersion, Imbalance, OnRuntimeUpgrade, OnUnbalanced},
+    DefaultNoBound, PalletId,
 };
 use frame_system::{ensure_root, pallet_prelude::*};
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
-    traits::{CheckedAdd, Zero},
+    traits::{AccountIdConversion, CheckedAdd, SaturatedConversion, Saturating, Zero},
     Perquintill,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 pub mod weights;
 pub use weights::WeightInfo;
@@ -154,6 +164,11 @@ pub mod pallet {
         /// Handler for 'per-block' payouts.
         type PayoutPerBlock: PayoutPerBlock<NegativeImbalanceOf<Self>>;
 
+        /// Handler for 'per-era' payouts of the staking related reward pools.
+        ///
+        /// If the pools are consumed via `StakingRewardHandler`, this should be `()`.
+        type PayoutPerEra: PayoutPerEra<NegativeImbalanceOf<Self>>;
+
         /// Cycle ('year') configuration - covers periods, subperiods, eras & blocks.
         type CycleConfiguration: CycleConfiguration;
 
@@ -175,12 +190,33 @@ pub mod pallet {
         ForcedInflationRecalculation { config: InflationConfiguration },
         /// New inflation configuration has been set.
         NewInflationConfiguration { config: InflationConfiguration },
+        /// New era has started.
+        NewEra {
+            era: EraNumber,
+            period: PeriodNumber,
+            subperiod: Subperiod,
+        },
+        /// Staking related reward pools of the ended era have been paid out.
+        /// Bonus reward pool is non-zero only if the era also ended a period.
+        EraRewardsPaidOut {
+            era: EraNumber,
+            dapps: Balance,
+            base_stakers: Balance,
+            adjustable_stakers: Balance,
+            bonus: Balance,
+        },
+        /// Total issuance has reached the issuance safety cap, further rewards aren't issued until the recalculation.
+        IssuanceSafetyCapReached { issuance_safety_cap: Balance },
+        /// Inflation curve has been changed. This will have effect on the next inflation recalculation.
+        InflationCurveChanged { curve: InflationCurve },
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Sum of all parts must be one whole (100%).
         InvalidInflationParameters,
+        /// Decaying curve floor must not be above the initial rate, and schedule must be non-empty & sorted by cycle.
+        InvalidInflationCurve,
     }
 
     /// Active inflation configuration parameters.
@@ -193,15 +229,27 @@ pub mod pallet {
     #[pallet::storage]
     pub type InflationParams<T: Config> = StorageValue<_, InflationParameters, ValueQuery>;
 
+    /// Inflation curve, deciding the max inflation rate of each cycle at the recalculation.
+    #[pallet::storage]
+    pub type ActiveInflationCurve<T: Config> = StorageValue<_, InflationCurve, ValueQuery>;
+
     /// Flag indicating whether on the first possible opportunity, recalculation of the inflation config should be done.
     #[pallet::storage]
     #[pallet::whitelist_storage]
     pub type DoRecalculation<T: Config> = StorageValue<_, EraNumber, OptionQuery>;
 
+    /// Current era, period & subperiod, tracked from block numbers.
+    ///
+    /// The first period starts in the first block in which this storage value isn't initialized yet.
+    #[pallet::storage]
+    #[pallet::whitelist_storage]
+    pub type CurrentEraInfo<T: Config> = StorageValue<_, EraInfo, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(DefaultNoBound)]
     pub struct GenesisConfig<T> {
         pub params: InflationParameters,
+        pub curve: InflationCurve,
         pub _config: sp_std::marker::PhantomData<T>,
     }
 
@@ -210,6 +258,9 @@ pub mod pallet {
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
             assert!(self.params.is_valid());
+            assert!(self.curve.is_valid());
+
+            ActiveInflationCurve::<T>::put(self.curve.clone());
 
             let starting_era = 1;
             let config = Pallet::<T>::recalculate_inflation(starting_era);
@@ -221,14 +272,47 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
             Self::payout_block_rewards();
 
             // Benchmarks won't account for the whitelisted storage access so this needs to be added manually.
             //
             // ActiveInflationConfig - 1 DB read
             // DoRecalculation - 1 DB read
-            <T as frame_system::Config>::DbWeight::get().reads(2)
+            // CurrentEraInfo - 1 DB read
+            let mut weight = <T as frame_system::Config>::DbWeight::get().reads(3);
+
+            let now: BlockNumber = now.saturated_into();
+            let mut era_info = CurrentEraInfo::<T>::get();
+
+            if now >= era_info.next_era_start {
+                let ended_era_info = era_info;
+                era_info = era_info.next::<T::CycleConfiguration>(now);
+                CurrentEraInfo::<T>::put(era_info);
+                weight.saturating_accrue(<T as frame_system::Config>::DbWeight::get().writes(1));
+
+                if ended_era_info.subperiod == Subperiod::BuildAndEarn {
+                    Self::payout_era_rewards(&ended_era_info, &era_info);
+                    weight.saturating_accrue(T::WeightInfo::era_payout());
+                }
+
+                Self::deposit_event(Event::<T>::NewEra {
+                    era: era_info.era,
+                    period: era_info.period,
+                    subperiod: era_info.subperiod,
+                });
+            }
+
+            // In the block right before the recalculation era starts, schedule the recalculation.
+            if now.saturating_add(1) >= era_info.next_era_start {
+                let next_era = era_info.era.saturating_add(1);
+                if next_era >= ActiveInflationConfig::<T>::get().recalculation_era {
+                    DoRecalculation::<T>::put(next_era);
+                    weight.saturating_accrue(T::WeightInfo::recalculation());
+                }
+            }
+
+            weight
         }
 
         fn on_finalize(_now: BlockNumberFor<T>) {
@@ -246,7 +330,12 @@ pub mod pallet {
                 Self::deposit_event(Event::<T>::NewInflationConfiguration { config });
             }
 
-            // NOTE: weight of the `on_finalize` logic with recalculation has to be covered by the observer notify call.
+            // NOTE: weight of the `on_finalize` logic with recalculation is covered by `on_initialize`, where it's scheduled.
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
         }
 
         fn integrity_test() {
@@ -303,22 +392,199 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Used to set the inflation curve, deciding the max inflation rate of each upcoming cycle.
+        /// The curve must be valid, otherwise the call will fail.
+        ///
+        /// Must be called by `root` origin, i.e. through governance.
+        ///
+        /// The new curve has effect on the next inflation recalculation.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_inflation_curve())]
+        pub fn set_inflation_curve(origin: OriginFor<T>, curve: InflationCurve) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(curve.is_valid(), Error::<T>::InvalidInflationCurve);
+            ActiveInflationCurve::<T>::put(curve.clone());
+
+            Self::deposit_event(Event::<T>::InflationCurveChanged { curve });
+
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Payout staking related reward pools of the ended `Build&Earn` era to the beneficiaries.
+        /// If the era ended the period, the bonus reward pool is paid out as well.
+        ///
+        /// Return the total amount issued.
+        pub(crate) fn payout_era_rewards(ended_era_info: &EraInfo, era_info: &EraInfo) -> Balance {
+            let config = ActiveInflationConfig::<T>::get();
+
+            let bonus = if era_info.period > ended_era_info.period {
+                config.bonus_reward_pool_per_period
+            } else {
+                Zero::zero()
+            };
+
+            let dapps_amount = Self::issue_within_cap(&config, config.dapp_reward_pool_per_era);
+            let dapps = dapps_amount.peek();
+            T::PayoutPerEra::dapps(dapps_amount);
+
+            let base_stakers_amount =
+                Self::issue_within_cap(&config, config.base_staker_reward_pool_per_era);
+            let base_stakers = base_stakers_amount.peek();
+            T::PayoutPerEra::base_stakers(base_stakers_amount);
+
+            let adjustable_stakers_amount =
+                Self::issue_within_cap(&config, config.adjustable_staker_reward_pool_per_era);
+            let adjustable_stakers = adjustable_stakers_amount.peek();
+            T::PayoutPerEra::adjustable_stakers(adjustable_stakers_amount);
+
+            let bonus = if bonus.is_zero() {
+                bonus
+            } else {
+                let bonus_amount = Self::issue_within_cap(&config, bonus);
+                let bonus = bonus_amount.peek();
+                T::PayoutPerEra::bonus(bonus_amount);
+                bonus
+            };
+
+            Self::deposit_event(Event::<T>::EraRewardsPaidOut {
+                era: ended_era_info.era,
+                dapps,
+                base_stakers,
+                adjustable_stakers,
+                bonus,
+            });
+
+            dapps
+                .saturating_add(base_stakers)
+                .saturating_add(adjustable_stakers)
+                .saturating_add(bonus)
+        }
+
         /// Payout block rewards to the beneficiaries.
         ///
         /// Return the total amount issued.
         fn payout_block_rewards() -> Balance {
             let config = ActiveInflationConfig::<T>::get();
 
-            let collator_amount = T::Currency::issue(config.collator_reward_per_block);
-            let treasury_amount = T::Currency::issue(config.treasury_reward_per_block);
+            let collator_amount = Self::issue_within_cap(&config, config.collator_reward_per_block);
+            let treasury_amount = Self::issue_within_cap(&config, config.treasury_reward_per_block);
+            let total_amount = collator_amount
+                .peek()
+                .saturating_add(treasury_amount.peek());
 
             T::PayoutPerBlock::collators(collator_amount);
             T::PayoutPerBlock::treasury(treasury_amount);
 
-            config.collator_reward_per_block + config.treasury_reward_per_block
+            total_amount
+        }
+
+        /// Issue the `amount`, clamped so the total issuance doesn't exceed the `issuance_safety_cap`.
+        ///
+        /// Emits `IssuanceSafetyCapReached` event if the cap is reached by this issuance.
+        fn issue_within_cap(
+            config: &InflationConfiguration,
+            amount: Balance,
+        ) -> NegativeImbalanceOf<T> {
+            let remaining = config
+                .issuance_safety_cap
+                .saturating_sub(T::Currency::total_issuance());
+
+            if !remaining.is_zero() && amount >= remaining {
+                Self::deposit_event(Event::<T>::IssuanceSafetyCapReached {
+                    issuance_safety_cap: config.issuance_safety_cap,
+                });
+            }
+
+            T::Currency::issue(amount.min(remaining))
+        }
+
+        /// Check the invariant of the total issuance being within the issuance safety cap.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                T::Currency::total_issuance()
+                    <= ActiveInflationConfig::<T>::get().issuance_safety_cap,
+                "Total issuance exceeds the issuance safety cap"
+            );
+
+            Ok(())
+        }
+
+        /// Cycle number of the given era, the first cycle starting with era `1`.
+        pub fn cycle_of_era(era: EraNumber) -> CycleNumber {
+            era.saturating_sub(1) / T::CycleConfiguration::eras_per_cycle() + 1
+        }
+
+        /// Max inflation rates of (at most `MAX_UPCOMING_CYCLES`) cycles following the current one,
+        /// according to the active inflation curve & parameters.
+        pub fn upcoming_inflation_rates(count: u32) -> Vec<(CycleNumber, Perquintill)> {
+            let curve = ActiveInflationCurve::<T>::get();
+            let default_rate = InflationParams::<T>::get().max_inflation_rate;
+            let current_cycle = Self::cycle_of_era(CurrentEraInfo::<T>::get().era);
+
+            (1..=count.min(MAX_UPCOMING_CYCLES))
+                .map(|offset| {
+                    let cycle = current_cycle.saturating_add(offset);
+                    (cycle, curve.rate(cycle, default_rate))
+                })
+                .collect()
+        }
+
+        /// Number of blocks until the `recalculation_era` starts, assuming the standard era progression.
+        /// Inflation recalculation is done at the end of the block preceding it.
+        pub fn blocks_until_recalculation() -> BlockNumber {
+            let now: BlockNumber = frame_system::Pallet::<T>::block_number().saturated_into();
+            let era_info = CurrentEraInfo::<T>::get();
+            let recalculation_era = ActiveInflationConfig::<T>::get().recalculation_era;
+
+            let blocks_until_next_era = era_info.next_era_start.saturating_sub(now);
+
+            // Eras fully passing between the next era start & the recalculation era start.
+            let first_era = era_info.era.saturating_add(1);
+            let eras = recalculation_era.saturating_sub(first_era);
+            if eras.is_zero() {
+                return blocks_until_next_era;
+            }
+
+            // Out of them, the ones starting a new period, i.e. the voting subperiod eras.
+            let last_era = first_era.saturating_add(eras).saturating_sub(1);
+            let voting_eras = if era_info.next_period_start_era > last_era {
+                0
+            } else {
+                (last_era - era_info.next_period_start_era)
+                    / T::CycleConfiguration::eras_per_period()
+                    + 1
+            };
+
+            let blocks_per_era = T::CycleConfiguration::blocks_per_era();
+            let blocks_per_voting_era =
+                blocks_per_era.saturating_mul(T::CycleConfiguration::eras_per_voting_subperiod());
+
+            blocks_until_next_era
+                .saturating_add(
+                    eras.saturating_sub(voting_eras)
+                        .saturating_mul(blocks_per_era),
+                )
+                .saturating_add(voting_eras.saturating_mul(blocks_per_voting_era))
+        }
+
+        /// Projected total issuance at the end of (at most `MAX_UPCOMING_CYCLES`) cycles following the current one.
+        ///
+        /// Assumes the max emission, i.e. the issuance safety cap is reached in the current & each of the upcoming cycles.
+        pub fn projected_issuance(count: u32) -> Vec<(CycleNumber, Balance)> {
+            let mut issuance = ActiveInflationConfig::<T>::get().issuance_safety_cap;
+
+            Self::upcoming_inflation_rates(count)
+                .into_iter()
+                .map(|(cycle, rate)| {
+                    issuance = issuance.saturating_add(rate * issuance);
+                    (cycle, issuance)
+                })
+                .collect()
         }
 
         /// Recalculates the inflation based on the total issuance & inflation parameters.
@@ -329,7 +595,9 @@ pub mod pallet {
             let total_issuance = T::Currency::total_issuance();
 
             // 1. Calculate maximum emission over the period before the next recalculation.
-            let max_emission = params.max_inflation_rate * total_issuance;
+            let max_inflation_rate = ActiveInflationCurve::<T>::get()
+                .rate(Self::cycle_of_era(next_era), params.max_inflation_rate);
+            let max_emission = max_inflation_rate * total_issuance;
             let issuance_safety_cap = total_issuance.saturating_add(max_emission);
 
             // 2. Calculate distribution of max emission between different purposes.
@@ -389,8 +657,6 @@ pub mod pallet {
             new_inflation_config
         }
     }
-
-   
 }
 
 /// Configuration of the inflation.
@@ -456,6 +722,145 @@ impl InflationConfiguration {
     }
 }
 
+/// Subperiod of a period.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum Subperiod {
+    /// Subperiod lasting for `eras_per_voting_subperiod` standard era lengths, but taking only a single era.
+    #[default]
+    Voting,
+    /// Subperiod consisting of `eras_per_build_and_earn_subperiod` standard eras.
+    BuildAndEarn,
+}
+
+/// Information about the current era, period & subperiod.
+#[derive(Encode, Decode, MaxEncodedLen, Default, Copy, Clone, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct EraInfo {
+    /// Current era number.
+    #[codec(compact)]
+    pub era: EraNumber,
+    /// Current period number.
+    #[codec(compact)]
+    pub period: PeriodNumber,
+    /// Current subperiod.
+    pub subperiod: Subperiod,
+    /// Era number at which the next period starts.
+    #[codec(compact)]
+    pub next_period_start_era: EraNumber,
+    /// Block number at which the next era starts.
+    #[codec(compact)]
+    pub next_era_start: BlockNumber,
+}
+
+impl EraInfo {
+    /// Era info of the era starting at block `now`, which follows this one.
+    ///
+    /// If no era has been tracked yet (default value), the first period starts at era `1`.
+    pub fn next<C: CycleConfiguration>(&self, now: BlockNumber) -> Self {
+        let era = self.era.saturating_add(1);
+
+        if era >= self.next_period_start_era {
+            Self {
+                era,
+                period: self.period.saturating_add(1),
+                subperiod: Subperiod::Voting,
+                next_period_start_era: era.saturating_add(C::eras_per_period()),
+                next_era_start: now.saturating_add(
+                    C::blocks_per_era().saturating_mul(C::eras_per_voting_subperiod()),
+                ),
+            }
+        } else {
+            Self {
+                era,
+                period: self.period,
+                subperiod: Subperiod::BuildAndEarn,
+                next_period_start_era: self.next_period_start_era,
+                next_era_start: now.saturating_add(C::blocks_per_era()),
+            }
+        }
+    }
+}
+
+/// Cycle ('year') number, the first cycle being `1`.
+pub type CycleNumber = u32;
+
+/// Max number of entries in the scheduled inflation curve.
+pub const MAX_INFLATION_SCHEDULE_LEN: u32 = 32;
+
+/// Max number of cycles for which the upcoming inflation rates can be queried.
+pub const MAX_UPCOMING_CYCLES: u32 = 100;
+
+/// Curve deciding the max inflation rate of each cycle.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Default,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub enum InflationCurve {
+    /// Max inflation rate from the inflation parameters is used for every cycle.
+    #[default]
+    Flat,
+    /// Max inflation rate starts at `initial_rate` in the first cycle, and decreases by `decay`
+    /// of the previous cycle's rate in each following cycle, down to the `floor`.
+    Decaying {
+        initial_rate: Perquintill,
+        decay: Perquintill,
+        floor: Perquintill,
+    },
+    /// Max inflation rates by the starting cycle number, sorted by the cycle.
+    /// Each rate is used until the next entry's cycle, the one before the first entry is taken from the inflation parameters.
+    Scheduled(BoundedVec<(CycleNumber, Perquintill), ConstU32<MAX_INFLATION_SCHEDULE_LEN>>),
+}
+
+impl InflationCurve {
+    /// Max inflation rate of the given cycle.
+    /// `default_rate` is the max inflation rate from the inflation parameters.
+    pub fn rate(&self, cycle: CycleNumber, default_rate: Perquintill) -> Perquintill {
+        match self {
+            Self::Flat => default_rate,
+            Self::Decaying {
+                initial_rate,
+                decay,
+                floor,
+            } => {
+                let factor = Perquintill::one()
+                    .saturating_sub(*decay)
+                    .saturating_pow(cycle.saturating_sub(1) as usize);
+                (factor * *initial_rate).max(*floor)
+            }
+            Self::Scheduled(schedule) => schedule
+                .iter()
+                .take_while(|(start, _)| *start <= cycle)
+                .last()
+                .map_or(default_rate, |(_, rate)| *rate),
+        }
+    }
+
+    /// `true` if the curve is valid, `false` otherwise.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Flat => true,
+            Self::Decaying {
+                initial_rate,
+                floor,
+                ..
+            } => floor <= initial_rate,
+            Self::Scheduled(schedule) => {
+                !schedule.is_empty() && schedule.windows(2).all(|pair| pair[0].0 < pair[1].0)
+            }
+        }
+    }
+}
+
 /// Inflation parameters.
 ///
 /// The parts of the inflation that go towards different purposes must add up to exactly 100%.
@@ -552,9 +957,149 @@ pub trait PayoutPerBlock<Imbalance> {
     fn collators(reward: Imbalance);
 }
 
+/// Defines functions used to payout the staking related reward pools, at the end of each `Build&Earn` era.
+///
+/// Each pool can be routed to a different destination, e.g. treasury, external pallet, or burned by dropping the imbalance.
+pub trait PayoutPerEra<Imbalance> {
+    /// Payout the dApp reward pool of an era.
+    fn dapps(reward: Imbalance);
+
+    /// Payout the base staker reward pool of an era.
+    fn base_stakers(reward: Imbalance);
+
+    /// Payout the adjustable staker reward pool of an era.
+    fn adjustable_stakers(reward: Imbalance);
+
+    /// Payout the bonus reward pool of a period.
+    fn bonus(reward: Imbalance);
+}
+
+/// Reward pools aren't paid out, e.g. because they're consumed via `StakingRewardHandler`.
+impl<Imbalance> PayoutPerEra<Imbalance> for () {
+    fn dapps(_reward: Imbalance) {}
+    fn base_stakers(_reward: Imbalance) {}
+    fn adjustable_stakers(_reward: Imbalance) {}
+    fn bonus(_reward: Imbalance) {}
+}
+
+/// Interface for staking reward handler.
+///
+/// Provides reward pool values for stakers - normal & bonus rewards, as well as dApp reward pool.
+/// Also provides a safe function for paying out rewards.
+pub trait StakingRewardHandler<AccountId> {
+    /// Returns the staker reward pool & dApp reward pool for an era.
+    ///
+    /// The total staker reward pool is dynamic and depends on the total value staked.
+    fn staker_and_dapp_reward_pools(total_value_staked: Balance) -> (Balance, Balance);
+
+    /// Returns the bonus reward pool for a period.
+    fn bonus_reward_pool() -> Balance;
+
+    /// Attempts to pay out the rewards to the beneficiary.
+    fn payout_reward(beneficiary: &AccountId, reward: Balance) -> Result<(), ()>;
+}
+
+impl<T: Config> StakingRewardHandler<T::AccountId> for Pallet<T> {
+    fn staker_and_dapp_reward_pools(total_value_staked: Balance) -> (Balance, Balance) {
+        let config = ActiveInflationConfig::<T>::get();
+        let total_issuance = T::Currency::total_issuance();
+
+        // First calculate the adjustable part of the staker reward pool, according to formula:
+        // adjustable_part = max_adjustable_part * min(1, total_staked_percent / ideal_staked_percent)
+        // (These operations are overflow & zero-division safe)
+        let staked_ratio = Perquintill::from_rational(total_value_staked, total_issuance);
+        let adjustment_factor = staked_ratio / config.ideal_staking_rate;
+
+        let adjustable_part = adjustment_factor * config.adjustable_staker_reward_pool_per_era;
+        let staker_reward_pool = config
+            .base_staker_reward_pool_per_era
+            .saturating_add(adjustable_part);
+
+        (staker_reward_pool, config.dapp_reward_pool_per_era)
+    }
+
+    fn bonus_reward_pool() -> Balance {
+        ActiveInflationConfig::<T>::get().bonus_reward_pool_per_period
+    }
+
+    fn payout_reward(account: &T::AccountId, reward: Balance) -> Result<(), ()> {
+        let config = ActiveInflationConfig::<T>::get();
+
+        // This is a safety measure to prevent excessive minting.
+        ensure!(
+            T::Currency::total_issuance().saturating_add(reward) <= config.issuance_safety_cap,
+            ()
+        );
+
+        // This can fail only if the amount is below existential deposit & the account doesn't exist,
+        // or if the account has no provider references.
+        // In both cases, the reward is lost but this can be ignored since it's extremely unlikely
+        // to appear and doesn't bring any real harm.
+        let _ = T::Currency::deposit_creating(account, reward);
+
+        Ok(())
+    }
+}
+
+/// Pays out the reward to the author of the current block, found by `F` from the pre-runtime digests (e.g. `pallet_aura`).
+///
+/// If the author cannot be determined, the reward is handled by `Fallback`.
+pub struct ToBlockAuthor<T, F, Fallback>(PhantomData<(T, F, Fallback)>);
+impl<T, F, Fallback> OnUnbalanced<NegativeImbalanceOf<T>> for ToBlockAuthor<T, F, Fallback>
+where
+    T: Config,
+    F: FindAuthor<T::AccountId>,
+    Fallback: OnUnbalanced<NegativeImbalanceOf<T>>,
+{
+    fn on_nonzero_unbalanced(reward: NegativeImbalanceOf<T>) {
+        let digest = frame_system::Pallet::<T>::digest();
+        let pre_runtime_digests = digest.logs().iter().filter_map(|d| d.as_pre_runtime());
+
+        match F::find_author(pre_runtime_digests) {
+            Some(author) => T::Currency::resolve_creating(&author, reward),
+            None => Fallback::on_unbalanced(reward),
+        }
+    }
+}
+
+/// Splits the reward evenly among the accounts provided by `A`, e.g. the current authority set.
+///
+/// The division remainder goes to the first account. If there are no accounts, the reward is handled by `Fallback`.
+pub struct SplitAmongAuthorities<T, A, Fallback>(PhantomData<(T, A, Fallback)>);
+impl<T, A, Fallback> OnUnbalanced<NegativeImbalanceOf<T>> for SplitAmongAuthorities<T, A, Fallback>
+where
+    T: Config,
+    A: Get<Vec<T::AccountId>>,
+    Fallback: OnUnbalanced<NegativeImbalanceOf<T>>,
+{
+    fn on_nonzero_unbalanced(reward: NegativeImbalanceOf<T>) {
+        let authorities = A::get();
+        let Some((first, rest)) = authorities.split_first() else {
+            Fallback::on_unbalanced(reward);
+            return;
+        };
+
+        let share = reward.peek() / authorities.len() as Balance;
+        let mut remainder = reward;
+        for authority in rest {
+            let (part, left) = remainder.split(share);
+            T::Currency::resolve_creating(authority, part);
+            remainder = left;
+        }
+
+        T::Currency::resolve_creating(first, remainder);
+    }
+}
+
+/// Pays out the reward to the pot account derived from `P`.
+pub struct ToPot<T, P>(PhantomData<(T, P)>);
+impl<T: Config, P: Get<PalletId>> OnUnbalanced<NegativeImbalanceOf<T>> for ToPot<T, P> {
+    fn on_nonzero_unbalanced(reward: NegativeImbalanceOf<T>) {
+        T::Currency::resolve_creating(&P::get().into_account_truncating(), reward);
+    }
+}
+
 /// `OnRuntimeUpgrade` logic for integrating this pallet into the live network.
-#[cfg(feature = "try-runtime")]
-use sp_std::vec::Vec;
 pub struct PalletInflationInitConfig<T, P>(PhantomData<(T, P, Weight)>);
 impl<T: Config, P: Get<(InflationParameters, EraNumber, Weight)>> OnRuntimeUpgrade
     for PalletInflationInitConfig<T, P>
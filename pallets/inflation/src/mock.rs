@@ -18,21 +18,24 @@
 
 use crate::{
     self as pallet_inflation, ActiveInflationConfig, CycleConfiguration, InflationParameters,
-    InflationParams, NegativeImbalanceOf, PayoutPerBlock,
+    InflationParams, NegativeImbalanceOf, PayoutPerBlock, PayoutPerEra, ToBlockAuthor, ToPot,
 };
 
 use frame_support::{
     construct_runtime, parameter_types,
     traits::Currency,
-    traits::{ConstU128, ConstU32, Hooks},
+    traits::{ConstBool, ConstU128, ConstU32, ConstU64, FindAuthor, Get, Hooks, OnUnbalanced},
     weights::Weight,
-    PalletId,
+    ConsensusEngineId, PalletId,
 };
+use parity_scale_codec::Encode;
+use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
 use sp_core::H256;
 use sp_io::TestExternalities;
 use sp_runtime::{
+    testing::UintAuthorityId,
     traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
-    BuildStorage, Perquintill,
+    BuildStorage, Digest, DigestItem, Perquintill,
 };
 
 use astar_primitives::{Balance, BlockNumber};
@@ -105,9 +108,52 @@ impl pallet_balances::Config for Test {
     type RuntimeFreezeReason = ();
     type MaxFreezes = ConstU32<0>;
 }
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = Aura;
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+impl pallet_aura::Config for Test {
+    type AuthorityId = UintAuthorityId;
+    type DisabledValidators = ();
+    type MaxAuthorities = ConstU32<8>;
+    type AllowMultipleBlocksPerSlot = ConstBool<false>;
+}
+
+/// Accounts of the Aura authorities set in the mock genesis.
+pub(crate) const AUTHORITIES: [AccountId; 3] = [11, 12, 13];
+
 // Dummy accounts used to simulate reward beneficiaries balances
 pub(crate) const TREASURY_POT: PalletId = PalletId(*b"moktrsry");
 pub(crate) const COLLATOR_POT: PalletId = PalletId(*b"mokcolat");
+pub(crate) const DAPPS_POT: PalletId = PalletId(*b"mokdapps");
+pub(crate) const STAKERS_POT: PalletId = PalletId(*b"mokstakr");
+pub(crate) const BONUS_POT: PalletId = PalletId(*b"mokbonus");
+
+parameter_types! {
+    pub const CollatorPot: PalletId = COLLATOR_POT;
+}
+
+/// Block author's account, found via the Aura pre-runtime digest.
+pub struct AuraAuthorAccount;
+impl FindAuthor<AccountId> for AuraAuthorAccount {
+    fn find_author<'a, I>(digests: I) -> Option<AccountId>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        Aura::find_author(digests).map(|index| Aura::authorities()[index as usize].0)
+    }
+}
+
+/// Accounts of the current Aura authority set.
+pub struct AuraAuthorityAccounts;
+impl Get<Vec<AccountId>> for AuraAuthorityAccounts {
+    fn get() -> Vec<AccountId> {
+        Aura::authorities().iter().map(|id| id.0).collect()
+    }
+}
 
 pub struct DummyPayoutPerBlock;
 impl PayoutPerBlock<NegativeImbalanceOf<Test>> for DummyPayoutPerBlock {
@@ -116,7 +162,26 @@ impl PayoutPerBlock<NegativeImbalanceOf<Test>> for DummyPayoutPerBlock {
     }
 
     fn collators(reward: NegativeImbalanceOf<Test>) {
-        Balances::resolve_creating(&COLLATOR_POT.into_account_truncating(), reward);
+        ToBlockAuthor::<Test, AuraAuthorAccount, ToPot<Test, CollatorPot>>::on_unbalanced(reward);
+    }
+}
+
+pub struct DummyPayoutPerEra;
+impl PayoutPerEra<NegativeImbalanceOf<Test>> for DummyPayoutPerEra {
+    fn dapps(reward: NegativeImbalanceOf<Test>) {
+        Balances::resolve_creating(&DAPPS_POT.into_account_truncating(), reward);
+    }
+
+    fn base_stakers(reward: NegativeImbalanceOf<Test>) {
+        Balances::resolve_creating(&STAKERS_POT.into_account_truncating(), reward);
+    }
+
+    fn adjustable_stakers(_reward: NegativeImbalanceOf<Test>) {
+        // burned
+    }
+
+    fn bonus(reward: NegativeImbalanceOf<Test>) {
+        Balances::resolve_creating(&BONUS_POT.into_account_truncating(), reward);
     }
 }
 
@@ -142,6 +207,7 @@ impl CycleConfiguration for DummyCycleConfiguration {
 impl pallet_inflation::Config for Test {
     type Currency = Balances;
     type PayoutPerBlock = DummyPayoutPerBlock;
+    type PayoutPerEra = DummyPayoutPerEra;
     type CycleConfiguration = DummyCycleConfiguration;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
@@ -151,6 +217,8 @@ construct_runtime!(
     pub struct Test {
         System: frame_system,
         Balances: pallet_balances,
+        Timestamp: pallet_timestamp,
+        Aura: pallet_aura,
         Inflation: pallet_inflation,
     }
 );
@@ -170,6 +238,15 @@ impl ExternalityBuilder {
         .assimilate_storage(&mut storage)
         .ok();
 
+        pallet_aura::GenesisConfig::<Test> {
+            authorities: AUTHORITIES
+                .iter()
+                .map(|acc| UintAuthorityId(*acc))
+                .collect(),
+        }
+        .assimilate_storage(&mut storage)
+        .ok();
+
         let mut ext = TestExternalities::from(storage);
         ext.execute_with(|| {
             // Set initial pallet inflation values
@@ -183,3 +260,23 @@ impl ExternalityBuilder {
         ext
     }
 }
+
+/// Advance blocks until the specified block number is reached, running the pallet hooks.
+pub fn run_to_block(n: BlockNumber) {
+    while System::block_number() < n {
+        Inflation::on_finalize(System::block_number());
+        System::set_block_number(System::block_number() + 1);
+        Inflation::on_initialize(System::block_number());
+    }
+}
+
+/// Sets the digest of the current block to the one produced by Aura in the given slot.
+pub fn set_aura_slot(slot: u64) {
+    let digest = Digest {
+        logs: vec![DigestItem::PreRuntime(
+            AURA_ENGINE_ID,
+            Slot::from(slot).encode(),
+        )],
+    };
+    System::initialize(&System::block_number(), &System::parent_hash(), &digest);
+}
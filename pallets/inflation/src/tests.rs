@@ -17,7 +17,10 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use super::{pallet::Error, Event, *};
-use frame_support::{assert_noop, assert_ok, assert_storage_noop, traits::Hooks};
+use frame_support::{
+    assert_noop, assert_ok, assert_storage_noop,
+    traits::{Currency, Hooks, OnUnbalanced},
+};
 use mock::*;
 use sp_runtime::{
     traits::{AccountIdConversion, BadOrigin, Zero},
@@ -71,6 +74,254 @@ fn force_set_inflation_params_fails() {
     })
 }
 
+#[test]
+fn set_inflation_curve_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_eq!(ActiveInflationCurve::<Test>::get(), InflationCurve::Flat);
+
+        let curve = InflationCurve::Decaying {
+            initial_rate: Perquintill::from_percent(7),
+            decay: Perquintill::from_percent(10),
+            floor: Perquintill::from_percent(2),
+        };
+        assert_ok!(Inflation::set_inflation_curve(
+            RuntimeOrigin::root(),
+            curve.clone()
+        ));
+
+        assert_eq!(ActiveInflationCurve::<Test>::get(), curve);
+        System::assert_last_event(Event::InflationCurveChanged { curve }.into());
+    })
+}
+
+#[test]
+fn set_inflation_curve_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        let curve = InflationCurve::Decaying {
+            initial_rate: Perquintill::from_percent(2),
+            decay: Perquintill::from_percent(10),
+            floor: Perquintill::from_percent(7),
+        };
+        assert!(
+            !curve.is_valid(),
+            "Must be invalid for check to make sense."
+        );
+
+        // Make sure it's not possible to set invalid curve
+        assert_noop!(
+            Inflation::set_inflation_curve(RuntimeOrigin::root(), curve),
+            Error::<Test>::InvalidInflationCurve
+        );
+
+        // Make sure action is privileged
+        assert_noop!(
+            Inflation::set_inflation_curve(RuntimeOrigin::signed(1), InflationCurve::Flat),
+            BadOrigin
+        );
+    })
+}
+
+#[test]
+fn inflation_curve_validity_check_works() {
+    assert!(InflationCurve::Flat.is_valid());
+
+    // Floor can't be above the initial rate
+    let decaying = |initial: u64, floor: u64| InflationCurve::Decaying {
+        initial_rate: Perquintill::from_percent(initial),
+        decay: Perquintill::from_percent(10),
+        floor: Perquintill::from_percent(floor),
+    };
+    assert!(decaying(7, 2).is_valid());
+    assert!(decaying(7, 7).is_valid());
+    assert!(!decaying(2, 7).is_valid());
+
+    // Schedule must be non-empty & strictly sorted by cycle
+    let scheduled = |cycles: Vec<CycleNumber>| {
+        InflationCurve::Scheduled(
+            cycles
+                .into_iter()
+                .map(|cycle| (cycle, Perquintill::from_percent(5)))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        )
+    };
+    assert!(scheduled(vec![1, 3, 10]).is_valid());
+    assert!(!scheduled(vec![]).is_valid());
+    assert!(!scheduled(vec![1, 3, 3]).is_valid());
+    assert!(!scheduled(vec![3, 1]).is_valid());
+}
+
+#[test]
+fn inflation_curve_rates_are_ok() {
+    let default_rate = Perquintill::from_percent(7);
+
+    // Flat curve always uses the default rate
+    assert_eq!(InflationCurve::Flat.rate(1, default_rate), default_rate);
+    assert_eq!(InflationCurve::Flat.rate(50, default_rate), default_rate);
+
+    // Decaying curve, 7% decaying by 10% per cycle, down to 2%
+    let decaying = InflationCurve::Decaying {
+        initial_rate: Perquintill::from_percent(7),
+        decay: Perquintill::from_percent(10),
+        floor: Perquintill::from_percent(2),
+    };
+    assert_eq!(decaying.rate(1, default_rate), Perquintill::from_percent(7));
+    assert_eq!(
+        decaying.rate(2, default_rate),
+        Perquintill::from_rational(63_u64, 1000)
+    );
+    assert_eq!(
+        decaying.rate(3, default_rate),
+        Perquintill::from_rational(567_u64, 10000)
+    );
+    assert_eq!(
+        decaying.rate(13, default_rate),
+        Perquintill::from_percent(2)
+    );
+    assert_eq!(
+        decaying.rate(100, default_rate),
+        Perquintill::from_percent(2)
+    );
+
+    // Scheduled curve, default rate is used before the first entry
+    let scheduled = InflationCurve::Scheduled(
+        vec![
+            (2, Perquintill::from_percent(5)),
+            (4, Perquintill::from_percent(4)),
+            (10, Perquintill::from_percent(2)),
+        ]
+        .try_into()
+        .unwrap(),
+    );
+    assert_eq!(scheduled.rate(1, default_rate), default_rate);
+    assert_eq!(
+        scheduled.rate(2, default_rate),
+        Perquintill::from_percent(5)
+    );
+    assert_eq!(
+        scheduled.rate(3, default_rate),
+        Perquintill::from_percent(5)
+    );
+    assert_eq!(
+        scheduled.rate(4, default_rate),
+        Perquintill::from_percent(4)
+    );
+    assert_eq!(
+        scheduled.rate(9, default_rate),
+        Perquintill::from_percent(4)
+    );
+    assert_eq!(
+        scheduled.rate(10, default_rate),
+        Perquintill::from_percent(2)
+    );
+    assert_eq!(
+        scheduled.rate(1000, default_rate),
+        Perquintill::from_percent(2)
+    );
+}
+
+#[test]
+fn inflation_recalculation_applies_curve() {
+    ExternalityBuilder::build().execute_with(|| {
+        type CycleConfig = <Test as Config>::CycleConfiguration;
+
+        assert_ok!(Inflation::set_inflation_curve(
+            RuntimeOrigin::root(),
+            InflationCurve::Decaying {
+                initial_rate: Perquintill::from_percent(7),
+                decay: Perquintill::from_percent(10),
+                floor: Perquintill::from_percent(2),
+            }
+        ));
+
+        // First era of the second cycle
+        let next_era = 1 + CycleConfig::eras_per_cycle();
+        assert_eq!(Inflation::cycle_of_era(next_era), 2);
+
+        let total_issuance = Balances::total_issuance();
+        let config = Inflation::recalculate_inflation(next_era);
+
+        assert_eq!(
+            config.issuance_safety_cap,
+            total_issuance + Perquintill::from_rational(63_u64, 1000) * total_issuance
+        );
+    })
+}
+
+#[test]
+fn upcoming_inflation_rates_are_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let default_rate = InflationParams::<Test>::get().max_inflation_rate;
+        assert_eq!(
+            Inflation::cycle_of_era(CurrentEraInfo::<Test>::get().era),
+            1
+        );
+
+        // Flat curve
+        assert_eq!(
+            Inflation::upcoming_inflation_rates(2),
+            vec![(2, default_rate), (3, default_rate)]
+        );
+
+        // Scheduled curve
+        assert_ok!(Inflation::set_inflation_curve(
+            RuntimeOrigin::root(),
+            InflationCurve::Scheduled(vec![(3, Perquintill::from_percent(4))].try_into().unwrap())
+        ));
+        assert_eq!(
+            Inflation::upcoming_inflation_rates(3),
+            vec![
+                (2, default_rate),
+                (3, Perquintill::from_percent(4)),
+                (4, Perquintill::from_percent(4))
+            ]
+        );
+
+        // Number of cycles is limited
+        assert_eq!(
+            Inflation::upcoming_inflation_rates(u32::MAX).len(),
+            MAX_UPCOMING_CYCLES as usize
+        );
+    })
+}
+
+#[test]
+fn blocks_until_recalculation_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        type CycleConfig = <Test as Config>::CycleConfiguration;
+
+        // Recalculation era starts in the first block after the cycle.
+        let cycle_length = CycleConfig::blocks_per_cycle();
+        assert_eq!(Inflation::blocks_until_recalculation(), cycle_length);
+
+        for block in [7, 23, 210, 500, cycle_length - 1, cycle_length] {
+            run_to_block(block);
+            assert_eq!(
+                Inflation::blocks_until_recalculation(),
+                cycle_length + 1 - block,
+                "Block: {}",
+                block
+            );
+        }
+    })
+}
+
+#[test]
+fn projected_issuance_is_ok() {
+    ExternalityBuilder::build().execute_with(|| {
+        let config = ActiveInflationConfig::<Test>::get();
+        let rate = InflationParams::<Test>::get().max_inflation_rate;
+
+        let second_cycle = config.issuance_safety_cap + rate * config.issuance_safety_cap;
+        let third_cycle = second_cycle + rate * second_cycle;
+        assert_eq!(
+            Inflation::projected_issuance(2),
+            vec![(2, second_cycle), (3, third_cycle)]
+        );
+    })
+}
+
 #[test]
 fn force_inflation_recalculation_work() {
     ExternalityBuilder::build().execute_with(|| {
@@ -105,34 +356,113 @@ fn force_inflation_fails_due_to_unprivileged_origin() {
     })
 }
 
+#[test]
+fn era_progression_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        type CycleConfig = <Test as Config>::CycleConfiguration;
+
+        // First era of the first period starts in the first block.
+        let era_info = CurrentEraInfo::<Test>::get();
+        assert_eq!(era_info.era, 1);
+        assert_eq!(era_info.period, 1);
+        assert_eq!(era_info.subperiod, Subperiod::Voting);
+        assert_eq!(
+            era_info.next_period_start_era,
+            1 + CycleConfig::eras_per_period()
+        );
+
+        // Voting subperiod takes a single era, but lasts for multiple standard era lengths.
+        let voting_length =
+            CycleConfig::eras_per_voting_subperiod() * CycleConfig::blocks_per_era();
+        assert_eq!(era_info.next_era_start, 1 + voting_length);
+
+        run_to_block(voting_length);
+        assert_eq!(CurrentEraInfo::<Test>::get(), era_info);
+
+        // Build&Earn subperiod starts with the next era.
+        run_to_block(voting_length + 1);
+        let era_info = CurrentEraInfo::<Test>::get();
+        assert_eq!(era_info.era, 2);
+        assert_eq!(era_info.period, 1);
+        assert_eq!(era_info.subperiod, Subperiod::BuildAndEarn);
+        assert_eq!(
+            era_info.next_era_start,
+            voting_length + 1 + CycleConfig::blocks_per_era()
+        );
+        System::assert_last_event(
+            Event::NewEra {
+                era: 2,
+                period: 1,
+                subperiod: Subperiod::BuildAndEarn,
+            }
+            .into(),
+        );
+
+        // Each Build&Earn era lasts for a standard era length, after which a new period starts.
+        let period_length = voting_length
+            + CycleConfig::eras_per_build_and_earn_subperiod() * CycleConfig::blocks_per_era();
+        run_to_block(period_length);
+        let era_info = CurrentEraInfo::<Test>::get();
+        assert_eq!(era_info.era, CycleConfig::eras_per_period());
+        assert_eq!(era_info.subperiod, Subperiod::BuildAndEarn);
+
+        run_to_block(period_length + 1);
+        let era_info = CurrentEraInfo::<Test>::get();
+        assert_eq!(era_info.era, 1 + CycleConfig::eras_per_period());
+        assert_eq!(era_info.period, 2);
+        assert_eq!(era_info.subperiod, Subperiod::Voting);
+        assert_eq!(
+            era_info.next_period_start_era,
+            1 + 2 * CycleConfig::eras_per_period()
+        );
+        System::assert_last_event(
+            Event::NewEra {
+                era: era_info.era,
+                period: 2,
+                subperiod: Subperiod::Voting,
+            }
+            .into(),
+        );
+    })
+}
+
 #[test]
 fn inflation_recalculation_occurs_when_expected() {
     ExternalityBuilder::build().execute_with(|| {
-        let init_config = ActiveInflationConfig::<Test>::get();
+        type CycleConfig = <Test as Config>::CycleConfiguration;
 
+        let init_config = ActiveInflationConfig::<Test>::get();
         let recalculation_era = init_config.recalculation_era;
+        assert_eq!(recalculation_era, 1 + CycleConfig::eras_per_cycle());
 
+        // Advance to the last block of the era preceding the recalculation era.
+        let cycle_length = CycleConfig::blocks_per_cycle();
+        run_to_block(cycle_length - 1);
+        assert_eq!(CurrentEraInfo::<Test>::get().era, recalculation_era - 1);
+        assert!(DoRecalculation::<Test>::get().is_none());
 
         // Make sure `on_finalize` calls before the expected change are storage noops
-        Inflation::block_before_new_era(recalculation_era - 2);
-        assert_storage_noop!(Inflation::on_finalize(100));
-
-        Inflation::block_before_new_era(recalculation_era - 1);
-        assert_storage_noop!(Inflation::on_finalize(200));
+        assert_storage_noop!(Inflation::on_finalize(cycle_length - 1));
 
-        // One block before recalculation era starts, on_finalize should calculate new inflation config
-        Inflation::block_before_new_era(recalculation_era);
-        let init_config = ActiveInflationConfig::<Test>::get();
+        // One block before recalculation era starts, recalculation is scheduled.
+        run_to_block(cycle_length);
+        assert_eq!(DoRecalculation::<Test>::get(), Some(recalculation_era));
+        assert_eq!(ActiveInflationConfig::<Test>::get(), init_config);
         let init_total_issuance = Balances::total_issuance();
 
         // Finally trigger inflation recalculation.
-        Inflation::on_finalize(300);
+        Inflation::on_finalize(cycle_length);
 
         let new_config = ActiveInflationConfig::<Test>::get();
         assert_ne!(
             new_config, init_config,
             "Recalculation must happen at this point."
         );
+        assert_eq!(
+            new_config.recalculation_era,
+            recalculation_era + CycleConfig::eras_per_cycle()
+        );
+        assert!(DoRecalculation::<Test>::get().is_none());
         System::assert_last_event(Event::NewInflationConfiguration { config: new_config }.into());
 
         assert_eq!(
@@ -142,6 +472,83 @@ fn inflation_recalculation_occurs_when_expected() {
         );
 
         assert_eq!(new_config.issuance_safety_cap, init_total_issuance + InflationParams::<Test>::get().max_inflation_rate * init_total_issuance);
+
+        // Recalculation era starts in the next block.
+        run_to_block(cycle_length + 1);
+        assert_eq!(CurrentEraInfo::<Test>::get().era, recalculation_era);
+    })
+}
+
+#[test]
+fn era_reward_pools_payout_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        type CycleConfig = <Test as Config>::CycleConfiguration;
+
+        let config = ActiveInflationConfig::<Test>::get();
+        let pot_balance = |pot: PalletId| Balances::free_balance(&pot.into_account_truncating());
+
+        // End of the voting subperiod, no era rewards are paid out.
+        let voting_length =
+            CycleConfig::eras_per_voting_subperiod() * CycleConfig::blocks_per_era();
+        run_to_block(voting_length + 1);
+        assert!(pot_balance(DAPPS_POT).is_zero());
+        assert!(pot_balance(STAKERS_POT).is_zero());
+
+        // End of the first build&earn era, pools are paid out.
+        let init_issuance = Balances::total_issuance();
+        run_to_block(voting_length + CycleConfig::blocks_per_era() + 1);
+
+        assert_eq!(pot_balance(DAPPS_POT), config.dapp_reward_pool_per_era);
+        assert_eq!(
+            pot_balance(STAKERS_POT),
+            config.base_staker_reward_pool_per_era
+        );
+        assert!(pot_balance(BONUS_POT).is_zero());
+        assert!(System::events().iter().any(|record| record.event
+            == Event::EraRewardsPaidOut {
+                era: 2,
+                dapps: config.dapp_reward_pool_per_era,
+                base_stakers: config.base_staker_reward_pool_per_era,
+                adjustable_stakers: config.adjustable_staker_reward_pool_per_era,
+                bonus: 0,
+            }
+            .into()));
+
+        // Burned adjustable staker pool isn't issued.
+        let block_rewards = CycleConfig::blocks_per_era() as Balance
+            * (config.collator_reward_per_block + config.treasury_reward_per_block);
+        assert_eq!(
+            Balances::total_issuance(),
+            init_issuance
+                + block_rewards
+                + config.dapp_reward_pool_per_era
+                + config.base_staker_reward_pool_per_era
+        );
+
+        // End of the period, bonus pool is paid out as well.
+        let period_length = voting_length
+            + CycleConfig::eras_per_build_and_earn_subperiod() * CycleConfig::blocks_per_era();
+        run_to_block(period_length + 1);
+
+        let build_and_earn_eras = CycleConfig::eras_per_build_and_earn_subperiod() as Balance;
+        assert_eq!(
+            pot_balance(DAPPS_POT),
+            build_and_earn_eras * config.dapp_reward_pool_per_era
+        );
+        assert_eq!(
+            pot_balance(STAKERS_POT),
+            build_and_earn_eras * config.base_staker_reward_pool_per_era
+        );
+        assert_eq!(pot_balance(BONUS_POT), config.bonus_reward_pool_per_period);
+        assert!(System::events().iter().any(|record| record.event
+            == Event::EraRewardsPaidOut {
+                era: CycleConfig::eras_per_period(),
+                dapps: config.dapp_reward_pool_per_era,
+                base_stakers: config.base_staker_reward_pool_per_era,
+                adjustable_stakers: config.adjustable_staker_reward_pool_per_era,
+                bonus: config.bonus_reward_pool_per_period,
+            }
+            .into()));
     })
 }
 
@@ -174,6 +581,63 @@ fn on_initialize_reward_payout_works() {
     })
 }
 
+#[test]
+fn collator_reward_is_paid_to_aura_block_author() {
+    ExternalityBuilder::build().execute_with(|| {
+        let config = ActiveInflationConfig::<Test>::get();
+        let init_collator_pot = Balances::free_balance(&COLLATOR_POT.into_account_truncating());
+
+        // Each slot is assigned to a different authority, in round-robin fashion.
+        for slot in 0..2 * AUTHORITIES.len() as u64 {
+            set_aura_slot(slot);
+            let author = AUTHORITIES[slot as usize % AUTHORITIES.len()];
+            let init_author_balance = Balances::free_balance(&author);
+
+            Inflation::on_initialize(System::block_number());
+
+            assert_eq!(
+                Balances::free_balance(&author),
+                init_author_balance + config.collator_reward_per_block
+            );
+        }
+
+        // Pot is only used as a fallback, when the author cannot be found.
+        assert_eq!(
+            Balances::free_balance(&COLLATOR_POT.into_account_truncating()),
+            init_collator_pot
+        );
+    })
+}
+
+#[test]
+fn collator_reward_split_among_authorities_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let init_balances: Vec<_> = AUTHORITIES
+            .iter()
+            .map(|acc| Balances::free_balance(acc))
+            .collect();
+        let init_issuance = Balances::total_issuance();
+
+        let reward = Balances::issue(100);
+        SplitAmongAuthorities::<Test, AuraAuthorityAccounts, ()>::on_unbalanced(reward);
+
+        // Remainder goes to the first authority.
+        assert_eq!(
+            Balances::free_balance(&AUTHORITIES[0]),
+            init_balances[0] + 34
+        );
+        assert_eq!(
+            Balances::free_balance(&AUTHORITIES[1]),
+            init_balances[1] + 33
+        );
+        assert_eq!(
+            Balances::free_balance(&AUTHORITIES[2]),
+            init_balances[2] + 33
+        );
+        assert_eq!(Balances::total_issuance(), init_issuance + 100);
+    })
+}
+
 #[test]
 fn inflation_parameters_validity_check_works() {
     // Params to be used as anchor for the tests
@@ -385,37 +849,107 @@ fn basic_payout_reward_is_ok() {
 }
 
 #[test]
-fn payout_reward_with_exceeded_cap_but_not_exceeded_relaxed_cap_is_ok() {
+fn payout_reward_fails_when_cap_is_exceeded() {
     ExternalityBuilder::build().execute_with(|| {
         // Prepare reward payout params
         let config = ActiveInflationConfig::<Test>::get();
         let account = 1;
 
-        let relaxed_cap = config.issuance_safety_cap * 101 / 100;
-        let reward = relaxed_cap - Balances::total_issuance();
-        let init_balance = Balances::free_balance(&account);
-        let init_issuance = Balances::total_issuance();
+        let reward = config.issuance_safety_cap - Balances::total_issuance() + 1;
 
-        // Payout reward and verify balances are as expected
-        assert_ok!(Inflation::payout_reward(&account, reward));
+        // Payout should be a failure, with storage noop.
+        assert_noop!(Inflation::payout_reward(&account, reward), ());
+    })
+}
 
-        assert_eq!(Balances::free_balance(&account), init_balance + reward);
-        assert_eq!(Balances::total_issuance(), init_issuance + reward);
+#[test]
+fn block_rewards_are_clamped_to_issuance_safety_cap() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Leave room only for the collator reward & a part of the treasury reward.
+        let config = ActiveInflationConfig::<Test>::get();
+        let issuance_safety_cap = Balances::total_issuance() + config.collator_reward_per_block + 1;
+        ActiveInflationConfig::<Test>::mutate(|config| {
+            config.issuance_safety_cap = issuance_safety_cap;
+        });
+        let init_treasury_pot = Balances::free_balance(&TREASURY_POT.into_account_truncating());
+
+        Inflation::on_initialize(1);
+
+        assert_eq!(Balances::total_issuance(), issuance_safety_cap);
+        assert_eq!(
+            Balances::free_balance(&TREASURY_POT.into_account_truncating()),
+            init_treasury_pot + 1
+        );
+        System::assert_has_event(
+            Event::IssuanceSafetyCapReached {
+                issuance_safety_cap,
+            }
+            .into(),
+        );
+        assert_ok!(Inflation::do_try_state());
+
+        // Once the cap is reached, nothing more is issued & the event isn't repeated.
+        let events_count = System::events().len();
+        Inflation::on_initialize(1);
+
+        assert_eq!(Balances::total_issuance(), issuance_safety_cap);
+        assert_eq!(System::events().len(), events_count);
     })
 }
 
 #[test]
-fn payout_reward_fails_when_relaxed_cap_is_exceeded() {
+fn era_rewards_are_clamped_to_issuance_safety_cap() {
     ExternalityBuilder::build().execute_with(|| {
-        // Prepare reward payout params
+        let ended_era_info = EraInfo {
+            era: 2,
+            period: 1,
+            subperiod: Subperiod::BuildAndEarn,
+            next_period_start_era: 10,
+            next_era_start: 1,
+        };
+        let era_info = ended_era_info.next::<<Test as Config>::CycleConfiguration>(1);
+
+        // Leave room only for a part of the dApp reward pool.
         let config = ActiveInflationConfig::<Test>::get();
-        let account = 1;
+        let issuance_safety_cap = Balances::total_issuance() + config.dapp_reward_pool_per_era / 2;
+        ActiveInflationConfig::<Test>::mutate(|config| {
+            config.issuance_safety_cap = issuance_safety_cap;
+        });
 
-        let relaxed_cap = config.issuance_safety_cap * 101 / 100;
-        let reward = relaxed_cap - Balances::total_issuance() + 1;
+        let issued = Inflation::payout_era_rewards(&ended_era_info, &era_info);
 
-        // Payout should be a failure, with storage noop.
-        assert_noop!(Inflation::payout_reward(&account, reward), ());
+        assert_eq!(issued, config.dapp_reward_pool_per_era / 2);
+        assert_eq!(Balances::total_issuance(), issuance_safety_cap);
+        System::assert_has_event(
+            Event::IssuanceSafetyCapReached {
+                issuance_safety_cap,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::EraRewardsPaidOut {
+                era: 2,
+                dapps: config.dapp_reward_pool_per_era / 2,
+                base_stakers: 0,
+                adjustable_stakers: 0,
+                bonus: 0,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn try_state_detects_exceeded_issuance_safety_cap() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(Inflation::do_try_state());
+
+        // Mint past the cap, bypassing the pallet.
+        let config = ActiveInflationConfig::<Test>::get();
+        let excess = config.issuance_safety_cap - Balances::total_issuance() + 1;
+        let _ = Balances::deposit_creating(&1, excess);
+
+        assert!(Inflation::do_try_state().is_err());
     })
 }
 
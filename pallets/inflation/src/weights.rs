@@ -53,6 +53,8 @@ pub trait WeightInfo {
 	fn force_inflation_recalculation() -> Weight;
 	fn recalculation() -> Weight;
 	fn hooks_without_recalculation() -> Weight;
+	fn era_payout() -> Weight;
+	fn set_inflation_curve() -> Weight;
 }
 
 /// Weights for pallet_inflation using the Substrate node and recommended hardware.
@@ -99,6 +101,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: System Account (r:4 w:4)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn era_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `11402`
+		// Minimum execution time: 42_187_000 picoseconds.
+		Weight::from_parts(42_903_000, 11402)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Inflation ActiveInflationCurve (r:0 w:1)
+	/// Proof: Inflation ActiveInflationCurve (max_values: Some(1), max_size: Some(770), added: 1265, mode: MaxEncodedLen)
+	fn set_inflation_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_587_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -144,4 +167,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: System Account (r:4 w:4)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn era_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `280`
+		//  Estimated: `11402`
+		// Minimum execution time: 42_187_000 picoseconds.
+		Weight::from_parts(42_903_000, 11402)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Inflation ActiveInflationCurve (r:0 w:1)
+	/// Proof: Inflation ActiveInflationCurve (max_values: Some(1), max_size: Some(770), added: 1265, mode: MaxEncodedLen)
+	fn set_inflation_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_587_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
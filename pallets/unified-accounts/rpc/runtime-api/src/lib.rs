@@ -0,0 +1,61 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::evm::EvmAddress;
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
+
+/// Mapping state of a native account.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AccountMapping<BlockNumber> {
+    /// The evm address mapped to the account, if any.
+    pub evm_address: Option<EvmAddress>,
+    /// The default evm address of the account, used if no mapping exists.
+    pub default_evm_address: EvmAddress,
+    /// The block at which the unlink of the mapping was announced, if any.
+    pub unlink_announced_at: Option<BlockNumber>,
+}
+
+sp_api::decl_runtime_apis! {
+
+    /// API for building and verifying unified accounts claims.
+    pub trait UnifiedAccountsApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// EIP-712 typed data (domain, types and message) of the claim payload
+        /// for `account_id`, encoded as UTF-8 JSON ready for `eth_signTypedData_v4`.
+        fn claim_typed_data(account_id: AccountId) -> Vec<u8>;
+
+        /// Recover the evm address which signed the claim payload of `account_id`.
+        ///
+        /// Returns `None` if the signature is malformed.
+        fn verify_claim_signature(account_id: AccountId, signature: [u8; 65]) -> Option<EvmAddress>;
+
+        /// The current mapping state of `account_id`.
+        fn account_mapping(account_id: AccountId) -> AccountMapping<BlockNumber>;
+    }
+}
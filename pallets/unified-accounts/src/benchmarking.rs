@@ -84,6 +84,130 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn announce_unlink() {
+        let caller: T::AccountId = whitelisted_caller();
+        let evm_address = T::DefaultMappings::to_default_h160(&caller);
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        // claim mapping
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_last_event::<T>(
+            Event::<T>::UnlinkAnnounced {
+                account_id: caller,
+                evm_address,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn cancel_unlink() {
+        let caller: T::AccountId = whitelisted_caller();
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        // claim mapping & announce unlink
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+        assert_ok!(Pallet::<T>::announce_unlink(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_last_event::<T>(Event::<T>::UnlinkCancelled { account_id: caller }.into());
+    }
+
+    #[benchmark]
+    fn unlink() {
+        let caller: T::AccountId = whitelisted_caller();
+        let eth_secret_key = libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap();
+        let evm_address = Pallet::<T>::eth_address(&eth_secret_key);
+        let signature = Pallet::<T>::eth_sign_prehash(
+            &Pallet::<T>::build_signing_payload(&caller),
+            &eth_secret_key,
+        )
+        .into();
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        // claim mapping & announce unlink
+        assert_ok!(Pallet::<T>::claim_evm_address(
+            RawOrigin::Signed(caller.clone()).into(),
+            evm_address,
+            signature
+        ));
+        assert_ok!(Pallet::<T>::announce_unlink(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+        let announced_at = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(announced_at + T::UnlinkDelay::get());
+
+        let unlink_signature = Pallet::<T>::eth_sign_prehash(
+            &Pallet::<T>::build_unlink_signing_payload(&caller, announced_at),
+            &eth_secret_key,
+        )
+        .into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), Some(unlink_signature));
+
+        assert_last_event::<T>(
+            Event::<T>::AccountUnlinked {
+                account_id: caller,
+                evm_address,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn force_unlink() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let evm_address = T::DefaultMappings::to_default_h160(&caller);
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        // claim mapping & announce unlink
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+        assert_ok!(Pallet::<T>::announce_unlink(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+        let announced_at = frame_system::Pallet::<T>::block_number();
+        frame_system::Pallet::<T>::set_block_number(announced_at + T::UnlinkDelay::get());
+        let origin = T::UnlinkRecoveryOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, caller.clone());
+
+        assert_last_event::<T>(
+            Event::<T>::AccountUnlinked {
+                account_id: caller,
+                evm_address,
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
     #[benchmark]
     fn to_account_id() {
         let caller: T::AccountId = whitelisted_caller();
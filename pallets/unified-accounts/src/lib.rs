@@ -38,26 +38,49 @@
 //!    account id given that no prior mapping exists for both and signature provided is valid.
 //! * `claim_default_evm_address`: Creates the double mapping with default evm address given that
 //!    no prior mapping exists.
+//! * `claim_evm_address_with_assets`: Same as `claim_evm_address`, additionally migrating the given
+//!    assets and vesting schedules of the default account via
+//!    [`ClaimAssetsMigrator`](`crate::Config::ClaimAssetsMigrator`).
+//! * `claim_contract_evm_address`: Creates the double mapping for the evm address of a smart contract
+//!    wallet, validating the signature with EIP-1271 `isValidSignature(bytes32,bytes)`.
+//! * `announce_unlink`: Announces the removal of caller's mappings, which can be executed after
+//!    [`UnlinkDelay`](`crate::Config::UnlinkDelay`).
+//! * `cancel_unlink`: Cancels the announced unlink.
+//! * `unlink`: Removes the mappings after the delay, given a valid signature from the evm address.
+//! * `force_unlink`: Removes the mappings after the delay without evm address signature, for
+//!    recovering accounts which lost access to their evm address.
+//! * `reissue_deposits`: Reissues the storage deposits of mappings claimed before deposits were
+//!    held, see [`HoldStorageDeposits`](`migration::HoldStorageDeposits`).
 //!
-//! ## Storage Fee
-//! User is also charged a storage fee [`AccountMappingStorageFee`](`crate::Config::AccountMappingStorageFee`)
-//! before mappings are created to prevent storage abuse.
+//! ## Storage Deposit
+//! User's [`AccountMappingStorageFee`](`crate::Config::AccountMappingStorageFee`) is also put on hold
+//! with [`HoldReason::AccountMapping`] before mappings are created to prevent storage abuse.
+//! As the deposit keeps the account alive, mappings are removed by unlinking them, which releases
+//! the deposit. `KillAccountMapping` removes the mappings of accounts reaped nonetheless, e.g. if
+//! the deposit was slashed.
 //!
 //! WARNINGS:
-//! * This pallet only handles transfer of native balance only, for the rest of native assets
+//! * `claim_evm_address` only handles transfer of native balance, for the rest of native assets
 //!   hold by evm address like XC20, DAppStaking unclaimed rewards, etc should be transferred
-//!   manually beforehand by user himself otherwise FUNDS WILL BE LOST FOREVER.
-//! * Once mapping is created it cannot be changed.
+//!   manually beforehand by user himself (or migrated with `claim_evm_address_with_assets`)
+//!   otherwise FUNDS WILL BE LOST FOREVER.
+//! * Once mapping is created it can only be changed by unlinking it first, which requires
+//!   both signatures (or the recovery origin) and a time-locked announcement.
 //!
 //! ## Traits
 //!
 //! * `UnifiedAddressMapper`: Interface to access pallet's mappings with defaults
+//! * `ClaimAssetsMigrator`: Interface to migrate the assets of default account on claim
+//! * `ContractSignatureValidator`: Interface to validate signatures of smart contract wallets
 //!
 //! ## Implementations
 //!
 //! * [`StaticLookup`](sp_runtime::traits::StaticLookup): Lookup implementations for accepting H160
+//! * `UnifiedAccountsLookup`: [`StaticLookup`](sp_runtime::traits::StaticLookup) implementation resolving
+//!   every `MultiAddress` variant, delegating `Id` and `Index` to a pluggable index lookup.
 //! * [`AddressMapping`](pallet_evm::AddressMapping): Wrapper over `UnifiedAddressMapper` for evm address mapping
 //!   to account id.
+//! * `EvmContractSignatureValidator`: EIP-1271 `ContractSignatureValidator` using read-only EVM calls.
 //! * `KillAccountMapping`: [`OnKilledAccount`](frame_support::traits::OnKilledAccount) implementation to remove
 //!   the mappings from storage after account is reaped.
 
@@ -69,22 +92,32 @@ use astar_primitives::{
 };
 use frame_support::{
     pallet_prelude::*,
+    storage::{with_transaction, TransactionOutcome},
     traits::{
-        fungible::{Inspect as FungibleInspect, Mutate as FungibleMutate},
+        fungible::{
+            Inspect as FungibleInspect, InspectHold as FungibleInspectHold,
+            Mutate as FungibleMutate, MutateHold as FungibleMutateHold,
+        },
         tokens::{Fortitude::*, Precision::*, Preservation::*},
         IsType, OnKilledAccount,
     },
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
-use pallet_evm::AddressMapping;
-use precompile_utils::keccak256;
-use sp_core::{H160, H256, U256};
+use pallet_evm::{AddressMapping, GasWeightMapping, Runner};
+use precompile_utils::{
+    keccak256,
+    solidity::codec::{UnboundedBytes, Writer},
+};
+use sp_core::{hexdisplay::HexDisplay, H160, H256, U256};
 use sp_io::hashing::keccak_256;
 use sp_runtime::{
-    traits::{LookupError, StaticLookup, Zero},
+    traits::{AccountIdLookup, LookupError, SaturatedConversion, Saturating, StaticLookup, Zero},
     MultiAddress,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, prelude::*};
+
+extern crate alloc;
+use alloc::format;
 
 pub use pallet::*;
 
@@ -100,19 +133,34 @@ mod tests;
 /// ECDSA Signature type, with last bit for recovering address
 type EvmSignature = [u8; 65];
 
+/// Proof of evm address ownership for claims
+enum OwnershipProof<'a> {
+    /// ECDSA signature of the evm address private key
+    Ecdsa(&'a EvmSignature),
+    /// Signature validated by the smart contract wallet at evm address (EIP-1271)
+    Contract(&'a [u8]),
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
+    /// The current storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The overarching event type
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
         /// The Currency for managing evm address assets
-        type Currency: FungibleMutate<Self::AccountId, Balance = Balance>;
+        type Currency: FungibleMutate<Self::AccountId, Balance = Balance>
+            + FungibleMutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
         /// Default address conversion
         type DefaultMappings: UnifiedAddressMapper<Self::AccountId>;
         /// EVM chain id
@@ -123,10 +171,35 @@ pub mod pallet {
         /// respectively
         #[pallet::constant]
         type AccountMappingStorageFee: Get<Balance>;
+        /// Number of blocks between an unlink announcement and its execution.
+        #[pallet::constant]
+        type UnlinkDelay: Get<BlockNumberFor<Self>>;
+        /// Origin which can unlink mappings without evm address signature
+        type UnlinkRecoveryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Identifier of the assets migrated on claim
+        type AssetId: Parameter + MaxEncodedLen;
+        /// Migrates the assets of default account on claim
+        type ClaimAssetsMigrator: ClaimAssetsMigrator<Self::AccountId, Self::AssetId>;
+        /// Maximum number of assets migrated on claim
+        #[pallet::constant]
+        type MaxClaimAssets: Get<u32>;
+        /// Validates signatures of smart contract wallets (EIP-1271)
+        type ContractSignatureValidator: ContractSignatureValidator;
+        /// Maximum length of smart contract wallet signatures
+        #[pallet::constant]
+        type MaxContractSignatureLength: Get<u32>;
         /// Weight information for the extrinsics in this module
         type WeightInfo: WeightInfo;
     }
 
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Storage deposit for the account mappings.
+        #[codec(index = 0)]
+        AccountMapping,
+    }
+
     #[pallet::error]
     pub enum Error<T> {
         /// AccountId or EvmAddress already mapped
@@ -137,6 +210,22 @@ pub mod pallet {
         InvalidSignature,
         /// Funds unavailable to claim account
         FundsUnavailable,
+        /// AccountId is not mapped
+        NotMapped,
+        /// Unlink already announced
+        UnlinkAlreadyAnnounced,
+        /// Unlink not announced
+        UnlinkNotAnnounced,
+        /// Unlink delay has not elapsed yet
+        UnlinkDelayNotElapsed,
+        /// Evm address signature is missing
+        MissingSignature,
+        /// Default account has funds on hold, which can't be migrated
+        FundsOnHold,
+        /// Claiming account can't receive all the vesting schedules of the default account
+        TooManyVestingSchedules,
+        /// All storage deposits have been reissued
+        NoDepositsToReissue,
     }
 
     #[pallet::event]
@@ -148,6 +237,18 @@ pub mod pallet {
             account_id: T::AccountId,
             evm_address: EvmAddress,
         },
+        /// Unlink of the mappings announced.
+        UnlinkAnnounced {
+            account_id: T::AccountId,
+            evm_address: EvmAddress,
+        },
+        /// Unlink announcement cancelled.
+        UnlinkCancelled { account_id: T::AccountId },
+        /// Double Mapping b/w native and evm address removed
+        AccountUnlinked {
+            account_id: T::AccountId,
+            evm_address: EvmAddress,
+        },
     }
 
     /// Native accounts for evm address
@@ -162,6 +263,26 @@ pub mod pallet {
     pub type NativeToEvm<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, EvmAddress, OptionQuery>;
 
+    /// Block number of unlink announcements for native accounts
+    /// PendingUnlinks: AccountId => Option<BlockNumber>
+    #[pallet::storage]
+    pub type PendingUnlinks<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Raw key of the last `NativeToEvm` entry whose storage deposit was reissued by
+    /// [`migration::HoldStorageDeposits`], present while the migration is ongoing.
+    #[pallet::storage]
+    pub type HoldDepositsCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, migration::MaxCursorLen>, OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Claim account mapping between Substrate account and Evm address.
@@ -173,8 +294,9 @@ pub mod pallet {
         /// WARNING:
         /// - This extrisic only handles transfer of native balance, if your EVM
         /// address contains any other native assets like XC20, DAppStaking unclaimed rewards,
-        /// etc you need to transfer them before hand, otherwise FUNDS WILL BE LOST FOREVER.
-        /// - Once connected user cannot change their mapping EVER.
+        /// etc you need to transfer them before hand (or use `claim_evm_address_with_assets`),
+        /// otherwise FUNDS WILL BE LOST FOREVER.
+        /// - Once connected user can only change their mapping through `unlink`.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::claim_evm_address())]
         pub fn claim_evm_address(
@@ -183,55 +305,13 @@ pub mod pallet {
             signature: EvmSignature,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            // make sure no prior mapping exists
-            ensure!(
-                !NativeToEvm::<T>::contains_key(&who),
-                Error::<T>::AlreadyMapped
-            );
-            ensure!(
-                !EvmToNative::<T>::contains_key(evm_address),
-                Error::<T>::AlreadyMapped
-            );
-
-            // recover evm address from signature
-            let address = Self::verify_signature(&who, &signature)
-                .ok_or(Error::<T>::UnexpectedSignatureFormat)?;
-
-            ensure!(evm_address == address, Error::<T>::InvalidSignature);
-
-            // charge the storage fee
-            Self::charge_storage_fee(&who)?;
-
-            // Check if the default account id already exists for this evm address
-            let default_account_id = T::DefaultMappings::to_default_account_id(&evm_address);
-            if frame_system::Pallet::<T>::account_exists(&default_account_id) {
-                // Transfer all the free native balance from old account id to the newly
-                // since this `default_account_id` will no longer be connected to evm address
-                // and users cannot access it.
-                // For the reset of the assets types (like XC20, etc) that should be handled by UI.
-                T::Currency::transfer(
-                    &default_account_id,
-                    &who,
-                    T::Currency::reducible_balance(&default_account_id, Expendable, Polite),
-                    Expendable,
-                )?;
-            }
-
-            // create double mappings for the pair
-            EvmToNative::<T>::insert(&evm_address, &who);
-            NativeToEvm::<T>::insert(&who, &evm_address);
-
-            Self::deposit_event(Event::AccountClaimed {
-                account_id: who,
-                evm_address,
-            });
-            Ok(())
+            Self::do_claim_evm_address(who, evm_address, OwnershipProof::Ecdsa(&signature), None)
         }
 
         /// Claim default evm address for given account id
         /// Ensure no prior mapping exists for the account
         ///
-        /// WARNINGS: Once connected user cannot change their mapping EVER.
+        /// WARNINGS: Once connected user can only change their mapping through `unlink`.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::claim_default_evm_address())]
         pub fn claim_default_evm_address(origin: OriginFor<T>) -> DispatchResult {
@@ -240,10 +320,244 @@ pub mod pallet {
             let _ = Self::do_claim_default_evm_address(who)?;
             Ok(())
         }
+
+        /// Announce the unlink of caller's mappings, which can be executed with `unlink`
+        /// or `force_unlink` after `UnlinkDelay` blocks.
+        ///
+        /// The delay gives both sides of the mapping time to react before it changes.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::announce_unlink())]
+        pub fn announce_unlink(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let evm_address = NativeToEvm::<T>::get(&who).ok_or(Error::<T>::NotMapped)?;
+            ensure!(
+                !PendingUnlinks::<T>::contains_key(&who),
+                Error::<T>::UnlinkAlreadyAnnounced
+            );
+
+            PendingUnlinks::<T>::insert(&who, frame_system::Pallet::<T>::block_number());
+
+            Self::deposit_event(Event::UnlinkAnnounced {
+                account_id: who,
+                evm_address,
+            });
+            Ok(())
+        }
+
+        /// Cancel the announced unlink of caller's mappings.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cancel_unlink())]
+        pub fn cancel_unlink(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            PendingUnlinks::<T>::take(&who).ok_or(Error::<T>::UnlinkNotAnnounced)?;
+
+            Self::deposit_event(Event::UnlinkCancelled { account_id: who });
+            Ok(())
+        }
+
+        /// Remove the mappings of caller's account and release the storage deposit.
+        /// Unlink must have been announced at least `UnlinkDelay` blocks before.
+        ///
+        /// - `signature`: A signature generated by the mapped evm address to approve the unlink,
+        ///   not needed if caller is mapped to its default evm address.
+        ///
+        /// Once unlinked, caller can claim a new evm address.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::unlink())]
+        pub fn unlink(origin: OriginFor<T>, signature: Option<EvmSignature>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let evm_address = NativeToEvm::<T>::get(&who).ok_or(Error::<T>::NotMapped)?;
+            let announced_at =
+                PendingUnlinks::<T>::get(&who).ok_or(Error::<T>::UnlinkNotAnnounced)?;
+
+            // default evm address has no private key, nothing to approve the unlink with
+            if evm_address != T::DefaultMappings::to_default_h160(&who) {
+                let signature = signature.ok_or(Error::<T>::MissingSignature)?;
+                // recover evm address from signature
+                let address = Self::verify_unlink_signature(&who, announced_at, &signature)
+                    .ok_or(Error::<T>::UnexpectedSignatureFormat)?;
+                ensure!(evm_address == address, Error::<T>::InvalidSignature);
+            }
+
+            Self::do_unlink(who, evm_address, announced_at)
+        }
+
+        /// Remove the mappings of given account without evm address signature and release
+        /// the storage deposit, to recover accounts which lost access to their evm address.
+        /// Unlink must have been announced by the account at least `UnlinkDelay` blocks before.
+        ///
+        /// Can only be called by `UnlinkRecoveryOrigin`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::force_unlink())]
+        pub fn force_unlink(origin: OriginFor<T>, account_id: T::AccountId) -> DispatchResult {
+            T::UnlinkRecoveryOrigin::ensure_origin(origin)?;
+            let evm_address = NativeToEvm::<T>::get(&account_id).ok_or(Error::<T>::NotMapped)?;
+            let announced_at =
+                PendingUnlinks::<T>::get(&account_id).ok_or(Error::<T>::UnlinkNotAnnounced)?;
+
+            Self::do_unlink(account_id, evm_address, announced_at)
+        }
+
+        /// Claim account mapping between Substrate account and Evm address like
+        /// `claim_evm_address`, additionally migrating the given assets and vesting schedules
+        /// from the default account of evm address to the caller's account.
+        ///
+        /// - `evm_address`: The evm address to bind to the caller's account
+        /// - `signature`: A signature generated by the address to prove ownership
+        /// - `assets`: The ids of the assets to migrate from the default account, bounded
+        ///   by [`MaxClaimAssets`](`crate::Config::MaxClaimAssets`)
+        ///
+        /// WARNING:
+        /// - Only the given assets are transferred, any other assets
+        /// held by the default account will be LOST FOREVER.
+        /// - Once connected user can only change their mapping through `unlink`.
+        /// - Claim fails while the default account has funds on hold, since the pallets
+        /// which placed them still refer to the default account.
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            T::WeightInfo::claim_evm_address()
+                .saturating_add(T::ClaimAssetsMigrator::migrate_weight(assets.len() as u32))
+        )]
+        pub fn claim_evm_address_with_assets(
+            origin: OriginFor<T>,
+            evm_address: EvmAddress,
+            signature: EvmSignature,
+            assets: BoundedVec<T::AssetId, T::MaxClaimAssets>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_claim_evm_address(
+                who,
+                evm_address,
+                OwnershipProof::Ecdsa(&signature),
+                Some(&assets),
+            )
+        }
+
+        /// Claim account mapping between Substrate account and Evm address of a smart contract
+        /// wallet (like Safe or account abstraction wallets), which cannot produce ECDSA signatures.
+        /// Ensure no prior mapping exists for evm address.
+        ///
+        /// - `evm_address`: The evm address of the smart contract wallet to bind to the
+        ///   caller's account
+        /// - `signature`: A signature over the claim payload, which is validated by calling
+        ///   `isValidSignature(bytes32,bytes)` on the wallet contract following EIP-1271
+        ///
+        /// WARNING:
+        /// - Same as `claim_evm_address`, only native balance is transferred.
+        /// - Once connected user can only change their mapping through `unlink`.
+        #[pallet::call_index(7)]
+        #[pallet::weight(
+            T::WeightInfo::claim_evm_address()
+                .saturating_add(T::ContractSignatureValidator::validate_weight())
+        )]
+        pub fn claim_contract_evm_address(
+            origin: OriginFor<T>,
+            evm_address: EvmAddress,
+            signature: BoundedVec<u8, T::MaxContractSignatureLength>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::do_claim_evm_address(who, evm_address, OwnershipProof::Contract(&signature), None)
+        }
+
+        /// Reissue the storage deposits of mappings claimed before deposits were held,
+        /// within the given `weight_limit`.
+        ///
+        /// Can be called by anyone while [`migration::HoldStorageDeposits`] is ongoing,
+        /// and is free of charge if it makes progress.
+        #[pallet::call_index(8)]
+        #[pallet::weight(weight_limit.saturating_add(T::DbWeight::get().reads(1)))]
+        pub fn reissue_deposits(
+            origin: OriginFor<T>,
+            weight_limit: Weight,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let cursor = HoldDepositsCursor::<T>::get().ok_or(Error::<T>::NoDepositsToReissue)?;
+
+            let consumed = migration::HoldStorageDeposits::<T>::reissue_deposits(weight_limit)
+                .saturating_add(T::DbWeight::get().reads(1));
+            let pays_fee = if HoldDepositsCursor::<T>::get() == Some(cursor) {
+                Pays::Yes
+            } else {
+                Pays::No
+            };
+            Ok((Some(consumed), pays_fee).into())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Claim the given evm address, migrating the assets of its default account if given
+    fn do_claim_evm_address(
+        who: T::AccountId,
+        evm_address: EvmAddress,
+        proof: OwnershipProof,
+        maybe_assets: Option<&[T::AssetId]>,
+    ) -> DispatchResult {
+        // make sure no prior mapping exists
+        ensure!(
+            !NativeToEvm::<T>::contains_key(&who),
+            Error::<T>::AlreadyMapped
+        );
+        ensure!(
+            !EvmToNative::<T>::contains_key(evm_address),
+            Error::<T>::AlreadyMapped
+        );
+
+        match proof {
+            OwnershipProof::Ecdsa(signature) => {
+                // recover evm address from signature
+                let address = Self::verify_signature(&who, signature)
+                    .ok_or(Error::<T>::UnexpectedSignatureFormat)?;
+
+                ensure!(evm_address == address, Error::<T>::InvalidSignature);
+            }
+            OwnershipProof::Contract(signature) => {
+                // ask the smart contract wallet to validate the signature (EIP-1271)
+                ensure!(
+                    T::ContractSignatureValidator::is_valid_signature(
+                        &evm_address,
+                        Self::build_signing_payload(&who),
+                        signature,
+                    ),
+                    Error::<T>::InvalidSignature
+                );
+            }
+        }
+
+        // hold the storage deposit
+        Self::charge_storage_fee(&who)?;
+
+        // Check if the default account id already exists for this evm address
+        let default_account_id = T::DefaultMappings::to_default_account_id(&evm_address);
+        if frame_system::Pallet::<T>::account_exists(&default_account_id) {
+            // Migrate the requested assets and vesting schedules first so
+            // the unlocked native balance is transferred below.
+            if let Some(assets) = maybe_assets {
+                T::ClaimAssetsMigrator::migrate(&default_account_id, &who, assets)?;
+            }
+            // Transfer all the free native balance from old account id to the newly
+            // since this `default_account_id` will no longer be connected to evm address
+            // and users cannot access it.
+            // For the reset of the assets types (like XC20, etc) that should be handled by UI.
+            T::Currency::transfer(
+                &default_account_id,
+                &who,
+                T::Currency::reducible_balance(&default_account_id, Expendable, Polite),
+                Expendable,
+            )?;
+        }
+
+        // create double mappings for the pair
+        EvmToNative::<T>::insert(&evm_address, &who);
+        NativeToEvm::<T>::insert(&who, &evm_address);
+
+        Self::deposit_event(Event::AccountClaimed {
+            account_id: who,
+            evm_address,
+        });
+        Ok(())
+    }
+
     /// Claim the default evm address
     fn do_claim_default_evm_address(account_id: T::AccountId) -> Result<EvmAddress, DispatchError> {
         ensure!(
@@ -272,13 +586,171 @@ impl<T: Config> Pallet<T> {
         Ok(evm_address)
     }
 
-    /// Charge the (exact) storage fee (politely) from the user and burn it
+    /// Remove the double mappings once unlink delay has elapsed and release the storage deposit
+    fn do_unlink(
+        account_id: T::AccountId,
+        evm_address: EvmAddress,
+        announced_at: BlockNumberFor<T>,
+    ) -> DispatchResult {
+        ensure!(
+            frame_system::Pallet::<T>::block_number()
+                >= announced_at.saturating_add(T::UnlinkDelay::get()),
+            Error::<T>::UnlinkDelayNotElapsed
+        );
+
+        EvmToNative::<T>::remove(&evm_address);
+        NativeToEvm::<T>::remove(&account_id);
+        PendingUnlinks::<T>::remove(&account_id);
+
+        // release the storage deposit held on claim
+        T::Currency::release_all(&HoldReason::AccountMapping.into(), &account_id, BestEffort)?;
+
+        Self::deposit_event(Event::AccountUnlinked {
+            account_id,
+            evm_address,
+        });
+        Ok(())
+    }
+
+    /// Hold the (exact) storage fee from the user as deposit for the mappings
     /// while preserving the account from being reaped.
     fn charge_storage_fee(who: &T::AccountId) -> Result<Balance, DispatchError> {
         let balance = T::Currency::reducible_balance(who, Preserve, Polite);
         let fee = T::AccountMappingStorageFee::get();
         ensure!(balance >= fee, Error::<T>::FundsUnavailable);
-        T::Currency::burn_from(who, T::AccountMappingStorageFee::get(), Exact, Polite)
+        T::Currency::hold(&HoldReason::AccountMapping.into(), who, fee)?;
+        Ok(fee)
+    }
+
+    /// Check the invariants of the double mappings and their storage deposits.
+    #[cfg(any(feature = "try-runtime", test))]
+    pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+        // deposits of existing mappings are still being reissued
+        let has_deposit =
+            !T::AccountMappingStorageFee::get().is_zero() && !HoldDepositsCursor::<T>::exists();
+        for (account_id, evm_address) in NativeToEvm::<T>::iter() {
+            ensure!(
+                EvmToNative::<T>::get(&evm_address) == Some(account_id.clone()),
+                "NativeToEvm mapping has no matching EvmToNative mapping"
+            );
+            ensure!(
+                !has_deposit
+                    || !T::Currency::balance_on_hold(
+                        &HoldReason::AccountMapping.into(),
+                        &account_id
+                    )
+                    .is_zero(),
+                "Mapped account has no storage deposit on hold"
+            );
+        }
+        for (evm_address, account_id) in EvmToNative::<T>::iter() {
+            ensure!(
+                NativeToEvm::<T>::get(&account_id) == Some(evm_address),
+                "EvmToNative mapping has no matching NativeToEvm mapping"
+            );
+        }
+        for (account_id, _) in PendingUnlinks::<T>::iter() {
+            ensure!(
+                NativeToEvm::<T>::contains_key(&account_id),
+                "Unlink announced for unmapped account"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Migrates the assets of an account to another, used to transfer the assets of
+/// the default account of an evm address to the account claiming it.
+pub trait ClaimAssetsMigrator<AccountId, AssetId> {
+    /// Transfer the balances of `assets` and the vesting schedules of `from` to `to`.
+    ///
+    /// Fails if `from` has funds on hold, as the pallets which placed them keep referring to
+    /// `from` and couldn't release them from `to`.
+    fn migrate(from: &AccountId, to: &AccountId, assets: &[AssetId]) -> DispatchResult;
+
+    /// Weight of migrating the given number of assets.
+    fn migrate_weight(assets: u32) -> Weight;
+}
+
+impl<AccountId, AssetId> ClaimAssetsMigrator<AccountId, AssetId> for () {
+    fn migrate(_from: &AccountId, _to: &AccountId, _assets: &[AssetId]) -> DispatchResult {
+        Ok(())
+    }
+
+    fn migrate_weight(_assets: u32) -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Validates signatures of smart contract wallets following EIP-1271.
+pub trait ContractSignatureValidator {
+    /// Whether the contract at `contract` considers `signature` valid for `hash`.
+    fn is_valid_signature(contract: &EvmAddress, hash: [u8; 32], signature: &[u8]) -> bool;
+
+    /// Weight of validating a signature.
+    fn validate_weight() -> Weight;
+}
+
+impl ContractSignatureValidator for () {
+    fn is_valid_signature(_contract: &EvmAddress, _hash: [u8; 32], _signature: &[u8]) -> bool {
+        false
+    }
+
+    fn validate_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// EIP-1271 magic value, the selector of `isValidSignature(bytes32,bytes)`
+pub const EIP1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// [`ContractSignatureValidator`] calling `isValidSignature(bytes32,bytes)` on the contract
+/// with a read-only EVM call, limited to `GasLimit` gas.
+///
+/// The call is executed in a storage layer which is always discarded.
+pub struct EvmContractSignatureValidator<T, GasLimit>(PhantomData<(T, GasLimit)>);
+impl<T: pallet_evm::Config, GasLimit: Get<u64>> ContractSignatureValidator
+    for EvmContractSignatureValidator<T, GasLimit>
+{
+    fn is_valid_signature(contract: &EvmAddress, hash: [u8; 32], signature: &[u8]) -> bool {
+        let input = Writer::new_with_selector(u32::from_be_bytes(EIP1271_MAGIC_VALUE))
+            .write(H256::from(hash))
+            .write(UnboundedBytes::from(signature))
+            .build();
+
+        let result = with_transaction(|| -> TransactionOutcome<Result<bool, DispatchError>> {
+            let is_valid = T::Runner::call(
+                H160::zero(),
+                *contract,
+                input,
+                U256::zero(),
+                GasLimit::get(),
+                None,
+                None,
+                None,
+                Vec::new(),
+                false,
+                false,
+                None,
+                None,
+                T::config(),
+            )
+            .map(|info| {
+                // the magic value must be returned as an ABI encoded `bytes4`, so contracts
+                // echoing their calldata aren't taken as signers
+                info.exit_reason.is_succeed()
+                    && info.value.len() == 32
+                    && info.value[..4] == EIP1271_MAGIC_VALUE
+                    && info.value[4..].iter().all(|byte| *byte == 0)
+            })
+            .unwrap_or(false);
+            TransactionOutcome::Rollback(Ok(is_valid))
+        });
+        result.unwrap_or(false)
+    }
+
+    fn validate_weight() -> Weight {
+        T::GasWeightMapping::gas_to_weight(GasLimit::get(), true)
     }
 }
 
@@ -323,6 +795,71 @@ impl<T: Config> Pallet<T> {
         args_hash.extend_from_slice(&keccak_256(&account.encode()));
         keccak_256(args_hash.as_slice())
     }
+
+    /// Build the EIP-712 typed data of the claim payload for `who` as UTF-8 JSON,
+    /// which can be signed with `eth_signTypedData_v4` as is.
+    pub fn build_claim_typed_data(who: &T::AccountId) -> Vec<u8> {
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+        format!(
+            concat!(
+                r#"{{"types":{{"EIP712Domain":["#,
+                r#"{{"name":"name","type":"string"}},"#,
+                r#"{{"name":"version","type":"string"}},"#,
+                r#"{{"name":"chainId","type":"uint256"}},"#,
+                r#"{{"name":"salt","type":"bytes32"}}],"#,
+                r#""Claim":[{{"name":"substrateAddress","type":"bytes"}}]}},"#,
+                r#""primaryType":"Claim","#,
+                r#""domain":{{"name":"Astar EVM Claim","version":"1","chainId":{},"salt":"0x{}"}},"#,
+                r#""message":{{"substrateAddress":"0x{}"}}}}"#,
+            ),
+            T::ChainId::get(),
+            HexDisplay::from(&genesis_hash.as_ref()),
+            HexDisplay::from(&who.encode()),
+        )
+        .into_bytes()
+    }
+}
+
+/// EIP-712 signature scheme for approving the unlink of an EVM Address.
+/// The announcement block number is signed as well so signatures cannot be replayed
+/// for a later unlink.
+///
+/// Raw Data = Domain Separator + Type Hash + keccak256(AccountId) + Announcement
+impl<T: Config> Pallet<T> {
+    pub fn build_unlink_signing_payload(
+        who: &T::AccountId,
+        announced_at: BlockNumberFor<T>,
+    ) -> [u8; 32] {
+        let domain_separator = Self::build_domain_separator();
+        let args_hash = Self::build_unlink_args_hash(who, announced_at);
+
+        let mut payload = b"\x19\x01".to_vec();
+        payload.extend_from_slice(&domain_separator);
+        payload.extend_from_slice(&args_hash);
+        keccak_256(&payload)
+    }
+
+    pub fn verify_unlink_signature(
+        who: &T::AccountId,
+        announced_at: BlockNumberFor<T>,
+        sig: &EvmSignature,
+    ) -> Option<EvmAddress> {
+        let payload_hash = Self::build_unlink_signing_payload(who, announced_at);
+
+        sp_io::crypto::secp256k1_ecdsa_recover(sig, &payload_hash)
+            .map(|pubkey| H160::from(H256::from_slice(&keccak_256(&pubkey))))
+            .ok()
+    }
+
+    fn build_unlink_args_hash(account: &T::AccountId, announced_at: BlockNumberFor<T>) -> [u8; 32] {
+        let mut args_hash =
+            keccak256!("Unlink(bytes substrateAddress,uint256 announcement)").to_vec();
+        args_hash.extend_from_slice(&keccak_256(&account.encode()));
+        args_hash.extend_from_slice(&<[u8; 32]>::from(U256::from(
+            announced_at.saturated_into::<u128>(),
+        )));
+        keccak_256(args_hash.as_slice())
+    }
 }
 
 #[cfg(any(feature = "std", feature = "runtime-benchmarks"))]
@@ -383,7 +920,10 @@ impl<T: Config> OnKilledAccount<T::AccountId> for KillAccountMapping<T> {
         if let Some(evm_addr) = NativeToEvm::<T>::take(who) {
             EvmToNative::<T>::remove(evm_addr);
             NativeToEvm::<T>::remove(who);
+            // release the storage deposit, if any is left
+            let _ = T::Currency::release_all(&HoldReason::AccountMapping.into(), who, BestEffort);
         }
+        PendingUnlinks::<T>::remove(who);
     }
 }
 
@@ -408,3 +948,48 @@ impl<T: Config> StaticLookup for Pallet<T> {
         MultiAddress::Id(a)
     }
 }
+
+/// A combined lookup resolving every `MultiAddress` variant to the native `AccountId`.
+///
+/// - `Address20` resolves to the mapped account of the evm address, or its default account.
+/// - `Address32` is decoded as the native account as is.
+/// - `Id` and `Index` are delegated to `IndexLookup` (e.g. `pallet_indices` or `AccountIdLookup`).
+///
+/// The default `AccountIdLookup` only resolves `Id`, so `Index` requires an index lookup like
+/// `pallet_indices`. `Raw` is always rejected unless `IndexLookup` supports it.
+pub struct UnifiedAccountsLookup<
+    T,
+    AccountIndex = (),
+    IndexLookup = AccountIdLookup<<T as frame_system::Config>::AccountId, AccountIndex>,
+>(PhantomData<(T, AccountIndex, IndexLookup)>);
+
+impl<T, AccountIndex, IndexLookup> StaticLookup
+    for UnifiedAccountsLookup<T, AccountIndex, IndexLookup>
+where
+    T: Config,
+    AccountIndex: Parameter + 'static,
+    IndexLookup:
+        StaticLookup<Source = MultiAddress<T::AccountId, AccountIndex>, Target = T::AccountId>,
+{
+    type Source = MultiAddress<T::AccountId, AccountIndex>;
+    type Target = T::AccountId;
+
+    fn lookup(a: Self::Source) -> Result<Self::Target, LookupError> {
+        match a {
+            MultiAddress::Address20(i) => Ok(
+                <Pallet<T> as UnifiedAddressMapper<T::AccountId>>::to_account_id_or_default(
+                    &EvmAddress::from_slice(&i),
+                )
+                .into_address(),
+            ),
+            MultiAddress::Address32(i) => {
+                T::AccountId::decode(&mut &i[..]).map_err(|_| LookupError)
+            }
+            a => IndexLookup::lookup(a),
+        }
+    }
+
+    fn unlookup(a: Self::Target) -> Self::Source {
+        IndexLookup::unlookup(a)
+    }
+}
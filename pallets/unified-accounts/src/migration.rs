@@ -1,11 +1,17 @@
-use super::{Config, Pallet, Weight};
+use super::{Config, HoldDepositsCursor, HoldReason, Pallet, Weight, STORAGE_VERSION};
 use astar_primitives::evm::EvmAddress;
 use frame_support::{
-    pallet_prelude::OptionQuery,
+    pallet_prelude::{BoundedVec, ConstU32, OptionQuery},
+    storage::StoragePrefixedMap,
     storage_alias,
-    traits::{Get, OnRuntimeUpgrade},
+    traits::{
+        fungible::{InspectHold, Mutate, MutateHold},
+        Get, GetStorageVersion, OnRuntimeUpgrade,
+    },
     Blake2_128Concat,
 };
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
 
 #[storage_alias]
 type EvmToNative<T: Config> = StorageMap<
@@ -48,4 +54,93 @@ impl<T: Config> OnRuntimeUpgrade for ClearCorruptedUnifiedMappings<T> {
         log::info!("Removed {count} corrupt entries");
         T::DbWeight::get().reads_writes(healthy_count + count, count)
     }
-}
\ No newline at end of file
+}
+
+/// Maximum length of the [`HoldDepositsCursor`](crate::HoldDepositsCursor), enough for
+/// the raw `NativeToEvm` key of 32 bytes account ids.
+pub type MaxCursorLen = ConstU32<128>;
+
+/// Reissue the storage fees of existing mappings, which used to be burned, as holds
+/// with [`HoldReason::AccountMapping`] so they can be released once mappings are removed.
+///
+/// The runtime upgrade only starts the migration by setting the
+/// [`HoldDepositsCursor`](crate::HoldDepositsCursor), the deposits are then reissued by
+/// permissionless `reissue_deposits` calls, so no hook keeps polling the cursor afterwards.
+///
+/// The reissued fees are minted outside of `pallet-inflation` and its `issuance_safety_cap`.
+/// They only restore the issuance burned by the claims made before deposits were held, i.e. at
+/// most `AccountMappingStorageFee` per existing mapping.
+pub struct HoldStorageDeposits<T>(core::marker::PhantomData<T>);
+impl<T: Config> HoldStorageDeposits<T> {
+    /// Reissue the deposits of as many mappings as `weight_limit` allows, starting after
+    /// the cursor. Removes the cursor once all mappings are processed.
+    pub(crate) fn reissue_deposits(weight_limit: Weight) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let Some(cursor) = HoldDepositsCursor::<T>::get() else {
+            return db_weight.reads(1);
+        };
+        // cursor read & write
+        let mut consumed = db_weight.reads_writes(1, 1);
+        // NativeToEvm, Account & Holds per mapping
+        let per_mapping = db_weight.reads_writes(3, 2);
+        if weight_limit.any_lt(consumed.saturating_add(per_mapping)) {
+            return db_weight.reads(1);
+        }
+
+        let fee = T::AccountMappingStorageFee::get();
+        let reason: T::RuntimeHoldReason = HoldReason::AccountMapping.into();
+        let mut iter = crate::NativeToEvm::<T>::iter_keys_from(cursor.into_inner());
+        loop {
+            if weight_limit.any_lt(consumed.saturating_add(per_mapping)) {
+                match BoundedVec::<u8, MaxCursorLen>::try_from(iter.last_raw_key().to_vec()) {
+                    Ok(cursor) => HoldDepositsCursor::<T>::put(cursor),
+                    Err(_) => {
+                        log::error!("Storage deposits cursor is too long, stopping migration");
+                        HoldDepositsCursor::<T>::kill();
+                    }
+                }
+                return consumed;
+            }
+
+            let Some(account_id) = iter.next() else {
+                HoldDepositsCursor::<T>::kill();
+                log::info!("Reissued storage deposits of all mappings");
+                return consumed;
+            };
+            consumed.saturating_accrue(per_mapping);
+
+            // mappings claimed since the upgrade already have their deposit on hold
+            if T::Currency::balance_on_hold(&reason, &account_id) >= fee {
+                continue;
+            }
+            // the fee was burned on claim, mint it back before putting it on hold
+            let result = T::Currency::mint_into(&account_id, fee)
+                .and_then(|_| T::Currency::hold(&reason, &account_id, fee));
+            if let Err(e) = result {
+                log::error!("Failed to hold storage deposit of {account_id:?}: {e:?}");
+            }
+        }
+    }
+}
+
+impl<T: Config> OnRuntimeUpgrade for HoldStorageDeposits<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if Pallet::<T>::on_chain_storage_version() >= STORAGE_VERSION {
+            return T::DbWeight::get().reads(1);
+        }
+
+        // start right after the map prefix, i.e. from the first mapping
+        let prefix = crate::NativeToEvm::<T>::final_prefix().to_vec();
+        HoldDepositsCursor::<T>::put(BoundedVec::<_, MaxCursorLen>::truncate_from(prefix));
+        STORAGE_VERSION.put::<Pallet<T>>();
+        log::info!("Started reissuing storage deposits of existing mappings");
+
+        T::DbWeight::get().reads_writes(1, 2)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), STORAGE_VERSION);
+        Pallet::<T>::do_try_state()
+    }
+}
@@ -24,16 +24,15 @@ use astar_primitives::evm::HashedDefaultMappings;
 use frame_support::{
     construct_runtime, parameter_types,
     traits::{ConstU64, FindAuthor},
-    weights::Weight,
+    weights::{constants::RocksDbWeight, Weight},
 };
+use frame_system::EnsureRoot;
 use pallet_ethereum::PostLogContent;
 use pallet_evm::FeeCalculator;
 use sp_core::{keccak_256, H160, H256, U256};
 use sp_io::TestExternalities;
-use sp_runtime::{
-    traits::{AccountIdLookup, BlakeTwo256},
-    AccountId32, BuildStorage, ConsensusEngineId,
-};
+use sp_runtime::{traits::BlakeTwo256, AccountId32, BuildStorage, ConsensusEngineId};
+use sp_std::cell::RefCell;
 
 parameter_types! {
     pub BlockWeights: frame_system::limits::BlockWeights =
@@ -52,10 +51,10 @@ impl frame_system::Config for TestRuntime {
     type Hash = H256;
     type Hashing = BlakeTwo256;
     type AccountId = AccountId;
-    type Lookup = (AccountIdLookup<Self::AccountId, ()>, UnifiedAccounts);
+    type Lookup = UnifiedAccountsLookup<Self>;
     type RuntimeEvent = RuntimeEvent;
     type BlockHashCount = ConstU64<250>;
-    type DbWeight = ();
+    type DbWeight = RocksDbWeight;
     type Version = ();
     type PalletInfo = PalletInfo;
     type AccountData = pallet_balances::AccountData<Balance>;
@@ -158,12 +157,48 @@ parameter_types! {
     pub const AccountMappingStorageFee: u128 = 100_000_000;
 }
 
+/// Asset id which fails to be migrated
+pub const UNMIGRATABLE_ASSET: u32 = 666;
+
+thread_local! {
+    static MIGRATED: RefCell<Option<(AccountId, AccountId, Vec<u32>)>> = RefCell::new(None);
+}
+
+pub struct MockClaimAssetsMigrator;
+impl MockClaimAssetsMigrator {
+    pub(crate) fn migrated() -> Option<(AccountId, AccountId, Vec<u32>)> {
+        MIGRATED.with(|v| v.borrow().clone())
+    }
+}
+impl ClaimAssetsMigrator<AccountId, u32> for MockClaimAssetsMigrator {
+    fn migrate(from: &AccountId, to: &AccountId, assets: &[u32]) -> DispatchResult {
+        ensure!(
+            !assets.contains(&UNMIGRATABLE_ASSET),
+            DispatchError::Other("unmigratable asset")
+        );
+        MIGRATED.with(|v| *v.borrow_mut() = Some((from.clone(), to.clone(), assets.to_vec())));
+        Ok(())
+    }
+
+    fn migrate_weight(assets: u32) -> Weight {
+        Weight::from_parts(1_000, 0).saturating_mul(assets.into())
+    }
+}
+
 impl pallet_unified_accounts::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type UnlinkDelay = ConstU64<10>;
+    type UnlinkRecoveryOrigin = EnsureRoot<AccountId>;
+    type AssetId = u32;
+    type ClaimAssetsMigrator = MockClaimAssetsMigrator;
+    type MaxClaimAssets = ConstU32<4>;
+    type ContractSignatureValidator = EvmContractSignatureValidator<Self, ConstU64<100_000>>;
+    type MaxContractSignatureLength = ConstU32<256>;
     type WeightInfo = ();
 }
 
@@ -24,10 +24,13 @@ use mock::*;
 
 use ethers::{
     contract::{Eip712, EthAbiType},
-    core::types::{transaction::eip712::Eip712, Bytes},
+    core::types::{
+        transaction::eip712::{Eip712, TypedData},
+        Bytes, U256 as EthU256,
+    },
 };
 use parity_scale_codec::Encode;
-use sp_runtime::{traits::StaticLookup, AccountId32, MultiAddress};
+use sp_runtime::{traits::StaticLookup, AccountId32, DispatchError, MultiAddress};
 
 /// EIP712 Payload struct
 #[derive(Eip712, EthAbiType, Clone)]
@@ -42,6 +45,20 @@ struct Claim {
     substrate_address: Bytes,
 }
 
+/// EIP712 Payload struct for unlink
+#[derive(Eip712, EthAbiType, Clone)]
+#[eip712(
+        name = "Astar EVM Claim",
+        version = "1",
+        chain_id = 1024,
+        // mock genisis hash
+        raw_salt = "0x4545454545454545454545454545454545454545454545454545454545454545"
+    )]
+struct Unlink {
+    substrate_address: Bytes,
+    announcement: EthU256,
+}
+
 /// Build the signature payload for given native account and eth private key
 fn get_evm_signature(who: &AccountId32, secret: &libsecp256k1::SecretKey) -> [u8; 65] {
     // sign the payload
@@ -64,6 +81,23 @@ fn connect_accounts(who: &AccountId32, secret: &libsecp256k1::SecretKey) {
     ));
 }
 
+/// Build the unlink signature payload for given native account, announcement and eth private key
+fn get_unlink_signature(
+    who: &AccountId32,
+    announced_at: u64,
+    secret: &libsecp256k1::SecretKey,
+) -> [u8; 65] {
+    UnifiedAccounts::eth_sign_prehash(
+        &Unlink {
+            substrate_address: who.encode().into(),
+            announcement: announced_at.into(),
+        }
+        .encode_eip712()
+        .unwrap(),
+        secret,
+    )
+}
+
 #[test]
 fn eip712_signature_verify_works() {
     ExtBuilder::default().build().execute_with(|| {
@@ -116,6 +150,88 @@ fn static_lookup_works() {
     });
 }
 
+#[test]
+fn unified_accounts_lookup_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        type Lookup = UnifiedAccountsLookup<TestRuntime>;
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let bob_eth = UnifiedAccounts::eth_address(&bob_secret());
+        let bob_default_account_id =
+            <UnifiedAccounts as UnifiedAddressMapper<_>>::to_default_account_id(&bob_eth);
+
+        // create mappings for alice
+        connect_accounts(&ALICE, &alice_secret());
+
+        // native account id should resolve as is
+        assert_eq!(Lookup::lookup(MultiAddress::Id(BOB)), Ok(BOB));
+        assert_eq!(Lookup::lookup(MultiAddress::Address32(BOB.into())), Ok(BOB));
+
+        // evm address should resolve to the mapped account or default
+        assert_eq!(
+            Lookup::lookup(MultiAddress::Address20(alice_eth.into())),
+            Ok(ALICE)
+        );
+        assert_eq!(
+            Lookup::lookup(MultiAddress::Address20(bob_eth.into())),
+            Ok(bob_default_account_id)
+        );
+
+        // account indices are not resolved by the default index lookup
+        assert_eq!(Lookup::lookup(MultiAddress::Index(())), Err(LookupError));
+        assert_eq!(
+            Lookup::lookup(MultiAddress::Raw(vec![1, 2, 3])),
+            Err(LookupError)
+        );
+
+        // unlookup should always be the native account id
+        assert_eq!(Lookup::unlookup(ALICE), MultiAddress::Id(ALICE));
+    });
+}
+
+#[test]
+fn unified_accounts_lookup_delegates_indices() {
+    /// Index lookup resolving the index `1` to `BOB`.
+    struct BobIndexLookup;
+
+    impl StaticLookup for BobIndexLookup {
+        type Source = MultiAddress<AccountId, u32>;
+        type Target = AccountId;
+
+        fn lookup(a: Self::Source) -> Result<Self::Target, LookupError> {
+            match a {
+                MultiAddress::Id(i) => Ok(i),
+                MultiAddress::Index(1) => Ok(BOB),
+                _ => Err(LookupError),
+            }
+        }
+
+        fn unlookup(a: Self::Target) -> Self::Source {
+            MultiAddress::Index(if a == BOB { 1 } else { 0 })
+        }
+    }
+
+    ExtBuilder::default().build().execute_with(|| {
+        type Lookup = UnifiedAccountsLookup<TestRuntime, u32, BobIndexLookup>;
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+
+        // create mappings for alice
+        connect_accounts(&ALICE, &alice_secret());
+
+        // indices and native account ids are resolved by the index lookup
+        assert_eq!(Lookup::lookup(MultiAddress::Index(1)), Ok(BOB));
+        assert_eq!(Lookup::lookup(MultiAddress::Index(2)), Err(LookupError));
+        assert_eq!(Lookup::lookup(MultiAddress::Id(ALICE)), Ok(ALICE));
+        assert_eq!(Lookup::unlookup(BOB), MultiAddress::Index(1));
+
+        // evm and 32 bytes addresses are still resolved by the pallet
+        assert_eq!(
+            Lookup::lookup(MultiAddress::Address20(alice_eth.into())),
+            Ok(ALICE)
+        );
+        assert_eq!(Lookup::lookup(MultiAddress::Address32(BOB.into())), Ok(BOB));
+    });
+}
+
 #[test]
 fn on_killed_account_hook() {
     ExtBuilder::default().build().execute_with(|| {
@@ -124,8 +240,17 @@ fn on_killed_account_hook() {
         // create the mappings
         connect_accounts(&ALICE, &alice_secret());
 
-        // kill alice by transfering everything to bob
+        // storage deposit on hold keeps alice alive
         Balances::set_balance(&ALICE, 0);
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(alice_eth));
+
+        // kill alice by slashing the deposit as well
+        assert_ok!(<Balances as FungibleMutateHold<_>>::burn_all_held(
+            &HoldReason::AccountMapping.into(),
+            &ALICE,
+            BestEffort,
+            Force
+        ));
 
         // check killed account events
         assert!(System::events().iter().any(|r| matches!(
@@ -139,6 +264,44 @@ fn on_killed_account_hook() {
     });
 }
 
+#[test]
+fn unlinked_account_can_be_reaped() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        connect_accounts(&ALICE, &alice_secret());
+
+        // storage deposit on hold keeps alice alive and mapped
+        assert_ok!(Balances::transfer_all(
+            RuntimeOrigin::signed(ALICE),
+            BOB.into(),
+            false
+        ));
+        assert!(System::account_exists(&ALICE));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(&ALICE), Some(alice_eth));
+
+        // unlinking removes the mappings & releases the deposit
+        assert_ok!(UnifiedAccounts::announce_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        System::set_block_number(11);
+        assert_ok!(UnifiedAccounts::unlink(
+            RuntimeOrigin::signed(ALICE),
+            Some(get_unlink_signature(&ALICE, 1, &alice_secret()))
+        ));
+        assert!(Balances::free_balance(&ALICE) >= AccountMappingStorageFee::get());
+
+        // alice can be reaped once the deposit is released
+        assert_ok!(Balances::transfer_all(
+            RuntimeOrigin::signed(ALICE),
+            BOB.into(),
+            false
+        ));
+        assert!(!System::account_exists(&ALICE));
+        assert_eq!(EvmToNative::<TestRuntime>::get(&alice_eth), None);
+        assert_ok!(UnifiedAccounts::do_try_state());
+    });
+}
+
 #[test]
 fn account_claim_should_work() {
     ExtBuilder::default().build().execute_with(|| {
@@ -170,12 +333,11 @@ fn account_claim_should_work() {
                 if account == &alice_eth_old_account
         )));
 
-        // check if storage fee is charged and burned
-        assert!(System::events().iter().any(|r| matches!(
-            &r.event,
-            RuntimeEvent::Balances(pallet_balances::Event::Burned { who, amount })
-                if who == &ALICE && amount == &AccountMappingStorageFee::get()
-        )));
+        // check if storage fee is charged and held
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &ALICE),
+            AccountMappingStorageFee::get()
+        );
 
         // check for claim account event
         System::assert_last_event(RuntimeEvent::UnifiedAccounts(
@@ -207,12 +369,11 @@ fn account_default_claim_works() {
             },
         ));
 
-        // check if storage fee is charged and burned
-        assert!(System::events().iter().any(|r| matches!(
-            &r.event,
-            RuntimeEvent::Balances(pallet_balances::Event::Burned { who, amount })
-                if who == &ALICE && amount == &AccountMappingStorageFee::get()
-        )));
+        // check if storage fee is charged and held
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &ALICE),
+            AccountMappingStorageFee::get()
+        );
 
         // check UnifiedAddressMapper's mapping works
         assert_eq!(
@@ -377,8 +538,12 @@ fn charging_storage_fee_should_not_reap_account() {
             },
         ));
 
-        // confirm the balance, should be equal to ED
-        assert_eq!(Balances::total_balance(&ALICE), ExistentialDeposit::get());
+        // confirm the balance, free balance should be equal to ED and fee on hold
+        assert_eq!(Balances::free_balance(&ALICE), ExistentialDeposit::get());
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &ALICE),
+            AccountMappingStorageFee::get()
+        );
 
         // clear mappings
         EvmToNative::<TestRuntime>::remove(alice_eth);
@@ -397,3 +562,680 @@ fn charging_storage_fee_should_not_reap_account() {
         );
     });
 }
+
+#[test]
+fn unlink_signature_verify_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let unlink = Unlink {
+            substrate_address: ALICE.encode().into(),
+            announcement: 7.into(),
+        };
+
+        let unlink_hash = UnifiedAccounts::build_unlink_signing_payload(&ALICE, 7);
+        // assert signing payload is correct
+        assert_eq!(
+            unlink.encode_eip712().unwrap(),
+            unlink_hash,
+            "signing payload should match"
+        );
+
+        let sig = UnifiedAccounts::eth_sign_prehash(&unlink_hash, &alice_secret());
+        assert_eq!(
+            Some(UnifiedAccounts::eth_address(&alice_secret())),
+            UnifiedAccounts::verify_unlink_signature(&ALICE, 7, &sig),
+            "signature verification should work"
+        );
+        // signature is bound to the announcement
+        assert_ne!(
+            Some(UnifiedAccounts::eth_address(&alice_secret())),
+            UnifiedAccounts::verify_unlink_signature(&ALICE, 8, &sig),
+        );
+    });
+}
+
+#[test]
+fn unlink_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        connect_accounts(&ALICE, &alice_secret());
+        let alice_balance = Balances::free_balance(&ALICE);
+
+        // announce the unlink
+        assert_ok!(UnifiedAccounts::announce_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        System::assert_last_event(
+            Event::UnlinkAnnounced {
+                account_id: ALICE,
+                evm_address: alice_eth,
+            }
+            .into(),
+        );
+        assert_eq!(PendingUnlinks::<TestRuntime>::get(&ALICE), Some(1));
+
+        // cannot unlink before the delay
+        let signature = get_unlink_signature(&ALICE, 1, &alice_secret());
+        System::set_block_number(10);
+        assert_noop!(
+            UnifiedAccounts::unlink(RuntimeOrigin::signed(ALICE), Some(signature)),
+            Error::<TestRuntime>::UnlinkDelayNotElapsed
+        );
+
+        // unlink after the delay
+        System::set_block_number(11);
+        assert_ok!(UnifiedAccounts::unlink(
+            RuntimeOrigin::signed(ALICE),
+            Some(signature)
+        ));
+        System::assert_last_event(
+            Event::AccountUnlinked {
+                account_id: ALICE,
+                evm_address: alice_eth,
+            }
+            .into(),
+        );
+
+        // mappings are removed and storage deposit is released
+        assert_eq!(NativeToEvm::<TestRuntime>::get(&ALICE), None);
+        assert_eq!(EvmToNative::<TestRuntime>::get(&alice_eth), None);
+        assert_eq!(PendingUnlinks::<TestRuntime>::get(&ALICE), None);
+        assert_eq!(
+            Balances::free_balance(&ALICE),
+            alice_balance + AccountMappingStorageFee::get()
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &ALICE),
+            0
+        );
+        assert_ok!(UnifiedAccounts::do_try_state());
+    });
+}
+
+#[test]
+fn unlink_requires_valid_evm_signature() {
+    ExtBuilder::default().build().execute_with(|| {
+        connect_accounts(&ALICE, &alice_secret());
+        assert_ok!(UnifiedAccounts::announce_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        System::set_block_number(11);
+
+        // missing signature
+        assert_noop!(
+            UnifiedAccounts::unlink(RuntimeOrigin::signed(ALICE), None),
+            Error::<TestRuntime>::MissingSignature
+        );
+        // signed by other evm address
+        assert_noop!(
+            UnifiedAccounts::unlink(
+                RuntimeOrigin::signed(ALICE),
+                Some(get_unlink_signature(&ALICE, 1, &bob_secret()))
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+        // signed for other announcement
+        assert_noop!(
+            UnifiedAccounts::unlink(
+                RuntimeOrigin::signed(ALICE),
+                Some(get_unlink_signature(&ALICE, 0, &alice_secret()))
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+        // claim signature cannot be reused
+        assert_noop!(
+            UnifiedAccounts::unlink(
+                RuntimeOrigin::signed(ALICE),
+                Some(get_evm_signature(&ALICE, &alice_secret()))
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn unlink_requires_announcement() {
+    ExtBuilder::default().build().execute_with(|| {
+        // not mapped
+        assert_noop!(
+            UnifiedAccounts::announce_unlink(RuntimeOrigin::signed(ALICE)),
+            Error::<TestRuntime>::NotMapped
+        );
+        assert_noop!(
+            UnifiedAccounts::unlink(RuntimeOrigin::signed(ALICE), None),
+            Error::<TestRuntime>::NotMapped
+        );
+
+        connect_accounts(&ALICE, &alice_secret());
+        assert_noop!(
+            UnifiedAccounts::unlink(
+                RuntimeOrigin::signed(ALICE),
+                Some(get_unlink_signature(&ALICE, 1, &alice_secret()))
+            ),
+            Error::<TestRuntime>::UnlinkNotAnnounced
+        );
+        assert_noop!(
+            UnifiedAccounts::cancel_unlink(RuntimeOrigin::signed(ALICE)),
+            Error::<TestRuntime>::UnlinkNotAnnounced
+        );
+
+        // announce twice
+        assert_ok!(UnifiedAccounts::announce_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        assert_noop!(
+            UnifiedAccounts::announce_unlink(RuntimeOrigin::signed(ALICE)),
+            Error::<TestRuntime>::UnlinkAlreadyAnnounced
+        );
+
+        // cancel the announcement
+        assert_ok!(UnifiedAccounts::cancel_unlink(RuntimeOrigin::signed(ALICE)));
+        System::assert_last_event(Event::UnlinkCancelled { account_id: ALICE }.into());
+        System::set_block_number(11);
+        assert_noop!(
+            UnifiedAccounts::unlink(
+                RuntimeOrigin::signed(ALICE),
+                Some(get_unlink_signature(&ALICE, 1, &alice_secret()))
+            ),
+            Error::<TestRuntime>::UnlinkNotAnnounced
+        );
+    });
+}
+
+#[test]
+fn unlink_default_mapping_works_without_signature() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(UnifiedAccounts::claim_default_evm_address(
+            RuntimeOrigin::signed(ALICE)
+        ));
+        assert_ok!(UnifiedAccounts::announce_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        System::set_block_number(11);
+
+        assert_ok!(UnifiedAccounts::unlink(RuntimeOrigin::signed(ALICE), None));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(&ALICE), None);
+    });
+}
+
+#[test]
+fn force_unlink_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        connect_accounts(&ALICE, &alice_secret());
+
+        // only recovery origin
+        assert_noop!(
+            UnifiedAccounts::force_unlink(RuntimeOrigin::signed(ALICE), ALICE),
+            DispatchError::BadOrigin
+        );
+        // account must announce the unlink
+        assert_noop!(
+            UnifiedAccounts::force_unlink(RuntimeOrigin::root(), ALICE),
+            Error::<TestRuntime>::UnlinkNotAnnounced
+        );
+        assert_ok!(UnifiedAccounts::announce_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        // delay applies to recovery as well
+        assert_noop!(
+            UnifiedAccounts::force_unlink(RuntimeOrigin::root(), ALICE),
+            Error::<TestRuntime>::UnlinkDelayNotElapsed
+        );
+
+        System::set_block_number(11);
+        assert_ok!(UnifiedAccounts::force_unlink(RuntimeOrigin::root(), ALICE));
+        System::assert_last_event(
+            Event::AccountUnlinked {
+                account_id: ALICE,
+                evm_address: alice_eth,
+            }
+            .into(),
+        );
+        assert_eq!(NativeToEvm::<TestRuntime>::get(&ALICE), None);
+        assert_eq!(EvmToNative::<TestRuntime>::get(&alice_eth), None);
+    });
+}
+
+#[test]
+fn rebind_after_unlink_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let bob_eth = UnifiedAccounts::eth_address(&bob_secret());
+        connect_accounts(&ALICE, &alice_secret());
+
+        assert_ok!(UnifiedAccounts::announce_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        System::set_block_number(11);
+        assert_ok!(UnifiedAccounts::unlink(
+            RuntimeOrigin::signed(ALICE),
+            Some(get_unlink_signature(&ALICE, 1, &alice_secret()))
+        ));
+
+        // alice can bind the new address, and bob the freed one
+        connect_accounts(&ALICE, &bob_secret());
+        connect_accounts(&BOB, &alice_secret());
+        assert_eq!(NativeToEvm::<TestRuntime>::get(&ALICE), Some(bob_eth));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(&BOB), Some(alice_eth));
+    });
+}
+
+#[test]
+fn claim_holds_storage_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        let issuance = Balances::total_issuance();
+        let alice_balance = Balances::free_balance(&ALICE);
+        connect_accounts(&ALICE, &alice_secret());
+        assert_ok!(UnifiedAccounts::claim_default_evm_address(
+            RuntimeOrigin::signed(BOB)
+        ));
+
+        // deposit is held, not burned
+        assert_eq!(
+            Balances::free_balance(&ALICE),
+            alice_balance - AccountMappingStorageFee::get()
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &ALICE),
+            AccountMappingStorageFee::get()
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &BOB),
+            AccountMappingStorageFee::get()
+        );
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_ok!(UnifiedAccounts::do_try_state());
+    });
+}
+
+#[test]
+fn hold_storage_deposits_migration_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+        // mappings created before deposits were held
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let bob_eth = UnifiedAccounts::eth_address(&bob_secret());
+        EvmToNative::<TestRuntime>::insert(&alice_eth, &ALICE);
+        NativeToEvm::<TestRuntime>::insert(&ALICE, &alice_eth);
+        EvmToNative::<TestRuntime>::insert(&bob_eth, &BOB);
+        NativeToEvm::<TestRuntime>::insert(&BOB, &bob_eth);
+        StorageVersion::new(0).put::<UnifiedAccounts>();
+        assert!(UnifiedAccounts::do_try_state().is_err());
+
+        let alice_balance = Balances::free_balance(&ALICE);
+        migration::HoldStorageDeposits::<TestRuntime>::on_runtime_upgrade();
+        assert!(HoldDepositsCursor::<TestRuntime>::exists());
+        assert_eq!(
+            UnifiedAccounts::on_chain_storage_version(),
+            pallet::STORAGE_VERSION
+        );
+        // deposits aren't checked while being reissued
+        assert_ok!(UnifiedAccounts::do_try_state());
+
+        // deposits are reissued one mapping per call
+        let db_weight = <TestRuntime as frame_system::Config>::DbWeight::get();
+        let call_weight = db_weight.reads_writes(4, 3);
+        let reissue = |weight_limit| {
+            UnifiedAccounts::reissue_deposits(RuntimeOrigin::signed(CHARLIE), weight_limit)
+                .map(|info| (info.actual_weight, info.pays_fee))
+        };
+        let held = || {
+            [&ALICE, &BOB]
+                .into_iter()
+                .filter(|who| {
+                    Balances::balance_on_hold(&HoldReason::AccountMapping.into(), who) > 0
+                })
+                .count()
+        };
+        // calls without progress are paid
+        assert_eq!(
+            reissue(Weight::zero()),
+            Ok((Some(db_weight.reads(2)), Pays::Yes))
+        );
+        assert_eq!(held(), 0);
+        assert_eq!(
+            reissue(call_weight),
+            Ok((
+                Some(call_weight.saturating_add(db_weight.reads(1))),
+                Pays::No
+            ))
+        );
+        assert_eq!(held(), 1);
+        assert_ok!(reissue(call_weight));
+        assert_eq!(held(), 2);
+        assert!(HoldDepositsCursor::<TestRuntime>::exists());
+        // cursor is removed once all mappings are processed
+        assert_ok!(reissue(call_weight));
+        assert!(!HoldDepositsCursor::<TestRuntime>::exists());
+
+        // burned fee is reissued as deposit
+        assert_eq!(Balances::free_balance(&ALICE), alice_balance);
+        for who in [&ALICE, &BOB] {
+            assert_eq!(
+                Balances::balance_on_hold(&HoldReason::AccountMapping.into(), who),
+                AccountMappingStorageFee::get()
+            );
+        }
+        assert_ok!(UnifiedAccounts::do_try_state());
+
+        // migration runs only once
+        migration::HoldStorageDeposits::<TestRuntime>::on_runtime_upgrade();
+        assert!(!HoldDepositsCursor::<TestRuntime>::exists());
+        assert_noop!(
+            UnifiedAccounts::reissue_deposits(RuntimeOrigin::signed(CHARLIE), Weight::MAX),
+            Error::<TestRuntime>::NoDepositsToReissue
+        );
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &ALICE),
+            AccountMappingStorageFee::get()
+        );
+    });
+}
+
+#[test]
+fn hold_storage_deposits_migration_skips_held_deposits() {
+    ExtBuilder::default().build().execute_with(|| {
+        use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+        StorageVersion::new(0).put::<UnifiedAccounts>();
+        migration::HoldStorageDeposits::<TestRuntime>::on_runtime_upgrade();
+
+        // mapping claimed while the migration is ongoing
+        assert_ok!(UnifiedAccounts::claim_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            UnifiedAccounts::eth_address(&alice_secret()),
+            get_evm_signature(&ALICE, &alice_secret())
+        ));
+
+        assert_ok!(UnifiedAccounts::reissue_deposits(
+            RuntimeOrigin::signed(CHARLIE),
+            Weight::MAX
+        ));
+        assert!(!HoldDepositsCursor::<TestRuntime>::exists());
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &ALICE),
+            AccountMappingStorageFee::get()
+        );
+        assert_ok!(UnifiedAccounts::do_try_state());
+    });
+}
+
+#[test]
+fn claim_with_assets_migrates_default_account() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+
+        // transfer some funds to alice_eth (H160)
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            alice_eth_old_account.clone().into(),
+            1001
+        ));
+        let alice_balance = Balances::free_balance(&ALICE);
+
+        assert_ok!(UnifiedAccounts::claim_evm_address_with_assets(
+            RuntimeOrigin::signed(ALICE),
+            alice_eth,
+            get_evm_signature(&ALICE, &alice_secret()),
+            vec![1, 2].try_into().unwrap()
+        ));
+
+        // assets are migrated along with the native balance
+        assert_eq!(
+            MockClaimAssetsMigrator::migrated(),
+            Some((alice_eth_old_account.clone(), ALICE, vec![1, 2]))
+        );
+        assert_eq!(
+            Balances::free_balance(&ALICE),
+            alice_balance + 1001 - AccountMappingStorageFee::get()
+        );
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(alice_eth));
+    });
+}
+
+#[test]
+fn claim_with_assets_skips_missing_default_account() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+
+        assert_ok!(UnifiedAccounts::claim_evm_address_with_assets(
+            RuntimeOrigin::signed(ALICE),
+            alice_eth,
+            get_evm_signature(&ALICE, &alice_secret()),
+            vec![1].try_into().unwrap()
+        ));
+
+        // nothing to migrate from
+        assert_eq!(MockClaimAssetsMigrator::migrated(), None);
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(alice_eth));
+    });
+}
+
+#[test]
+fn claim_with_assets_fails_if_migration_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            alice_eth_old_account.into(),
+            1001
+        ));
+
+        assert_noop!(
+            UnifiedAccounts::claim_evm_address_with_assets(
+                RuntimeOrigin::signed(ALICE),
+                alice_eth,
+                get_evm_signature(&ALICE, &alice_secret()),
+                vec![1, UNMIGRATABLE_ASSET].try_into().unwrap()
+            ),
+            DispatchError::Other("unmigratable asset")
+        );
+    });
+}
+
+#[test]
+fn claim_with_assets_weight_scales_with_assets() {
+    ExtBuilder::default().build().execute_with(|| {
+        use frame_support::dispatch::GetDispatchInfo;
+
+        let claim_weight = |assets: Vec<u32>| {
+            RuntimeCall::UnifiedAccounts(crate::Call::claim_evm_address_with_assets {
+                evm_address: Default::default(),
+                signature: [0u8; 65],
+                assets: assets.try_into().unwrap(),
+            })
+            .get_dispatch_info()
+            .weight
+        };
+
+        assert_eq!(
+            claim_weight(vec![]),
+            <TestRuntime as Config>::WeightInfo::claim_evm_address()
+        );
+        assert_eq!(
+            claim_weight(vec![1, 2, 3]),
+            claim_weight(vec![]) + MockClaimAssetsMigrator::migrate_weight(3)
+        );
+    });
+}
+
+/// Deploy a smart contract wallet at `address` which accepts any signature for `accepted_hash`
+/// in EIP-1271 `isValidSignature(bytes32,bytes)`, optionally writing to its storage first.
+fn deploy_contract_wallet(address: H160, accepted_hash: [u8; 32], write_storage: bool) {
+    let mut code = vec![];
+    if write_storage {
+        // SSTORE(0, 1)
+        code.extend_from_slice(&[0x60, 0x01, 0x60, 0x00, 0x55]);
+    }
+    // PUSH32 accepted_hash
+    code.push(0x7f);
+    code.extend_from_slice(&accepted_hash);
+    code.extend_from_slice(&[
+        0x60, 0x04, 0x35, // CALLDATALOAD(4), the hash argument
+        0x14, // EQ
+        0x63, 0x16, 0x26, 0xba, 0x7e, // PUSH4 magic value
+        0x02, // MUL, magic value if hashes are equal or zero
+        0x60, 0xe0, 0x1b, // SHL(224), left align bytes4
+        0x60, 0x00, 0x52, // MSTORE(0)
+        0x60, 0x20, 0x60, 0x00, 0xf3, // RETURN(0, 32)
+    ]);
+    pallet_evm::Pallet::<TestRuntime>::create_account(address, code);
+}
+
+#[test]
+fn contract_claim_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let wallet = H160::repeat_byte(0x42);
+        deploy_contract_wallet(
+            wallet,
+            UnifiedAccounts::build_signing_payload(&ALICE),
+            false,
+        );
+
+        assert_ok!(UnifiedAccounts::claim_contract_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            wallet,
+            vec![1, 2, 3].try_into().unwrap()
+        ));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountClaimed {
+                account_id: ALICE,
+                evm_address: wallet,
+            },
+        ));
+        assert_eq!(EvmToNative::<TestRuntime>::get(wallet), Some(ALICE));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(wallet));
+    });
+}
+
+#[test]
+fn contract_claim_requires_valid_contract_signature() {
+    ExtBuilder::default().build().execute_with(|| {
+        // wallet only accepts bob's claim
+        let wallet = H160::repeat_byte(0x42);
+        deploy_contract_wallet(wallet, UnifiedAccounts::build_signing_payload(&BOB), false);
+        assert_noop!(
+            UnifiedAccounts::claim_contract_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                wallet,
+                vec![1, 2, 3].try_into().unwrap()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+
+        // accounts without code cannot validate signatures
+        assert_noop!(
+            UnifiedAccounts::claim_contract_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                UnifiedAccounts::eth_address(&alice_secret()),
+                vec![1, 2, 3].try_into().unwrap()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+
+        // already mapped
+        connect_accounts(&BOB, &bob_secret());
+        assert_noop!(
+            UnifiedAccounts::claim_contract_evm_address(
+                RuntimeOrigin::signed(BOB),
+                wallet,
+                vec![1, 2, 3].try_into().unwrap()
+            ),
+            Error::<TestRuntime>::AlreadyMapped
+        );
+    });
+}
+
+#[test]
+fn contract_claim_rejects_echoed_magic_value() {
+    ExtBuilder::default().build().execute_with(|| {
+        // returns the whole calldata, starting with the `isValidSignature` selector
+        let echo_wallet = H160::repeat_byte(0x42);
+        pallet_evm::Pallet::<TestRuntime>::create_account(
+            echo_wallet,
+            vec![
+                0x36, 0x60, 0x00, 0x60, 0x00, 0x37, // CALLDATACOPY(0, 0, CALLDATASIZE)
+                0x36, 0x60, 0x00, 0xf3, // RETURN(0, CALLDATASIZE)
+            ],
+        );
+        // returns the first 32 bytes of calldata, i.e. the selector followed by the hash
+        let truncated_echo_wallet = H160::repeat_byte(0x43);
+        pallet_evm::Pallet::<TestRuntime>::create_account(
+            truncated_echo_wallet,
+            vec![
+                0x60, 0x20, 0x60, 0x00, 0x60, 0x00, 0x37, // CALLDATACOPY(0, 0, 32)
+                0x60, 0x20, 0x60, 0x00, 0xf3, // RETURN(0, 32)
+            ],
+        );
+
+        for wallet in [echo_wallet, truncated_echo_wallet] {
+            assert_noop!(
+                UnifiedAccounts::claim_contract_evm_address(
+                    RuntimeOrigin::signed(ALICE),
+                    wallet,
+                    vec![1, 2, 3].try_into().unwrap()
+                ),
+                Error::<TestRuntime>::InvalidSignature
+            );
+        }
+    });
+}
+
+#[test]
+fn contract_signature_validation_is_read_only() {
+    ExtBuilder::default().build().execute_with(|| {
+        let wallet = H160::repeat_byte(0x42);
+        deploy_contract_wallet(wallet, UnifiedAccounts::build_signing_payload(&ALICE), true);
+
+        assert_ok!(UnifiedAccounts::claim_contract_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            wallet,
+            vec![1, 2, 3].try_into().unwrap()
+        ));
+
+        // storage written by the wallet is discarded
+        assert_eq!(
+            pallet_evm::AccountStorages::<TestRuntime>::get(wallet, H256::zero()),
+            H256::zero()
+        );
+    });
+}
+
+#[test]
+fn claim_typed_data_matches_signing_payload() {
+    ExtBuilder::default().build().execute_with(|| {
+        let typed_data: TypedData =
+            serde_json::from_slice(&UnifiedAccounts::build_claim_typed_data(&ALICE))
+                .expect("typed data must be valid json");
+
+        // typed data should hash to the same payload used for signature verification
+        assert_eq!(
+            typed_data.encode_eip712().unwrap(),
+            UnifiedAccounts::build_signing_payload(&ALICE)
+        );
+        assert_eq!(
+            typed_data.encode_eip712().unwrap(),
+            Claim {
+                substrate_address: ALICE.encode().into(),
+            }
+            .encode_eip712()
+            .unwrap()
+        );
+
+        // signing the typed data should be accepted by the claim
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let signature = UnifiedAccounts::eth_sign_prehash(
+            &typed_data.encode_eip712().unwrap(),
+            &alice_secret(),
+        );
+        assert_eq!(
+            UnifiedAccounts::verify_signature(&ALICE, &signature),
+            Some(alice_eth)
+        );
+    });
+}
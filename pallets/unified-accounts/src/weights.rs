@@ -50,6 +50,10 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn claim_evm_address() -> Weight;
 	fn claim_default_evm_address() -> Weight;
+	fn announce_unlink() -> Weight;
+	fn cancel_unlink() -> Weight;
+	fn unlink() -> Weight;
+	fn force_unlink() -> Weight;
 	fn to_account_id() -> Weight;
 	fn to_account_id_or_default() -> Weight;
 	fn to_h160() -> Weight;
@@ -91,6 +95,68 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn announce_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `170`
+		//  Estimated: `3533`
+		// Minimum execution time: 14_842_000 picoseconds.
+		Weight::from_parts(15_301_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116`
+		//  Estimated: `3517`
+		// Minimum execution time: 11_962_000 picoseconds.
+		Weight::from_parts(12_408_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `336`
+		//  Estimated: `3593`
+		// Minimum execution time: 96_114_000 picoseconds.
+		Weight::from_parts(97_052_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn force_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `336`
+		//  Estimated: `3593`
+		// Minimum execution time: 42_387_000 picoseconds.
+		Weight::from_parts(43_120_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:0)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn to_account_id() -> Weight {
@@ -167,6 +233,68 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn announce_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `170`
+		//  Estimated: `3533`
+		// Minimum execution time: 14_842_000 picoseconds.
+		Weight::from_parts(15_301_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116`
+		//  Estimated: `3517`
+		// Minimum execution time: 11_962_000 picoseconds.
+		Weight::from_parts(12_408_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `336`
+		//  Estimated: `3593`
+		// Minimum execution time: 96_114_000 picoseconds.
+		Weight::from_parts(97_052_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn force_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `336`
+		//  Estimated: `3593`
+		// Minimum execution time: 42_387_000 picoseconds.
+		Weight::from_parts(43_120_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:0)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn to_account_id() -> Weight {
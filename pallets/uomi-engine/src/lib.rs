@@ -85,6 +85,8 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
+        /// Origin allowed to administer the engine, e.g. to clear a pending execution.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     /// In this template, we are declaring a storage item called `AiAgentsExecutions` that stores the couple nft_id (u32) and the input_uri (Vec<u32>).
@@ -113,6 +115,11 @@ pub mod pallet {
             /// The account who set the new value.
             who: T::AccountId,
         },
+        /// The pending execution has been cleared by the admin origin.
+        AiAgentExecutionCleared {
+            /// The nft_id of the cleared execution.
+            nft_id: u32,
+        },
     }
 
     /// Errors that can be returned by this pallet.
@@ -170,6 +177,21 @@ pub mod pallet {
             // Return a successful `DispatchResult`
             Ok(())
         }
+
+        /// Clear the pending execution so that the offchain worker stops processing it.
+        ///
+        /// Can only be called by `T::AdminOrigin`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::clear_execution())]
+        pub fn clear_execution(origin: OriginFor<T>) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let (nft_id, _) = AiAgentsExecutions::<T>::take();
+
+            Self::deposit_event(Event::AiAgentExecutionCleared { nft_id });
+
+            Ok(())
+        }
     }
 
     #[pallet::hooks]
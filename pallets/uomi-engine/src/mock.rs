@@ -48,6 +48,7 @@ impl frame_system::Config for Test {
 impl pallet_template::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -91,6 +91,46 @@ fn test_run_fails_if_input_uri_is_empty() {
     });
 }
 
+#[test]
+fn test_clear_execution_works_for_admin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let nft_id = 1;
+        let input_uri = b"https://storage.gregoriogalante.com/uomi_example_input.txt".to_vec();
+
+        assert_ok!(TemplateModule::run(
+            RuntimeOrigin::signed(1),
+            nft_id,
+            input_uri
+        ));
+        assert_ok!(TemplateModule::clear_execution(RuntimeOrigin::root()));
+
+        assert_eq!(AiAgentsExecutions::<Test>::get(), (0, Vec::new()));
+        System::assert_last_event(RuntimeEvent::TemplateModule(
+            Event::AiAgentExecutionCleared { nft_id },
+        ));
+    });
+}
+
+#[test]
+fn test_clear_execution_fails_for_non_admin() {
+    new_test_ext().execute_with(|| {
+        let nft_id = 1;
+        let input_uri = b"https://storage.gregoriogalante.com/uomi_example_input.txt".to_vec();
+
+        assert_ok!(TemplateModule::run(
+            RuntimeOrigin::signed(1),
+            nft_id,
+            input_uri.clone()
+        ));
+        assert_noop!(
+            TemplateModule::clear_execution(RuntimeOrigin::signed(1)),
+            BadOrigin
+        );
+        assert_eq!(AiAgentsExecutions::<Test>::get(), (nft_id, input_uri));
+    });
+}
+
 // OFFCHAIN WORKER TESTS
 
 #[test]
@@ -0,0 +1,67 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::{
+    ethereum_checked::AccessList,
+    xvm::{CallResult, VmId},
+    Balance,
+};
+use frame_support::weights::Weight;
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    /// API for dry-running XVM calls.
+    #[api_version(2)]
+    pub trait XvmApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        #[changed_in(2)]
+        fn dry_run(
+            source_vm_id: VmId,
+            target_vm_id: VmId,
+            source: AccountId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+            storage_deposit_limit: Option<Balance>,
+        ) -> CallResult;
+
+        /// Execute a cross-VM call without committing any state changes.
+        ///
+        /// Returns the call output and used weight on success, or the failure reason
+        /// and used weight on failure. If `weight_limit` is `None`, the max block
+        /// weight is used. `access_list` is only used by EVM calls.
+        fn dry_run(
+            source_vm_id: VmId,
+            target_vm_id: VmId,
+            source: AccountId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+            storage_deposit_limit: Option<Balance>,
+            access_list: Option<AccessList>,
+        ) -> CallResult;
+    }
+}
@@ -33,6 +33,10 @@
 //! ### Implementation
 //!
 //! - Implements `XvmCall` trait.
+//! - `dry_run`: executes an XVM call without committing state, for the `XvmApi` runtime API.
+//!
+//! With the `evm-tracing` feature, XVM calls are reported to the `astar_primitives::xvm::tracing`
+//! listener, so that they show up as call frames in EVM traces.
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -41,16 +45,22 @@ extern crate alloc;
 use alloc::format;
 
 use fp_evm::ExitReason;
-use frame_support::{ensure, traits::fungible::Inspect, weights::Weight};
+use frame_support::{
+    ensure,
+    storage::{with_transaction, TransactionOutcome},
+    traits::fungible::Inspect,
+    weights::Weight,
+};
 use pallet_contracts::{CollectEvents, DebugInfo, Determinism};
 use pallet_contracts_uapi::ReturnFlags;
 use pallet_evm::GasWeightMapping;
 use parity_scale_codec::Decode;
 use sp_core::{H160, U256};
+use sp_runtime::{traits::Zero, DispatchError};
 use sp_std::{marker::PhantomData, prelude::*};
 
 use astar_primitives::{
-    ethereum_checked::{CheckedEthereumTransact, CheckedEthereumTx, EthereumTxInput},
+    ethereum_checked::{AccessList, CheckedEthereumTransact, CheckedEthereumTx, EthereumTxInput},
     evm::UnifiedAddressMapper,
     xvm::{
         CallFailure, CallOutput, CallResult, Context, FailureError::*, FailureRevert::*, VmId,
@@ -59,6 +69,9 @@ use astar_primitives::{
     Balance,
 };
 
+#[cfg(feature = "evm-tracing")]
+use astar_primitives::xvm::tracing;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
@@ -110,6 +123,7 @@ where
         input: Vec<u8>,
         value: Balance,
         storage_deposit_limit: Option<Balance>,
+        access_list: Option<AccessList>,
     ) -> CallResult {
         Pallet::<T>::do_call(
             context,
@@ -119,9 +133,23 @@ where
             input,
             value,
             storage_deposit_limit,
+            access_list,
+            false,
             false,
         )
     }
+
+    fn static_call(
+        context: Context,
+        vm_id: VmId,
+        source: T::AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+    ) -> CallResult {
+        Pallet::<T>::do_call(
+            context, vm_id, source, target, input, 0, None, None, true, false,
+        )
+    }
 }
 
 impl<T> Pallet<T>
@@ -137,6 +165,63 @@ where
         input: Vec<u8>,
         value: Balance,
         storage_deposit_limit: Option<Balance>,
+        access_list: Option<AccessList>,
+        read_only: bool,
+        skip_execution: bool,
+    ) -> CallResult {
+        #[cfg(feature = "evm-tracing")]
+        if !skip_execution {
+            tracing::Event::Call {
+                source_vm_id: context.source_vm_id,
+                target_vm_id: vm_id,
+                target: target.clone(),
+                input: input.clone(),
+                value,
+                is_static: read_only,
+                gas_limit: T::GasWeightMapping::weight_to_gas(context.weight_limit),
+            }
+            .emit();
+        }
+
+        let res = Pallet::<T>::execute_call(
+            context,
+            vm_id,
+            source,
+            target,
+            input,
+            value,
+            storage_deposit_limit,
+            access_list,
+            read_only,
+            skip_execution,
+        );
+
+        #[cfg(feature = "evm-tracing")]
+        if !skip_execution {
+            let used_weight = match res {
+                Ok(ref output) => output.used_weight,
+                Err(ref failure) => failure.used_weight,
+            };
+            tracing::Event::Exit {
+                result: res.clone(),
+                used_gas: T::GasWeightMapping::weight_to_gas(used_weight),
+            }
+            .emit();
+        }
+
+        res
+    }
+
+    fn execute_call(
+        context: Context,
+        vm_id: VmId,
+        source: T::AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+        value: Balance,
+        storage_deposit_limit: Option<Balance>,
+        access_list: Option<AccessList>,
+        read_only: bool,
         skip_execution: bool,
     ) -> CallResult {
         let overheads = match vm_id {
@@ -154,7 +239,7 @@ where
             return Err(CallFailure::error(ReentranceDenied, overheads));
         }
 
-        let res = match vm_id {
+        let execute = || match vm_id {
             VmId::Evm => Pallet::<T>::evm_call(
                 context,
                 source,
@@ -162,6 +247,8 @@ where
                 input,
                 value,
                 overheads,
+                access_list,
+                read_only,
                 skip_execution,
             ),
             VmId::Wasm => Pallet::<T>::wasm_call(
@@ -172,10 +259,28 @@ where
                 value,
                 overheads,
                 storage_deposit_limit,
+                read_only,
                 skip_execution,
             ),
         };
 
+        // Read-only calls are executed in a storage layer which is always discarded.
+        let res = if read_only {
+            with_transaction(
+                || -> TransactionOutcome<Result<CallResult, DispatchError>> {
+                    TransactionOutcome::Rollback(Ok(execute()))
+                },
+            )
+            .unwrap_or_else(|err| {
+                Err(CallFailure::error(
+                    VmError(format!("XVM storage layer error: {:?}", err).into()),
+                    overheads,
+                ))
+            })
+        } else {
+            execute()
+        };
+
         // Set `IN_XVM` to false.
         // We should make sure that this line is executed whatever the execution path.
         let _ = IN_XVM.with(|in_xvm| in_xvm.take());
@@ -190,12 +295,14 @@ where
         input: Vec<u8>,
         value: Balance,
         overheads: Weight,
+        access_list: Option<AccessList>,
+        read_only: bool,
         skip_execution: bool,
     ) -> CallResult {
         log::trace!(
             target: "xvm::evm_call",
-            "Calling EVM: {:?} {:?}, {:?}, {:?}, {:?}",
-            context, source, target, input, value,
+            "Calling EVM: {:?} {:?}, {:?}, {:?}, {:?}, {:?}",
+            context, source, target, input, value, access_list,
         );
 
         ensure!(
@@ -218,7 +325,7 @@ where
             target: target_decoded,
             value: value_u256,
             input: bounded_input,
-            maybe_access_list: None,
+            maybe_access_list: access_list,
         };
 
         // Note the skip execution check should be exactly before `T::EthereumTransact::xvm_transact`
@@ -227,7 +334,11 @@ where
             return Ok(CallOutput::new(vec![], overheads));
         }
 
-        let transact_result = T::EthereumTransact::xvm_transact(source, tx);
+        let transact_result = if read_only {
+            T::EthereumTransact::xvm_static_call(source, tx)
+        } else {
+            T::EthereumTransact::xvm_transact(source, tx)
+        };
         log::trace!(
             target: "xvm::evm_call",
             "EVM call result: {:?}", transact_result,
@@ -278,6 +389,7 @@ where
         value: Balance,
         overheads: Weight,
         storage_deposit_limit: Option<Balance>,
+        read_only: bool,
         skip_execution: bool,
     ) -> CallResult {
         log::trace!(
@@ -300,6 +412,16 @@ where
             return Ok(CallOutput::new(vec![], overheads));
         }
 
+        // `pallet-contracts` has no read-only entry point, so read-only calls are guarded by the
+        // storage deposit: a zero limit makes any storage growth fail, and a refund reveals
+        // removed storage. Overwrites keeping the storage size are not detected, but they are
+        // discarded along with the storage layer of the read-only call.
+        let storage_deposit_limit = if read_only {
+            Some(Balance::zero())
+        } else {
+            storage_deposit_limit
+        };
+
         let call_result = pallet_contracts::Pallet::<T>::bare_call(
             source,
             dest,
@@ -318,10 +440,21 @@ where
             Ok(val) => {
                 if val.flags.contains(ReturnFlags::REVERT) {
                     Err(CallFailure::revert(VmRevert(val.data), used_weight))
+                } else if read_only && !call_result.storage_deposit.is_zero() {
+                    Err(CallFailure::error(StateChangeDenied, used_weight))
                 } else {
                     Ok(CallOutput::new(val.data, used_weight))
                 }
             }
+            Err(error)
+                if read_only
+                    && error
+                        == DispatchError::from(
+                            pallet_contracts::Error::<T>::StorageDepositLimitExhausted,
+                        ) =>
+            {
+                Err(CallFailure::error(StateChangeDenied, used_weight))
+            }
             Err(error) => Err(CallFailure::error(
                 VmError(format!("WASM call error: {:?}", error).into()),
                 used_weight,
@@ -329,6 +462,44 @@ where
         }
     }
 
+    /// Execute an XVM call and discard all state changes, reporting its outcome.
+    ///
+    /// Intended for the `XvmApi` runtime API, so frontends can estimate the weight
+    /// of cross-VM calls.
+    pub fn dry_run(
+        context: Context,
+        vm_id: VmId,
+        source: T::AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+        value: Balance,
+        storage_deposit_limit: Option<Balance>,
+        access_list: Option<AccessList>,
+    ) -> CallResult {
+        with_transaction(
+            || -> TransactionOutcome<Result<CallResult, DispatchError>> {
+                TransactionOutcome::Rollback(Ok(Self::do_call(
+                    context,
+                    vm_id,
+                    source,
+                    target,
+                    input,
+                    value,
+                    storage_deposit_limit,
+                    access_list,
+                    false,
+                    false,
+                )))
+            },
+        )
+        .unwrap_or_else(|err| {
+            Err(CallFailure::error(
+                VmError(format!("XVM storage layer error: {:?}", err).into()),
+                Weight::zero(),
+            ))
+        })
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     pub fn call_without_execution(
         context: Context,
@@ -347,6 +518,8 @@ where
             input,
             value,
             storage_deposit_limit,
+            None,
+            false,
             true,
         )
     }
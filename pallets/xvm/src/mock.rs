@@ -144,6 +144,7 @@ impl pallet_contracts::Config for TestRuntime {
 
 thread_local! {
     static TRANSACTED: RefCell<Option<(H160, CheckedEthereumTx)>> = RefCell::new(None);
+    static STATIC_CALLED: RefCell<Option<(H160, CheckedEthereumTx)>> = RefCell::new(None);
 }
 
 pub struct MockEthereumTransact;
@@ -152,13 +153,14 @@ impl MockEthereumTransact {
         let transacted = TRANSACTED.with(|v| v.borrow().clone());
         assert_eq!(transacted, Some((source, checked_tx)));
     }
-}
-impl CheckedEthereumTransact for MockEthereumTransact {
-    fn xvm_transact(
-        source: H160,
-        checked_tx: CheckedEthereumTx,
-    ) -> Result<(PostDispatchInfo, EvmCallInfo), DispatchErrorWithPostInfo> {
-        TRANSACTED.with(|v| *v.borrow_mut() = Some((source, checked_tx)));
+
+    pub(crate) fn assert_static_called(source: H160, checked_tx: CheckedEthereumTx) {
+        let static_called = STATIC_CALLED.with(|v| v.borrow().clone());
+        assert_eq!(static_called, Some((source, checked_tx)));
+        assert_eq!(TRANSACTED.with(|v| v.borrow().clone()), None);
+    }
+
+    fn succeeded() -> Result<(PostDispatchInfo, EvmCallInfo), DispatchErrorWithPostInfo> {
         Ok((
             PostDispatchInfo {
                 actual_weight: Default::default(),
@@ -177,6 +179,23 @@ impl CheckedEthereumTransact for MockEthereumTransact {
         ))
     }
 }
+impl CheckedEthereumTransact for MockEthereumTransact {
+    fn xvm_transact(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, EvmCallInfo), DispatchErrorWithPostInfo> {
+        TRANSACTED.with(|v| *v.borrow_mut() = Some((source, checked_tx)));
+        Self::succeeded()
+    }
+
+    fn xvm_static_call(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, EvmCallInfo), DispatchErrorWithPostInfo> {
+        STATIC_CALLED.with(|v| *v.borrow_mut() = Some((source, checked_tx)));
+        Self::succeeded()
+    }
+}
 
 pub struct MockGasWeightMapping;
 impl GasWeightMapping for MockGasWeightMapping {
@@ -212,6 +231,57 @@ construct_runtime!(
 
 pub(crate) const ALICE: AccountId = AccountId32::new([0u8; 32]);
 
+/* Testing contracts
+
+;; Writes `[0, 0, 0, 0]` under the storage key `[0; 32]` on deploy, and overwrites it with
+;; `[1, 0, 0, 0, 0, 0, 0, 0]` on call, which grows the storage deposit.
+(module
+  (import "seal0" "seal_set_storage" (func $set_storage (param i32 i32 i32)))
+  (import "env" "memory" (memory 1 1))
+  (func (export "deploy")
+    (call $set_storage (i32.const 0) (i32.const 32) (i32.const 4)))
+  (func (export "call")
+    (i32.store (i32.const 32) (i32.const 1))
+    (call $set_storage (i32.const 0) (i32.const 32) (i32.const 8)))
+)
+
+;; Same deploy, but call doesn't modify the storage.
+(module
+  (import "seal0" "seal_set_storage" (func $set_storage (param i32 i32 i32)))
+  (import "env" "memory" (memory 1 1))
+  (func (export "deploy")
+    (call $set_storage (i32.const 0) (i32.const 32) (i32.const 4)))
+  (func (export "call"))
+)
+*/
+pub const STORAGE_OVERWRITE_CONTRACT: &str = "0061736d01000000010a0260037f7f7f00600000022902057365616c30107365616c5f7365745f73746f72616765000003656e76066d656d6f7279020101010303020101071102066465706c6f7900010463616c6c00020a1e020a0041004120410410000b11004120410136020041004120410810000b";
+pub const READ_ONLY_CONTRACT: &str = "0061736d01000000010a0260037f7f7f00600000022902057365616c30107365616c5f7365745f73746f72616765000003656e76066d656d6f7279020101010303020101071102066465706c6f7900010463616c6c00020a0f020a0041004120410410000b02000b";
+
+/// Storage key written by the testing contracts.
+pub const CONTRACT_STORAGE_KEY: [u8; 32] = [0; 32];
+
+/// Weight limit enough to call the testing contracts.
+pub fn contract_weight_limit() -> Weight {
+    Weight::from_parts(100_000_000_000, 1024 * 1024)
+}
+
+pub fn deploy_contract(code: &str) -> AccountId {
+    Contracts::bare_instantiate(
+        ALICE,
+        0,
+        contract_weight_limit(),
+        Some(1_000_000_000),
+        pallet_contracts::Code::Upload(hex::decode(code).expect("invalid code hex")),
+        vec![],
+        vec![],
+        DebugInfo::Skip,
+        CollectEvents::Skip,
+    )
+    .result
+    .expect("failed to instantiate contract")
+    .account_id
+}
+
 #[derive(Default)]
 pub struct ExtBuilder;
 
@@ -219,11 +289,18 @@ impl ExtBuilder {
     #[allow(dead_code)]
     pub fn build(self) -> TestExternalities {
         TRANSACTED.with(|v| *v.borrow_mut() = None);
+        STATIC_CALLED.with(|v| *v.borrow_mut() = None);
 
-        let t = frame_system::GenesisConfig::<TestRuntime>::default()
+        let mut t = frame_system::GenesisConfig::<TestRuntime>::default()
             .build_storage()
             .unwrap();
 
+        pallet_balances::GenesisConfig::<TestRuntime> {
+            balances: vec![(ALICE, 1_000_000_000_000)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
         let mut ext = TestExternalities::from(t);
         ext.execute_with(|| {
             System::set_block_number(1);
@@ -21,9 +21,10 @@
 use super::*;
 use mock::*;
 
+use astar_primitives::xvm::FailureReason;
 use frame_support::{assert_noop, assert_ok, weights::Weight};
 use parity_scale_codec::Encode;
-use sp_core::H160;
+use sp_core::{H160, H256};
 
 #[test]
 fn calling_into_same_vm_is_not_allowed() {
@@ -46,6 +47,7 @@ fn calling_into_same_vm_is_not_allowed() {
                 evm_target,
                 input.clone(),
                 value,
+                None,
                 None
             ),
             CallFailure::error(SameVmCallDenied, evm_used_weight,),
@@ -68,6 +70,7 @@ fn calling_into_same_vm_is_not_allowed() {
                 wasm_target,
                 input,
                 value,
+                None,
                 None
             ),
             CallFailure::error(SameVmCallDenied, wasm_used_weight,),
@@ -95,13 +98,23 @@ fn evm_call_fails_if_target_not_h160() {
                 ALICE.encode(),
                 input.clone(),
                 value,
+                None,
                 None
             ),
             CallFailure::revert(InvalidTarget, used_weight,),
         );
 
         assert_noop!(
-            Xvm::call(context, vm_id, ALICE, vec![1, 2, 3], input, value, None),
+            Xvm::call(
+                context,
+                vm_id,
+                ALICE,
+                vec![1, 2, 3],
+                input,
+                value,
+                None,
+                None
+            ),
             CallFailure::revert(InvalidTarget, used_weight,),
         );
     });
@@ -127,6 +140,7 @@ fn evm_call_fails_if_input_too_large() {
                 target.encode(),
                 vec![1; 65_537],
                 value,
+                None,
                 None
             ),
             CallFailure::revert(InputTooLarge, used_weight,),
@@ -153,6 +167,7 @@ fn evm_call_works() {
             target.encode(),
             input.clone(),
             value,
+            None,
             None
         ));
         let source = Decode::decode(
@@ -188,8 +203,199 @@ fn wasm_call_fails_if_invalid_target() {
         let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::wasm_call_overheads();
 
         assert_noop!(
-            Xvm::call(context, vm_id, ALICE, target.encode(), input, value, None),
+            Xvm::call(
+                context,
+                vm_id,
+                ALICE,
+                target.encode(),
+                input,
+                value,
+                None,
+                None
+            ),
             CallFailure::revert(InvalidTarget, used_weight,),
         );
     });
 }
+
+#[test]
+fn static_call_into_same_vm_is_not_allowed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::evm_call_overheads();
+        assert_noop!(
+            Xvm::static_call(
+                context,
+                VmId::Evm,
+                ALICE,
+                H160::repeat_byte(1).encode(),
+                vec![1, 2, 3]
+            ),
+            CallFailure::error(SameVmCallDenied, used_weight),
+        );
+    });
+}
+
+#[test]
+fn evm_static_call_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let target = H160::repeat_byte(0xFF);
+        let input = vec![1; 65_536];
+
+        assert_ok!(Xvm::static_call(
+            context,
+            VmId::Evm,
+            ALICE,
+            target.encode(),
+            input.clone()
+        ));
+        let source = Decode::decode(
+            &mut hex::decode("f0bd9ffde7f9f4394d8cc1d86bf24d87e5d5a9a9")
+                .expect("invalid source hex")
+                .as_ref(),
+        )
+        .expect("invalid source");
+        // Static calls never transfer value, nor are applied as transactions.
+        MockEthereumTransact::assert_static_called(
+            source,
+            CheckedEthereumTx {
+                gas_limit: U256::from(246000),
+                target,
+                value: U256::zero(),
+                input: EthereumTxInput::try_from(input).expect("input too large"),
+                maybe_access_list: None,
+            },
+        );
+    });
+}
+
+#[test]
+fn dry_run_reports_failure_reason_and_used_weight() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let used_weight: Weight = weights::SubstrateWeight::<TestRuntime>::wasm_call_overheads();
+
+        assert_eq!(
+            Xvm::dry_run(
+                context,
+                VmId::Wasm,
+                ALICE,
+                vec![1, 2, 3].encode(),
+                vec![],
+                0,
+                None,
+                None
+            ),
+            Err(CallFailure::revert(InvalidTarget, used_weight)),
+        );
+    });
+}
+
+#[test]
+fn evm_call_forwards_access_list() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Wasm,
+            weight_limit: Weight::from_parts(1_000_000, 1_000_000),
+        };
+        let target = H160::repeat_byte(0xFF);
+        let access_list = vec![(target, vec![H256::repeat_byte(1), H256::repeat_byte(2)])];
+
+        assert_ok!(Xvm::call(
+            context,
+            VmId::Evm,
+            ALICE,
+            target.encode(),
+            vec![],
+            0,
+            None,
+            Some(access_list.clone())
+        ));
+        let source = Decode::decode(
+            &mut hex::decode("f0bd9ffde7f9f4394d8cc1d86bf24d87e5d5a9a9")
+                .expect("invalid source hex")
+                .as_ref(),
+        )
+        .expect("invalid source");
+        MockEthereumTransact::assert_transacted(
+            source,
+            CheckedEthereumTx {
+                gas_limit: U256::from(246000),
+                target,
+                value: U256::zero(),
+                input: EthereumTxInput::default(),
+                maybe_access_list: Some(access_list),
+            },
+        );
+    });
+}
+
+#[test]
+fn wasm_static_call_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: contract_weight_limit(),
+        };
+        let contract = deploy_contract(READ_ONLY_CONTRACT);
+
+        assert_ok!(Xvm::static_call(
+            context,
+            VmId::Wasm,
+            ALICE,
+            contract.encode(),
+            vec![]
+        ));
+    });
+}
+
+#[test]
+fn wasm_static_call_fails_on_storage_overwrite() {
+    ExtBuilder::default().build().execute_with(|| {
+        let context = Context {
+            source_vm_id: VmId::Evm,
+            weight_limit: contract_weight_limit(),
+        };
+        let contract = deploy_contract(STORAGE_OVERWRITE_CONTRACT);
+        let stored_value = || {
+            Contracts::get_storage(contract.clone(), CONTRACT_STORAGE_KEY.to_vec())
+                .expect("contract exists")
+        };
+        assert_eq!(stored_value(), Some(vec![0, 0, 0, 0]));
+
+        let result = Xvm::static_call(
+            context.clone(),
+            VmId::Wasm,
+            ALICE,
+            contract.encode(),
+            vec![],
+        );
+        assert_eq!(
+            result.map_err(|failure| failure.reason),
+            Err(FailureReason::Error(StateChangeDenied))
+        );
+        assert_eq!(stored_value(), Some(vec![0, 0, 0, 0]));
+
+        assert_ok!(Xvm::call(
+            context,
+            VmId::Wasm,
+            ALICE,
+            contract.encode(),
+            vec![],
+            0,
+            None,
+            None
+        ));
+        assert_eq!(stored_value(), Some(vec![1, 0, 0, 0, 0, 0, 0, 0]));
+    });
+}
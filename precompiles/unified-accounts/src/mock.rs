@@ -242,10 +242,18 @@ parameter_types! {
 
 impl pallet_unified_accounts::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type UnlinkDelay = ConstU64<10>;
+    type UnlinkRecoveryOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetId = u32;
+    type ClaimAssetsMigrator = ();
+    type MaxClaimAssets = ConstU32<0>;
+    type ContractSignatureValidator = ();
+    type MaxContractSignatureLength = ConstU32<0>;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
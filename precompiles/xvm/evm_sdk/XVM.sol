@@ -21,4 +21,44 @@ interface XVM {
         uint256 value,
         uint256 storage_deposit_limit
     ) external payable returns (bool success, bytes memory data);
+
+    /// @dev EIP-2930 access list entry.
+    struct AccessListItem {
+        address addr;
+        bytes32[] storageKeys;
+    }
+
+    /**
+     * @dev Execute external VM call with an EIP-2930 access list
+     * @param vm_id - target VM id
+     * @param to - call recipient
+     * @param input - SCALE-encoded call arguments
+     * @param value - value to transfer
+     * @param storage_deposit_limit - storage deposit limit, use 0 for unlimited.
+     * @param access_list - addresses and storage keys to pre-warm, only used by EVM calls
+     * @return success - operation outcome
+     * @return data - output data if successful, error data on error
+     */
+    function xvm_call_with_access_list(
+        uint8 vm_id,
+        bytes calldata to,
+        bytes calldata input,
+        uint256 value,
+        uint256 storage_deposit_limit,
+        AccessListItem[] calldata access_list
+    ) external payable returns (bool success, bytes memory data);
+
+    /**
+     * @dev Execute external VM call without modifying state
+     * @param vm_id - target VM id
+     * @param to - call recipient
+     * @param input - SCALE-encoded call arguments
+     * @return success - operation outcome
+     * @return data - output data if successful, error data on error
+     */
+    function xvm_static_call(
+        uint8 vm_id,
+        bytes calldata to,
+        bytes calldata input
+    ) external view returns (bool success, bytes memory data);
 }
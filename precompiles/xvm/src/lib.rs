@@ -21,12 +21,15 @@
 extern crate alloc;
 use alloc::format;
 
-use astar_primitives::xvm::{Context, FailureReason, VmId, XvmCall};
+use astar_primitives::{
+    ethereum_checked::AccessList,
+    xvm::{CallResult, Context, FailureReason, VmId, XvmCall},
+};
 use fp_evm::{ExitRevert, PrecompileFailure, PrecompileHandle};
 use pallet_evm::{AddressMapping, GasWeightMapping};
-use sp_core::U256;
+use sp_core::{H256, U256};
 use sp_runtime::traits::Dispatchable;
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use precompile_utils::prelude::*;
 #[cfg(test)]
@@ -56,19 +59,81 @@ where
         call_input: UnboundedBytes,
         value: U256,
         storage_deposit_limit: U256,
+    ) -> EvmResult<(bool, UnboundedBytes)> {
+        Self::call_xvm(
+            handle,
+            vm_id,
+            call_to,
+            call_input,
+            value,
+            storage_deposit_limit,
+            None,
+        )
+    }
+
+    #[precompile::public(
+        "xvm_call_with_access_list(uint8,bytes,bytes,uint256,uint256,(address,bytes32[])[])"
+    )]
+    fn xvm_call_with_access_list(
+        handle: &mut impl PrecompileHandle,
+        vm_id: u8,
+        call_to: UnboundedBytes,
+        call_input: UnboundedBytes,
+        value: U256,
+        storage_deposit_limit: U256,
+        access_list: Vec<(Address, Vec<H256>)>,
+    ) -> EvmResult<(bool, UnboundedBytes)> {
+        let access_list = access_list
+            .into_iter()
+            .map(|(address, storage_keys)| (address.into(), storage_keys))
+            .collect();
+        Self::call_xvm(
+            handle,
+            vm_id,
+            call_to,
+            call_input,
+            value,
+            storage_deposit_limit,
+            Some(access_list),
+        )
+    }
+
+    #[precompile::public("xvm_static_call(uint8,bytes,bytes)")]
+    #[precompile::view]
+    fn xvm_static_call(
+        handle: &mut impl PrecompileHandle,
+        vm_id: u8,
+        call_to: UnboundedBytes,
+        call_input: UnboundedBytes,
     ) -> EvmResult<(bool, UnboundedBytes)> {
         let vm_id = vm_id.try_into().map_err(|_| revert("invalid vm id"))?;
+        let xvm_context = Self::xvm_context(handle);
 
-        let mut gas_limit = handle.remaining_gas();
-        // If user specified a gas limit, make sure it's not exceeded.
-        if let Some(user_limit) = handle.gas_limit() {
-            gas_limit = gas_limit.min(user_limit);
-        }
-        let weight_limit = R::GasWeightMapping::gas_to_weight(gas_limit, true);
-        let xvm_context = Context {
-            source_vm_id: VmId::Evm,
-            weight_limit,
-        };
+        let call_to = call_to.into();
+        let call_input = call_input.into();
+        let from = R::AddressMapping::into_account_id(handle.context().caller);
+
+        log::trace!(
+            target: "xvm-precompile::xvm_static_call",
+            "vm_id: {:?}, from: {:?}, call_to: {:?}, call_input: {:?}", vm_id, from, call_to, call_input
+        );
+        let call_result = XC::static_call(xvm_context, vm_id, from, call_to, call_input);
+
+        Self::handle_call_result(handle, call_result)
+    }
+
+    /// Execute the XVM call on behalf of the precompile caller.
+    fn call_xvm(
+        handle: &mut impl PrecompileHandle,
+        vm_id: u8,
+        call_to: UnboundedBytes,
+        call_input: UnboundedBytes,
+        value: U256,
+        storage_deposit_limit: U256,
+        access_list: Option<AccessList>,
+    ) -> EvmResult<(bool, UnboundedBytes)> {
+        let vm_id = vm_id.try_into().map_err(|_| revert("invalid vm id"))?;
+        let xvm_context = Self::xvm_context(handle);
 
         let call_to = call_to.into();
         let call_input = call_input.into();
@@ -89,10 +154,41 @@ where
 
         log::trace!(
             target: "xvm-precompile::xvm_call",
-            "vm_id: {:?}, from: {:?}, call_to: {:?}, call_input: {:?}, value: {:?}, limit: {:?}", vm_id, from, call_to, call_input, value, limit
+            "vm_id: {:?}, from: {:?}, call_to: {:?}, call_input: {:?}, value: {:?}, limit: {:?}, access_list: {:?}", vm_id, from, call_to, call_input, value, limit, access_list
+        );
+        let call_result = XC::call(
+            xvm_context,
+            vm_id,
+            from,
+            call_to,
+            call_input,
+            value,
+            limit,
+            access_list,
         );
-        let call_result = XC::call(xvm_context, vm_id, from, call_to, call_input, value, limit);
 
+        Self::handle_call_result(handle, call_result)
+    }
+
+    /// XVM context with the weight limit derived from the gas left for the precompile.
+    fn xvm_context(handle: &mut impl PrecompileHandle) -> Context {
+        let mut gas_limit = handle.remaining_gas();
+        // If user specified a gas limit, make sure it's not exceeded.
+        if let Some(user_limit) = handle.gas_limit() {
+            gas_limit = gas_limit.min(user_limit);
+        }
+        let weight_limit = R::GasWeightMapping::gas_to_weight(gas_limit, true);
+        Context {
+            source_vm_id: VmId::Evm,
+            weight_limit,
+        }
+    }
+
+    /// Charge the used weight and convert the XVM call result into the precompile output.
+    fn handle_call_result(
+        handle: &mut impl PrecompileHandle,
+        call_result: CallResult,
+    ) -> EvmResult<(bool, UnboundedBytes)> {
         let used_weight = match &call_result {
             Ok(s) => s.used_weight,
             Err(f) => f.used_weight,
@@ -40,8 +40,9 @@ use sp_runtime::{
 };
 use sp_std::cell::RefCell;
 
-use astar_primitives::xvm::{
-    CallFailure, CallOutput, CallResult, FailureError::*, FailureRevert::*,
+use astar_primitives::{
+    ethereum_checked::AccessList,
+    xvm::{CallFailure, CallOutput, CallResult, FailureError::*, FailureRevert::*},
 };
 
 pub type AccountId = TestAccount;
@@ -274,6 +275,7 @@ impl XvmCall<AccountId> for MockXvmWithArgsCheck {
         input: Vec<u8>,
         _value: Balance,
         _storage_deposit_limit: Option<Balance>,
+        _access_list: Option<AccessList>,
     ) -> CallResult {
         ensure!(
             vm_id != VmId::Evm,
@@ -292,6 +294,16 @@ impl XvmCall<AccountId> for MockXvmWithArgsCheck {
 
         Ok(CallOutput::new(vec![], Weight::zero()))
     }
+
+    fn static_call(
+        context: Context,
+        vm_id: VmId,
+        source: AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+    ) -> CallResult {
+        Self::call(context, vm_id, source, target, input, 0, None, None)
+    }
 }
 
 // Configure a mock runtime to test the pallet.
@@ -126,3 +126,63 @@ fn weight_limit_is_min_of_remaining_and_user_limit() {
         );
     });
 }
+
+#[test]
+fn static_call_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::xvm_static_call {
+                    vm_id: 0x1Fu8.into(),
+                    call_to: hex::decode("0000000000000000000000000000000000000000")
+                        .expect("invalid hex")
+                        .into(),
+                    call_input: b"".into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns((true, UnboundedBytes::from(Vec::<u8>::new())));
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::xvm_static_call {
+                    vm_id: 0.into(),
+                    call_to: b"".into(),
+                    call_input: b"".into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output == b"invalid vm id");
+    })
+}
+
+#[test]
+fn call_with_access_list_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::xvm_call_with_access_list {
+                    vm_id: 0x1Fu8.into(),
+                    call_to: hex::decode("0000000000000000000000000000000000000000")
+                        .expect("invalid hex")
+                        .into(),
+                    call_input: b"".into(),
+                    value: 1.into(),
+                    storage_deposit_limit: 0.into(),
+                    access_list: vec![(
+                        Address(H160::repeat_byte(0xAA)),
+                        vec![H256::repeat_byte(1)],
+                    )]
+                    .into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns((true, UnboundedBytes::from(Vec::<u8>::new())));
+    })
+}
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::AssetId;
+use crate::{treasury::TreasuryAssetKind, AccountId, AssetId};
 
 /// Benchmark helper for `pallet-assets`.
 pub struct AssetsBenchmarkHelper;
@@ -29,3 +29,27 @@ impl<AssetIdParameter: From<u128>> pallet_assets::BenchmarkHelper<AssetIdParamet
         AssetId::from(id).into()
     }
 }
+
+/// Benchmark helper for `pallet-treasury` & `pallet-asset-rate`.
+pub struct TreasuryBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_treasury::ArgumentsFactory<TreasuryAssetKind<AssetId>, AccountId>
+    for TreasuryBenchmarkHelper
+{
+    fn create_asset_kind(_seed: u32) -> TreasuryAssetKind<AssetId> {
+        // Native payouts don't require the asset to exist.
+        TreasuryAssetKind::Native
+    }
+
+    fn create_beneficiary(seed: [u8; 32]) -> AccountId {
+        AccountId::from(seed)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_asset_rate::AssetKindFactory<TreasuryAssetKind<AssetId>> for TreasuryBenchmarkHelper {
+    fn create_asset_kind(seed: u32) -> TreasuryAssetKind<AssetId> {
+        TreasuryAssetKind::Asset(AssetId::from(seed))
+    }
+}
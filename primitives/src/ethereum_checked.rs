@@ -37,6 +37,9 @@ pub const MAX_ETHEREUM_TX_INPUT_SIZE: u32 = 2u32.pow(16);
 
 pub type EthereumTxInput = BoundedVec<u8, ConstU32<MAX_ETHEREUM_TX_INPUT_SIZE>>;
 
+/// Access list, specified in EIP-2930: a list of addresses and storage keys to pre-warm.
+pub type AccessList = Vec<(H160, Vec<H256>)>;
+
 /// The checked Ethereum transaction. Only contracts `call` is support(no `create`).
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct CheckedEthereumTx {
@@ -49,11 +52,12 @@ pub struct CheckedEthereumTx {
     /// Input of a contract call.
     pub input: EthereumTxInput,
     /// Optional access list, specified in EIP-2930.
-    pub maybe_access_list: Option<Vec<(H160, Vec<H256>)>>,
+    pub maybe_access_list: Option<AccessList>,
 }
 
 impl CheckedEthereumTx {
-    pub fn into_ethereum_tx(&self, nonce: U256, chain_id: u64) -> Transaction {
+    /// Build the Ethereum transaction, paying `gas_price` per unit of gas.
+    pub fn into_ethereum_tx(&self, nonce: U256, chain_id: u64, gas_price: U256) -> Transaction {
         let access_list = if let Some(ref list) = self.maybe_access_list {
             list.iter()
                 .map(|(address, storage_keys)| AccessListItem {
@@ -68,7 +72,7 @@ impl CheckedEthereumTx {
         Transaction::EIP1559(EIP1559Transaction {
             chain_id,
             nonce,
-            max_fee_per_gas: U256::zero(),
+            max_fee_per_gas: gas_price,
             max_priority_fee_per_gas: U256::zero(),
             gas_limit: self.gas_limit,
             value: self.value,
@@ -95,4 +99,12 @@ pub trait CheckedEthereumTransact {
         source: H160,
         checked_tx: CheckedEthereumTx,
     ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo>;
+
+    /// Call a contract in EVM static context, in which any state modification (`SSTORE`,
+    /// `LOG*`, `CREATE*`, value transfer, etc.) makes the call fail. The `value` of
+    /// `checked_tx` is ignored.
+    fn xvm_static_call(
+        source: H160,
+        checked_tx: CheckedEthereumTx,
+    ) -> Result<(PostDispatchInfo, CallInfo), DispatchErrorWithPostInfo>;
 }
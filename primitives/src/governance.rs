@@ -24,6 +24,7 @@ pub type OracleMembershipInst = pallet_membership::Instance1;
 pub type MainCouncilMembershipInst = pallet_membership::Instance2;
 pub type TechnicalCommitteeMembershipInst = pallet_membership::Instance3;
 pub type CommunityCouncilMembershipInst = pallet_membership::Instance4;
+pub type SignedTransactMembershipInst = pallet_membership::Instance5;
 
 // Leaving instance 1 for potentially having an oracle membership collective instance
 pub type MainCouncilCollectiveInst = pallet_collective::Instance2;
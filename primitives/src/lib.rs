@@ -43,6 +43,8 @@ pub mod inflation;
 /// Governance primitives.
 pub mod governance;
 
+pub mod treasury;
+
 /// Benchmark primitives
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarks;
@@ -0,0 +1,135 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        tokens::{
+            fungible, fungibles, ConversionFromAssetBalance, Pay, PaymentStatus, Preservation,
+        },
+        TypedGet,
+    },
+};
+use sp_runtime::DispatchError;
+use sp_std::marker::PhantomData;
+
+/// Asset which can be spent by a treasury via the `spend` call.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    TypeInfo,
+    RuntimeDebug,
+)]
+pub enum TreasuryAssetKind<AssetId> {
+    /// The native currency.
+    Native,
+    /// An asset from `pallet-assets`.
+    Asset(AssetId),
+}
+
+/// Pays native currency or `pallet-assets` assets from the account `A`.
+///
+/// Payments are executed immediately, so `check_payment` always reports success.
+pub struct PayFromAccountOrAsset<F, Fs, A>(PhantomData<(F, Fs, A)>);
+impl<F, Fs, A> Pay for PayFromAccountOrAsset<F, Fs, A>
+where
+    A: TypedGet,
+    F: fungible::Mutate<A::Type>,
+    Fs: fungibles::Mutate<A::Type, Balance = F::Balance>,
+{
+    type Balance = F::Balance;
+    type Beneficiary = A::Type;
+    type AssetKind = TreasuryAssetKind<Fs::AssetId>;
+    type Id = ();
+    type Error = DispatchError;
+
+    fn pay(
+        who: &Self::Beneficiary,
+        asset_kind: Self::AssetKind,
+        amount: Self::Balance,
+    ) -> Result<Self::Id, Self::Error> {
+        match asset_kind {
+            TreasuryAssetKind::Native => {
+                F::transfer(&A::get(), who, amount, Preservation::Expendable)?;
+            }
+            TreasuryAssetKind::Asset(asset_id) => {
+                Fs::transfer(asset_id, &A::get(), who, amount, Preservation::Expendable)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_payment(_: ()) -> PaymentStatus {
+        PaymentStatus::Success
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_successful(
+        _: &Self::Beneficiary,
+        asset_kind: Self::AssetKind,
+        amount: Self::Balance,
+    ) {
+        match asset_kind {
+            TreasuryAssetKind::Native => {
+                let _ = F::mint_into(&A::get(), amount.saturating_add(F::minimum_balance()));
+            }
+            TreasuryAssetKind::Asset(asset_id) => {
+                let minimum_balance = Fs::minimum_balance(asset_id.clone());
+                let _ = Fs::mint_into(asset_id, &A::get(), amount.saturating_add(minimum_balance));
+            }
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_concluded(_: Self::Id) {}
+}
+
+/// Converts asset balances to native balance.
+///
+/// Native balance is returned unchanged, while asset balances are converted with `C`, e.g. the
+/// admin-set rates of `pallet-asset-rate`.
+pub struct NativeOrAssetBalanceConversion<C>(PhantomData<C>);
+impl<C, AssetId, Balance> ConversionFromAssetBalance<Balance, TreasuryAssetKind<AssetId>, Balance>
+    for NativeOrAssetBalanceConversion<C>
+where
+    C: ConversionFromAssetBalance<Balance, TreasuryAssetKind<AssetId>, Balance>,
+{
+    type Error = C::Error;
+
+    fn from_asset_balance(
+        balance: Balance,
+        asset_kind: TreasuryAssetKind<AssetId>,
+    ) -> Result<Balance, Self::Error> {
+        match asset_kind {
+            TreasuryAssetKind::Native => Ok(balance),
+            asset_kind => C::from_asset_balance(balance, asset_kind),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_successful(asset_kind: TreasuryAssetKind<AssetId>) {
+        C::ensure_successful(asset_kind)
+    }
+}
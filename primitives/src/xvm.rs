@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::Balance;
+use crate::{ethereum_checked::AccessList, Balance};
 
 use frame_support::weights::Weight;
 use parity_scale_codec::{Decode, Encode};
@@ -133,6 +133,8 @@ pub enum FailureError {
     VmError(Vec<u8>),
     /// Out of gas.
     OutOfGas,
+    /// A static call attempted to modify state.
+    StateChangeDenied,
 }
 
 /// XVM call result.
@@ -160,6 +162,7 @@ pub trait XvmCall<AccountId> {
     /// - `input`: call input data.
     /// - `value`: value to transfer.
     /// - `storage_deposit_limit`: storage deposit limit for wasm calls.
+    /// - `access_list`: EIP-2930 access list for evm calls, ignored for wasm calls.
     fn call(
         context: Context,
         vm_id: VmId,
@@ -168,5 +171,82 @@ pub trait XvmCall<AccountId> {
         input: Vec<u8>,
         value: Balance,
         storage_deposit_limit: Option<Balance>,
+        access_list: Option<AccessList>,
     ) -> CallResult;
+
+    /// Call a contract in XVM without modifying state.
+    ///
+    /// Any state changes made by the callee are discarded. EVM targets are executed in
+    /// static context, failing on any state modification like `STATICCALL`. WASM targets
+    /// fail with `StateChangeDenied` if the call changed the storage deposit.
+    ///
+    /// Parameters:
+    /// - `context`: XVM context.
+    /// - `vm_id`: the VM Id of the target contract.
+    /// - `source`: Caller Id.
+    /// - `target`: Target contract address.
+    /// - `input`: call input data.
+    fn static_call(
+        context: Context,
+        vm_id: VmId,
+        source: AccountId,
+        target: Vec<u8>,
+        input: Vec<u8>,
+    ) -> CallResult;
+}
+
+/// Tracing of XVM calls.
+///
+/// Works like `evm::tracing`: events are only reported if a listener has been
+/// registered with `using`, e.g. while tracing Ethereum transactions.
+pub mod tracing {
+    use super::*;
+
+    environmental::environmental!(listener: dyn EventListener + 'static);
+
+    /// XVM tracing event.
+    #[derive(PartialEq, Eq, Clone, RuntimeDebug)]
+    pub enum Event {
+        /// An XVM call is about to be executed.
+        Call {
+            /// The source VM of the call.
+            source_vm_id: VmId,
+            /// The VM Id of the target contract.
+            target_vm_id: VmId,
+            /// Target contract address.
+            target: Vec<u8>,
+            /// Call input data.
+            input: Vec<u8>,
+            /// Value to transfer.
+            value: Balance,
+            /// Whether state changes are denied.
+            is_static: bool,
+            /// Weight limit of the call, converted to EVM gas.
+            gas_limit: u64,
+        },
+        /// The XVM call exited.
+        Exit {
+            /// Result of the call.
+            result: CallResult,
+            /// Weight used by the call, converted to EVM gas.
+            used_gas: u64,
+        },
+    }
+
+    impl Event {
+        /// Report the event to the registered listener, if any.
+        pub fn emit(self) {
+            listener::with(|listener| listener.event(self));
+        }
+    }
+
+    /// XVM tracing event listener.
+    pub trait EventListener {
+        fn event(&mut self, event: Event);
+    }
+
+    /// Run `f` with `new` registered as XVM event listener.
+    pub fn using<R, F: FnOnce() -> R>(new: &mut (dyn EventListener + 'static), f: F) -> R {
+        listener::using(new, f)
+    }
 }
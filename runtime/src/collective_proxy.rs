@@ -0,0 +1,352 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Collective proxy aliases: the collectives acting on their behalf, their call filters and the
+//! inspection of the transfers & contract calls they dispatch.
+
+use crate::{AccountId, AssetId, Balance, Runtime, RuntimeCall, RuntimeOrigin};
+use astar_primitives::{
+    governance::{
+        EnsureRootOrTwoThirdsCommunityCouncil, EnsureRootOrTwoThirdsMainCouncil,
+        EnsureRootOrTwoThirdsTechnicalCommittee,
+    },
+    treasury::TreasuryAssetKind,
+};
+use frame_support::traits::{EnsureOrigin, EnsureOriginWithArg, InstanceFilter};
+use pallet_collective_proxy::{ContractTarget, InspectContractCall, InspectTransfer};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+    traits::{StaticLookup, UniqueSaturatedInto, Zero},
+    RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+/// Collectives which can act on behalf of a collective proxy alias.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub enum CollectiveProxyOrigin {
+    /// Two thirds of the Main Council.
+    MainCouncil,
+    /// Two thirds of the Technical Committee.
+    TechnicalCommittee,
+    /// Two thirds of the Community Council.
+    CommunityCouncil,
+}
+
+/// Ensures the origin is root or two thirds of the collective of the alias.
+pub struct EnsureCollectiveProxyOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, CollectiveProxyOrigin> for EnsureCollectiveProxyOrigin {
+    type Success = ();
+
+    fn try_origin(o: RuntimeOrigin, arg: &CollectiveProxyOrigin) -> Result<(), RuntimeOrigin> {
+        match arg {
+            CollectiveProxyOrigin::MainCouncil => {
+                EnsureRootOrTwoThirdsMainCouncil::try_origin(o).map(|_| ())
+            }
+            CollectiveProxyOrigin::TechnicalCommittee => {
+                EnsureRootOrTwoThirdsTechnicalCommittee::try_origin(o).map(|_| ())
+            }
+            CollectiveProxyOrigin::CommunityCouncil => {
+                EnsureRootOrTwoThirdsCommunityCouncil::try_origin(o).map(|_| ())
+            }
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(arg: &CollectiveProxyOrigin) -> Result<RuntimeOrigin, ()> {
+        match arg {
+            CollectiveProxyOrigin::MainCouncil => {
+                EnsureRootOrTwoThirdsMainCouncil::try_successful_origin()
+            }
+            CollectiveProxyOrigin::TechnicalCommittee => {
+                EnsureRootOrTwoThirdsTechnicalCommittee::try_successful_origin()
+            }
+            CollectiveProxyOrigin::CommunityCouncil => {
+                EnsureRootOrTwoThirdsCommunityCouncil::try_successful_origin()
+            }
+        }
+    }
+}
+
+/// Calls a collective proxy alias can dispatch.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub enum CollectiveProxyCallFilter {
+    /// Remarks & batches, as well as allowed contract calls, for the community treasury.
+    CommunityCouncil,
+    /// Native & asset transfers, and batches of them.
+    Transfers,
+}
+
+impl InstanceFilter<RuntimeCall> for CollectiveProxyCallFilter {
+    fn filter(&self, c: &RuntimeCall) -> bool {
+        match self {
+            CollectiveProxyCallFilter::CommunityCouncil => matches!(
+                c,
+                RuntimeCall::System(frame_system::Call::remark { .. })
+                    | RuntimeCall::Utility(..)
+                    // Needed to call EVM contracts as the mapped address of the treasury account.
+                    | RuntimeCall::UnifiedAccounts(
+                        pallet_unified_accounts::Call::claim_default_evm_address { .. }
+                    )
+            ),
+            CollectiveProxyCallFilter::Transfers => matches!(
+                c,
+                RuntimeCall::Utility(..)
+                    | RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
+                    | RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. })
+                    | RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. })
+                    | RuntimeCall::Assets(pallet_assets::Call::transfer { .. })
+                    | RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive { .. })
+            ),
+        }
+    }
+}
+
+/// Calls nested in `call`, if it's a batch.
+fn batched_calls(call: &RuntimeCall) -> Option<&[RuntimeCall]> {
+    match call {
+        RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+        | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls })
+        | RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) => Some(&calls[..]),
+        _ => None,
+    }
+}
+
+/// Native & asset transfers counted towards the spending caps of collective proxy aliases,
+/// including the value sent to contracts and the ones in batches.
+pub struct CollectiveProxyTransfers;
+impl InspectTransfer<RuntimeCall, TreasuryAssetKind<AssetId>, Balance>
+    for CollectiveProxyTransfers
+{
+    fn inspect(call: &RuntimeCall) -> Vec<(TreasuryAssetKind<AssetId>, Balance)> {
+        if let Some(calls) = batched_calls(call) {
+            return calls.iter().flat_map(Self::inspect).collect();
+        }
+
+        let (asset_kind, amount) = match call {
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                value, ..
+            })
+            | RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { value, .. }) => {
+                (TreasuryAssetKind::Native, *value)
+            }
+            // The transferred amount isn't known in advance, so it exceeds any native cap.
+            RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) => {
+                (TreasuryAssetKind::Native, Balance::MAX)
+            }
+            RuntimeCall::Assets(pallet_assets::Call::transfer { id, amount, .. })
+            | RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive {
+                id, amount, ..
+            }) => (TreasuryAssetKind::Asset(id.0), *amount),
+            RuntimeCall::EthereumChecked(pallet_ethereum_checked::Call::signed_transact { tx }) => {
+                (TreasuryAssetKind::Native, tx.value.unique_saturated_into())
+            }
+            RuntimeCall::Contracts(pallet_contracts::Call::call { value, .. }) => {
+                (TreasuryAssetKind::Native, *value)
+            }
+            _ => return Vec::new(),
+        };
+
+        if amount.is_zero() {
+            Vec::new()
+        } else {
+            vec![(asset_kind, amount)]
+        }
+    }
+}
+
+/// Contract calls collective proxy aliases can be allowed to make, via `EthereumChecked` for EVM
+/// contracts and `Contracts` for Wasm contracts.
+pub struct CollectiveProxyContractCalls;
+impl InspectContractCall<RuntimeCall, AccountId> for CollectiveProxyContractCalls {
+    fn inspect(call: &RuntimeCall) -> Option<(ContractTarget<AccountId>, &[u8])> {
+        match call {
+            RuntimeCall::EthereumChecked(pallet_ethereum_checked::Call::signed_transact { tx }) => {
+                Some((ContractTarget::Evm(tx.target), &tx.input[..]))
+            }
+            RuntimeCall::Contracts(pallet_contracts::Call::call { dest, data, .. }) => {
+                let dest = <Runtime as frame_system::Config>::Lookup::lookup(dest.clone()).ok()?;
+                Some((ContractTarget::Wasm(dest), &data[..]))
+            }
+            _ => None,
+        }
+    }
+
+    fn checked_calls(call: &RuntimeCall) -> u32 {
+        batched_calls(call).map_or(1, |calls| {
+            calls
+                .iter()
+                .map(Self::checked_calls)
+                .fold(1, u32::saturating_add)
+        })
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CollectiveProxyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl
+    pallet_collective_proxy::BenchmarkHelper<
+        CollectiveProxyOrigin,
+        CollectiveProxyCallFilter,
+        TreasuryAssetKind<AssetId>,
+    > for CollectiveProxyBenchmarkHelper
+{
+    fn alias_origin_and_filter() -> (CollectiveProxyOrigin, CollectiveProxyCallFilter) {
+        (
+            CollectiveProxyOrigin::MainCouncil,
+            CollectiveProxyCallFilter::CommunityCouncil,
+        )
+    }
+
+    fn asset_kind(seed: u32) -> TreasuryAssetKind<AssetId> {
+        TreasuryAssetKind::Asset(seed.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AST;
+    use astar_primitives::ethereum_checked::CheckedEthereumTx;
+    use parity_scale_codec::Compact;
+    use sp_core::{H160, U256};
+    use sp_runtime::MultiAddress;
+
+    const DEST: AccountId = AccountId::new([2; 32]);
+
+    fn native_transfer(value: Balance) -> RuntimeCall {
+        RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+            dest: MultiAddress::Id(DEST),
+            value,
+        })
+    }
+
+    fn evm_call(value: Balance) -> RuntimeCall {
+        RuntimeCall::EthereumChecked(pallet_ethereum_checked::Call::signed_transact {
+            tx: CheckedEthereumTx {
+                gas_limit: U256::from(1_000_000),
+                target: H160::repeat_byte(0x01),
+                value: U256::from(value),
+                input: vec![1, 2, 3, 4].try_into().unwrap(),
+                maybe_access_list: None,
+            },
+        })
+    }
+
+    fn wasm_call(value: Balance) -> RuntimeCall {
+        RuntimeCall::Contracts(pallet_contracts::Call::call {
+            dest: MultiAddress::Id(DEST),
+            value,
+            gas_limit: Default::default(),
+            storage_deposit_limit: None,
+            data: vec![1, 2, 3, 4],
+        })
+    }
+
+    #[test]
+    fn transfers_are_inspected() {
+        assert_eq!(
+            CollectiveProxyTransfers::inspect(&native_transfer(10 * AST)),
+            vec![(TreasuryAssetKind::Native, 10 * AST)]
+        );
+        assert_eq!(
+            CollectiveProxyTransfers::inspect(&RuntimeCall::Assets(
+                pallet_assets::Call::transfer {
+                    id: Compact(7),
+                    target: MultiAddress::Id(DEST),
+                    amount: 5,
+                }
+            )),
+            vec![(TreasuryAssetKind::Asset(7), 5)]
+        );
+        assert_eq!(
+            CollectiveProxyTransfers::inspect(&RuntimeCall::Balances(
+                pallet_balances::Call::transfer_all {
+                    dest: MultiAddress::Id(DEST),
+                    keep_alive: false,
+                }
+            )),
+            vec![(TreasuryAssetKind::Native, Balance::MAX)]
+        );
+    }
+
+    #[test]
+    fn contract_call_values_are_inspected() {
+        assert_eq!(
+            CollectiveProxyTransfers::inspect(&evm_call(3 * AST)),
+            vec![(TreasuryAssetKind::Native, 3 * AST)]
+        );
+        assert_eq!(
+            CollectiveProxyTransfers::inspect(&wasm_call(4 * AST)),
+            vec![(TreasuryAssetKind::Native, 4 * AST)]
+        );
+
+        // Contract calls without value don't transfer anything.
+        assert!(CollectiveProxyTransfers::inspect(&evm_call(0)).is_empty());
+        assert!(CollectiveProxyTransfers::inspect(&wasm_call(0)).is_empty());
+    }
+
+    #[test]
+    fn batched_transfers_are_inspected() {
+        let batch = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+            calls: vec![
+                native_transfer(AST),
+                RuntimeCall::Utility(pallet_utility::Call::batch {
+                    calls: vec![evm_call(2 * AST), wasm_call(3 * AST)],
+                }),
+            ],
+        });
+
+        assert_eq!(
+            CollectiveProxyTransfers::inspect(&batch),
+            vec![
+                (TreasuryAssetKind::Native, AST),
+                (TreasuryAssetKind::Native, 2 * AST),
+                (TreasuryAssetKind::Native, 3 * AST),
+            ]
+        );
+        // Both batches & their 3 inner calls go through the origin filter.
+        assert_eq!(CollectiveProxyContractCalls::checked_calls(&batch), 5);
+        assert_eq!(CollectiveProxyContractCalls::checked_calls(&evm_call(0)), 1);
+    }
+}
@@ -0,0 +1,155 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Ethereum checked transactions policy: which accounts may `signed_transact`.
+
+use crate::{AccountId, SignedTransactMembership};
+#[cfg(feature = "runtime-benchmarks")]
+use crate::{Balances, RuntimeOrigin, UnifiedAccounts, AST};
+use frame_support::traits::{Contains, SortedMembers};
+
+/// Accounts allowed to `signed_transact`: the members of `SignedTransactMembership`, which is
+/// managed by the main council.
+///
+/// Allowed accounts transact as their mapped H160 address. Accounts without a mapping are still
+/// rejected by the pallet, since their default H160 address isn't controlled by anyone's
+/// private key.
+pub struct SignedTransactFilter;
+impl Contains<AccountId> for SignedTransactFilter {
+    fn contains(account_id: &AccountId) -> bool {
+        <SignedTransactMembership as SortedMembers<AccountId>>::contains(account_id)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct EthereumCheckedBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_ethereum_checked::BenchmarkHelper<AccountId> for EthereumCheckedBenchmarkHelper {
+    fn signed_transact_account() -> AccountId {
+        use frame_support::traits::fungible::Mutate;
+
+        let account: AccountId = frame_benchmarking::account("signed_transact", 0, 0);
+        let _ = Balances::set_balance(&account, 1_000 * AST);
+        let _ = UnifiedAccounts::claim_default_evm_address(RuntimeOrigin::signed(account.clone()));
+        let _ = SignedTransactMembership::add_member(RuntimeOrigin::root(), account.clone().into());
+        account
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock::new_test_ext, Balance, Balances, EthereumChecked, Runtime, RuntimeOrigin,
+        UnifiedAccounts, AST,
+    };
+    use astar_primitives::{ethereum_checked::CheckedEthereumTx, evm::UnifiedAddressMapper};
+    use frame_support::{assert_noop, assert_ok};
+    use pallet_evm::AddressMapping;
+    use sp_core::{H160, U256};
+
+    const ALICE: AccountId = AccountId::new([1; 32]);
+    const BOB: AccountId = AccountId::new([2; 32]);
+
+    fn endowed_accounts() -> Vec<(AccountId, Balance)> {
+        vec![(ALICE, 1_000 * AST), (BOB, 1_000 * AST)]
+    }
+
+    fn transfer_tx(target: H160) -> CheckedEthereumTx {
+        CheckedEthereumTx {
+            gas_limit: U256::from(100_000),
+            target,
+            value: U256::from(AST),
+            input: Default::default(),
+            maybe_access_list: None,
+        }
+    }
+
+    fn allow(who: &AccountId) {
+        assert_ok!(SignedTransactMembership::add_member(
+            RuntimeOrigin::root(),
+            who.clone().into()
+        ));
+    }
+
+    #[test]
+    fn mapped_accounts_can_signed_transact() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            allow(&ALICE);
+            assert_ok!(UnifiedAccounts::claim_default_evm_address(
+                RuntimeOrigin::signed(ALICE)
+            ));
+            assert!(UnifiedAccounts::to_h160(&ALICE).is_some());
+
+            let target = H160::repeat_byte(0x11);
+            let alice_balance = Balances::free_balance(&ALICE);
+            assert_ok!(EthereumChecked::signed_transact(
+                RuntimeOrigin::signed(ALICE),
+                transfer_tx(target)
+            ));
+
+            // Only the value is transferred, gas is paid by the extrinsic fee.
+            assert_eq!(Balances::free_balance(&ALICE), alice_balance - AST);
+            assert_eq!(
+                Balances::free_balance(&UnifiedAccounts::into_account_id(target)),
+                AST
+            );
+        });
+    }
+
+    #[test]
+    fn unlisted_accounts_cannot_signed_transact() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            assert_ok!(UnifiedAccounts::claim_default_evm_address(
+                RuntimeOrigin::signed(ALICE)
+            ));
+            assert!(!SignedTransactFilter::contains(&ALICE));
+            assert_noop!(
+                EthereumChecked::signed_transact(
+                    RuntimeOrigin::signed(ALICE),
+                    transfer_tx(H160::repeat_byte(0x11))
+                ),
+                pallet_ethereum_checked::Error::<Runtime>::SignedTransactFiltered
+            );
+
+            // removed accounts are rejected again
+            allow(&ALICE);
+            assert!(SignedTransactFilter::contains(&ALICE));
+            assert_ok!(SignedTransactMembership::remove_member(
+                RuntimeOrigin::root(),
+                ALICE.into()
+            ));
+            assert!(!SignedTransactFilter::contains(&ALICE));
+        });
+    }
+
+    #[test]
+    fn unmapped_accounts_cannot_signed_transact() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            allow(&BOB);
+            assert!(SignedTransactFilter::contains(&BOB));
+            assert_noop!(
+                EthereumChecked::signed_transact(
+                    RuntimeOrigin::signed(BOB),
+                    transfer_tx(H160::repeat_byte(0x11))
+                ),
+                pallet_ethereum_checked::Error::<Runtime>::UnmappedAccount
+            );
+        });
+    }
+}
@@ -0,0 +1,470 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Track-based governance: custom origins, referenda tracks and the migration away from
+//! `pallet_democracy`.
+
+use crate::{
+    AccountId, Balances, Democracy, MainTreasuryAccount, Origins, Referenda, Runtime, RuntimeOrigin,
+};
+use frame_support::{
+    storage::with_storage_layer,
+    traits::{schedule::DispatchTime, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency},
+    weights::{constants::RocksDbWeight, Weight},
+};
+use pallet_democracy::ReferendumInfo;
+use pallet_referenda::{BoundedCallOf, WeightInfo};
+use sp_runtime::DispatchResult;
+use sp_std::prelude::*;
+
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+pub mod origins;
+pub use origins::{
+    pallet_custom_origins, RuntimeUpgrader, TreasurySpender, UomiEngineAdmin, WhitelistedCaller,
+};
+mod tracks;
+pub use tracks::TracksInfo;
+
+/// Moves the public proposals and the ongoing referenda of `pallet_democracy` to the root track
+/// of `pallet_referenda`.
+///
+/// Democracy deposits of the proposals are unreserved, and the proposer pays the referenda
+/// submission and decision deposits instead. Ongoing referenda are cancelled in democracy and
+/// submitted again on behalf of the main treasury account, which pays their deposits. Votes are
+/// not carried over, voters unlock their funds with `remove_vote` and vote on the new referendum.
+///
+/// The decision deposit is placed right away, otherwise the migrated referenda would time out
+/// after `UndecidingTimeout`. Proposals that cannot be submitted, e.g. because the proposer can
+/// no longer afford the deposits, are dropped, and such referenda are left to democracy.
+///
+/// Runs once, guarded by the storage version of the `Origins` pallet.
+pub struct MigrateDemocracyProposals;
+impl MigrateDemocracyProposals {
+    /// Submit `proposal` on the root track on behalf of `who` and place its decision deposit.
+    /// Nothing is submitted if either fails.
+    fn submit_on_root_track(
+        who: &AccountId,
+        proposal: BoundedCallOf<Runtime, ()>,
+    ) -> DispatchResult {
+        with_storage_layer(|| {
+            let origin = RuntimeOrigin::signed(who.clone());
+            Referenda::submit(
+                origin.clone(),
+                Box::new(frame_system::RawOrigin::Root.into()),
+                proposal,
+                DispatchTime::After(0),
+            )?;
+            let index = pallet_referenda::ReferendumCount::<Runtime>::get().saturating_sub(1);
+            Referenda::place_decision_deposit(origin, index)
+        })
+    }
+
+    fn submit_on_root_track_weight() -> Weight {
+        type ReferendaWeight = <Runtime as pallet_referenda::Config>::WeightInfo;
+        ReferendaWeight::submit()
+            .saturating_add(ReferendaWeight::place_decision_deposit_preparing())
+            .saturating_add(RocksDbWeight::get().reads(1))
+    }
+}
+
+impl OnRuntimeUpgrade for MigrateDemocracyProposals {
+    fn on_runtime_upgrade() -> Weight {
+        if Origins::on_chain_storage_version() >= pallet_custom_origins::STORAGE_VERSION {
+            return RocksDbWeight::get().reads(1);
+        }
+
+        // storage version & public proposals
+        let mut weight = RocksDbWeight::get().reads_writes(2, 2);
+
+        let proposals = pallet_democracy::PublicProps::<Runtime>::take();
+        let proposals_count = proposals.len();
+        for (index, proposal, proposer) in proposals {
+            if let Some((depositors, deposit)) = pallet_democracy::DepositOf::<Runtime>::take(index)
+            {
+                for depositor in depositors.iter() {
+                    Balances::unreserve(depositor, deposit);
+                }
+                let accounts = depositors.len() as u64;
+                weight.saturating_accrue(
+                    RocksDbWeight::get().reads_writes(1 + accounts, 1 + accounts),
+                );
+            }
+
+            if let Err(e) = Self::submit_on_root_track(&proposer, proposal) {
+                log::error!("Failed to migrate democracy proposal {index} of {proposer:?}: {e:?}");
+            }
+            weight.saturating_accrue(Self::submit_on_root_track_weight());
+        }
+
+        let referenda: Vec<_> = pallet_democracy::ReferendumInfoOf::<Runtime>::iter().collect();
+        weight.saturating_accrue(RocksDbWeight::get().reads(referenda.len() as u64));
+        let treasury = MainTreasuryAccount::get();
+        let mut referenda_count = 0;
+        for (index, info) in referenda {
+            let ReferendumInfo::Ongoing(status) = info else {
+                continue;
+            };
+            match Self::submit_on_root_track(&treasury, status.proposal) {
+                Ok(()) => {
+                    Democracy::internal_cancel_referendum(index);
+                    referenda_count += 1;
+                }
+                Err(e) => log::error!("Failed to migrate democracy referendum {index}: {e:?}"),
+            }
+            weight.saturating_accrue(
+                Self::submit_on_root_track_weight().saturating_add(RocksDbWeight::get().writes(2)),
+            );
+        }
+
+        pallet_custom_origins::STORAGE_VERSION.put::<Origins>();
+        log::info!(
+            "Migrated {proposals_count} democracy proposals and {referenda_count} referenda to the referenda tracks"
+        );
+
+        weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let proposals = pallet_democracy::PublicProps::<Runtime>::get().len() as u32;
+        let ongoing = pallet_democracy::ReferendumInfoOf::<Runtime>::iter_values()
+            .filter(|info| matches!(info, ReferendumInfo::Ongoing(_)))
+            .count() as u32;
+        let referenda = pallet_referenda::ReferendumCount::<Runtime>::get();
+        Ok((proposals, ongoing, referenda).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let (proposals, ongoing, referenda) = <(u32, u32, u32)>::decode(&mut &state[..])
+            .map_err(|_| "Failed to decode pre-upgrade state")?;
+
+        frame_support::ensure!(
+            Origins::on_chain_storage_version() == pallet_custom_origins::STORAGE_VERSION,
+            "Origins storage version was not updated"
+        );
+        frame_support::ensure!(
+            pallet_democracy::PublicProps::<Runtime>::get().is_empty(),
+            "Democracy public proposals are left after the migration"
+        );
+
+        let count = pallet_referenda::ReferendumCount::<Runtime>::get();
+        frame_support::ensure!(
+            count <= referenda + proposals + ongoing,
+            "More referenda were submitted than there were democracy proposals and referenda"
+        );
+        for index in referenda..count {
+            match pallet_referenda::ReferendumInfoFor::<Runtime>::get(index) {
+                Some(pallet_referenda::ReferendumInfo::Ongoing(status)) => frame_support::ensure!(
+                    status.decision_deposit.is_some(),
+                    "Migrated referendum has no decision deposit"
+                ),
+                _ => return Err("Migrated referendum is not ongoing".into()),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock::new_test_ext, Balance, BlockNumber, ConvictionVoting, OriginCaller, Preimage,
+        RuntimeCall, Scheduler, Sudo, System, AST, VERSION,
+    };
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{EnsureOrigin, OnInitialize, StorageVersion, StorePreimage},
+    };
+    use pallet_custom_origins::{Origin, MAX_TREASURY_SPEND};
+    use pallet_referenda::TracksInfo as _;
+    use parity_scale_codec::Encode;
+    use sp_runtime::traits::BadOrigin;
+
+    const ALICE: AccountId = AccountId::new([1; 32]);
+    const BOB: AccountId = AccountId::new([2; 32]);
+    const ROOT_DECISION_DEPOSIT: Balance = 1_000 * AST;
+    const SUBMISSION_DEPOSIT: Balance = 10 * AST;
+
+    fn endowed_accounts() -> Vec<(AccountId, Balance)> {
+        vec![
+            (ALICE, 10_000 * AST),
+            (BOB, 100 * AST),
+            (MainTreasuryAccount::get(), 10_000 * AST),
+        ]
+    }
+
+    fn remark_proposal() -> BoundedCallOf<Runtime, ()> {
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![1] });
+        <Preimage as StorePreimage>::bound(call).unwrap()
+    }
+
+    /// Runs the scheduler, which drives both the referenda alarms and the enactments, up to `n`.
+    fn run_to_block(n: BlockNumber) {
+        while System::block_number() < n {
+            System::set_block_number(System::block_number() + 1);
+            Scheduler::on_initialize(System::block_number());
+        }
+    }
+
+    /// Reads back the runtime version of any code as the current one with a bumped spec version.
+    struct MockReadRuntimeVersion;
+    impl sp_core::traits::ReadRuntimeVersion for MockReadRuntimeVersion {
+        fn read_runtime_version(
+            &self,
+            _wasm_code: &[u8],
+            _ext: &mut dyn sp_externalities::Externalities,
+        ) -> Result<Vec<u8>, String> {
+            let mut version = VERSION;
+            version.spec_version += 1;
+            Ok(version.encode())
+        }
+    }
+
+    #[test]
+    fn tracks_are_sorted_by_id() {
+        let tracks = TracksInfo::tracks();
+        assert!(tracks.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn origins_are_mapped_to_their_tracks() {
+        let track = |origin: OriginCaller| {
+            TracksInfo::track_for(&origin).map(|id| TracksInfo::info(id).unwrap().name)
+        };
+
+        assert_eq!(track(frame_system::RawOrigin::Root.into()), Ok("root"));
+        assert_eq!(
+            track(Origin::WhitelistedCaller.into()),
+            Ok("whitelisted_caller")
+        );
+        assert_eq!(
+            track(Origin::TreasurySpender.into()),
+            Ok("treasury_spender")
+        );
+        assert_eq!(
+            track(Origin::RuntimeUpgrader.into()),
+            Ok("runtime_upgrader")
+        );
+        assert_eq!(
+            track(Origin::UomiEngineAdmin.into()),
+            Ok("uomi_engine_admin")
+        );
+
+        // signed and none origins can't submit to any track
+        assert_eq!(
+            track(frame_system::RawOrigin::Signed(ALICE).into()),
+            Err(())
+        );
+        assert_eq!(track(frame_system::RawOrigin::None.into()), Err(()));
+    }
+
+    #[test]
+    fn custom_origins_only_ensure_their_own_origin() {
+        assert_eq!(
+            TreasurySpender::try_origin(RuntimeOrigin::from(Origin::TreasurySpender)).ok(),
+            Some(MAX_TREASURY_SPEND)
+        );
+        assert!(
+            WhitelistedCaller::try_origin(RuntimeOrigin::from(Origin::WhitelistedCaller)).is_ok()
+        );
+        assert!(RuntimeUpgrader::try_origin(RuntimeOrigin::from(Origin::RuntimeUpgrader)).is_ok());
+        assert!(UomiEngineAdmin::try_origin(RuntimeOrigin::from(Origin::UomiEngineAdmin)).is_ok());
+
+        assert!(TreasurySpender::try_origin(RuntimeOrigin::from(Origin::UomiEngineAdmin)).is_err());
+        assert!(
+            RuntimeUpgrader::try_origin(RuntimeOrigin::from(Origin::WhitelistedCaller)).is_err()
+        );
+        assert!(WhitelistedCaller::try_origin(RuntimeOrigin::root()).is_err());
+        assert!(UomiEngineAdmin::try_origin(RuntimeOrigin::signed(ALICE)).is_err());
+    }
+
+    #[test]
+    fn upgrade_runtime_works_for_runtime_upgrader() {
+        let mut ext = new_test_ext(endowed_accounts());
+        ext.register_extension(sp_core::traits::ReadRuntimeVersionExt::new(
+            MockReadRuntimeVersion,
+        ));
+        ext.execute_with(|| {
+            let code = vec![1, 2, 3];
+
+            assert_noop!(
+                Origins::upgrade_runtime(RuntimeOrigin::signed(ALICE), code.clone()),
+                BadOrigin
+            );
+            assert_noop!(
+                Origins::upgrade_runtime(Origin::WhitelistedCaller.into(), code.clone()),
+                BadOrigin
+            );
+
+            assert_ok!(Origins::upgrade_runtime(
+                Origin::RuntimeUpgrader.into(),
+                code.clone()
+            ));
+            System::assert_last_event(frame_system::Event::<Runtime>::CodeUpdated.into());
+            assert_eq!(
+                sp_io::storage::get(sp_core::storage::well_known_keys::CODE).map(|c| c.to_vec()),
+                Some(code)
+            );
+        });
+    }
+
+    #[test]
+    fn democracy_proposals_are_migrated() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            assert_ok!(Democracy::propose(
+                RuntimeOrigin::signed(ALICE),
+                remark_proposal(),
+                10 * AST
+            ));
+            assert_eq!(Balances::reserved_balance(&ALICE), 10 * AST);
+
+            MigrateDemocracyProposals::on_runtime_upgrade();
+
+            assert!(pallet_democracy::PublicProps::<Runtime>::get().is_empty());
+            assert_eq!(
+                Origins::on_chain_storage_version(),
+                pallet_custom_origins::STORAGE_VERSION
+            );
+
+            // democracy deposit is swapped for the referenda deposits
+            assert_eq!(
+                Balances::reserved_balance(&ALICE),
+                SUBMISSION_DEPOSIT + ROOT_DECISION_DEPOSIT
+            );
+            let Some(pallet_referenda::ReferendumInfo::Ongoing(status)) =
+                pallet_referenda::ReferendumInfoFor::<Runtime>::get(0)
+            else {
+                panic!("referendum should be ongoing");
+            };
+            assert_eq!(status.track, 0);
+            assert_eq!(status.proposal, remark_proposal());
+            assert_eq!(status.decision_deposit.map(|d| d.who), Some(ALICE));
+        });
+    }
+
+    #[test]
+    fn ongoing_democracy_referenda_are_migrated() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            let index = Democracy::internal_start_referendum(
+                remark_proposal(),
+                pallet_democracy::VoteThreshold::SuperMajorityApprove,
+                0,
+            );
+
+            MigrateDemocracyProposals::on_runtime_upgrade();
+
+            assert!(pallet_democracy::ReferendumInfoOf::<Runtime>::get(index).is_none());
+
+            // the treasury pays the deposits of migrated referenda
+            let treasury = MainTreasuryAccount::get();
+            assert_eq!(
+                Balances::reserved_balance(&treasury),
+                SUBMISSION_DEPOSIT + ROOT_DECISION_DEPOSIT
+            );
+            let Some(pallet_referenda::ReferendumInfo::Ongoing(status)) =
+                pallet_referenda::ReferendumInfoFor::<Runtime>::get(0)
+            else {
+                panic!("referendum should be ongoing");
+            };
+            assert_eq!(status.proposal, remark_proposal());
+            assert_eq!(status.decision_deposit.map(|d| d.who), Some(treasury));
+        });
+    }
+
+    #[test]
+    fn unaffordable_democracy_proposals_are_dropped() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            assert_ok!(Democracy::propose(
+                RuntimeOrigin::signed(BOB),
+                remark_proposal(),
+                10 * AST
+            ));
+
+            MigrateDemocracyProposals::on_runtime_upgrade();
+
+            // BOB can't afford the decision deposit, so nothing is submitted
+            assert!(pallet_democracy::PublicProps::<Runtime>::get().is_empty());
+            assert_eq!(pallet_referenda::ReferendumCount::<Runtime>::get(), 0);
+            assert_eq!(Balances::reserved_balance(&BOB), 0);
+        });
+    }
+
+    #[test]
+    fn democracy_migration_runs_once() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            StorageVersion::new(1).put::<Origins>();
+            assert_ok!(Democracy::propose(
+                RuntimeOrigin::signed(ALICE),
+                remark_proposal(),
+                10 * AST
+            ));
+
+            MigrateDemocracyProposals::on_runtime_upgrade();
+
+            assert_eq!(pallet_democracy::PublicProps::<Runtime>::get().len(), 1);
+            assert_eq!(pallet_referenda::ReferendumCount::<Runtime>::get(), 0);
+            assert_eq!(Balances::reserved_balance(&ALICE), 10 * AST);
+        });
+    }
+
+    #[test]
+    fn root_track_referendum_can_remove_sudo_key() {
+        new_test_ext(vec![(ALICE, 100_000 * AST), (BOB, 100 * AST)]).execute_with(|| {
+            assert_ok!(Sudo::set_key(RuntimeOrigin::root(), BOB.into()));
+            assert_eq!(Sudo::key(), Some(BOB));
+
+            let call = RuntimeCall::Sudo(pallet_sudo::Call::remove_key {});
+            assert_ok!(Referenda::submit(
+                RuntimeOrigin::signed(ALICE),
+                Box::new(frame_system::RawOrigin::Root.into()),
+                <Preimage as StorePreimage>::bound(call).unwrap(),
+                DispatchTime::After(0),
+            ));
+            assert_ok!(Referenda::place_decision_deposit(
+                RuntimeOrigin::signed(ALICE),
+                0
+            ));
+            assert_ok!(ConvictionVoting::vote(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                pallet_conviction_voting::AccountVote::Standard {
+                    vote: pallet_conviction_voting::Vote {
+                        aye: true,
+                        conviction: pallet_conviction_voting::Conviction::Locked1x,
+                    },
+                    balance: 90_000 * AST,
+                },
+            ));
+
+            // the referendum is confirmed and enacted within its decision period
+            let track = TracksInfo::info(0).unwrap();
+            run_to_block(System::block_number() + track.prepare_period + track.decision_period);
+            assert!(matches!(
+                pallet_referenda::ReferendumInfoFor::<Runtime>::get(0),
+                Some(pallet_referenda::ReferendumInfo::Approved(..))
+            ));
+            assert_eq!(Sudo::key(), None);
+        });
+    }
+}
@@ -0,0 +1,107 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Custom origins for the referenda tracks.
+
+pub use pallet_custom_origins::*;
+
+#[frame_support::pallet]
+pub mod pallet_custom_origins {
+    use crate::{Balance, AST};
+    use frame_support::pallet_prelude::*;
+    use frame_system::{pallet_prelude::*, WeightInfo};
+    use sp_std::prelude::*;
+
+    /// Maximum amount the `TreasurySpender` origin can spend from the treasury in one go.
+    pub const MAX_TREASURY_SPEND: Balance = 100_000 * AST;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Origin allowed to enact a runtime upgrade through [`Pallet::upgrade_runtime`].
+        type RuntimeUpgradeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    }
+
+    /// Storage version at which the democracy proposals and referenda were moved to the
+    /// referenda tracks by [`crate::governance::MigrateDemocracyProposals`].
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+    #[pallet::origin]
+    pub enum Origin {
+        /// Origin able to dispatch a call that was whitelisted by the technical committee.
+        WhitelistedCaller,
+        /// Origin able to spend up to [`MAX_TREASURY_SPEND`] from the treasury at once.
+        TreasurySpender,
+        /// Origin able to upgrade the runtime.
+        RuntimeUpgrader,
+        /// Origin able to administer the UOMI engine.
+        UomiEngineAdmin,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set the new runtime code, dispatching `frame_system::set_code` as `Root`.
+        ///
+        /// Lets the runtime upgrade track enact an upgrade without going through the root track.
+        #[pallet::call_index(0)]
+        #[pallet::weight((T::SystemWeightInfo::set_code(), DispatchClass::Operational))]
+        pub fn upgrade_runtime(origin: OriginFor<T>, code: Vec<u8>) -> DispatchResultWithPostInfo {
+            T::RuntimeUpgradeOrigin::ensure_origin(origin)?;
+            frame_system::Pallet::<T>::set_code(frame_system::RawOrigin::Root.into(), code)
+        }
+    }
+
+    macro_rules! decl_unit_ensures {
+        ( $name:ident: $success_type:ty = $success:expr ) => {
+            pub struct $name;
+            impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for $name {
+                type Success = $success_type;
+                fn try_origin(o: O) -> Result<Self::Success, O> {
+                    o.into().and_then(|o| match o {
+                        Origin::$name => Ok($success),
+                        r => Err(O::from(r)),
+                    })
+                }
+                #[cfg(feature = "runtime-benchmarks")]
+                fn try_successful_origin() -> Result<O, ()> {
+                    Ok(O::from(Origin::$name))
+                }
+            }
+        };
+        ( $name:ident ) => { decl_unit_ensures! { $name: () = () } };
+        ( $name:ident: $success_type:ty = $success:expr, $( $rest:tt )* ) => {
+            decl_unit_ensures! { $name: $success_type = $success }
+            decl_unit_ensures! { $( $rest )* }
+        };
+        ( $name:ident, $( $rest:tt )* ) => {
+            decl_unit_ensures! { $name }
+            decl_unit_ensures! { $( $rest )* }
+        };
+        () => {}
+    }
+    decl_unit_ensures!(
+        WhitelistedCaller,
+        TreasurySpender: Balance = MAX_TREASURY_SPEND,
+        RuntimeUpgrader,
+        UomiEngineAdmin,
+    );
+}
@@ -0,0 +1,146 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Track configurations for the referenda pallet.
+
+use super::origins;
+use crate::{Balance, BlockNumber, RuntimeOrigin, AST, MINUTES};
+use pallet_referenda::Curve;
+use sp_runtime::FixedI64;
+
+const fn percent(x: i32) -> FixedI64 {
+    FixedI64::from_rational(x as u128, 100)
+}
+
+const APP_ROOT: Curve = Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
+const SUP_ROOT: Curve = Curve::make_linear(28, 28, percent(0), percent(50));
+const APP_WHITELISTED_CALLER: Curve =
+    Curve::make_reciprocal(16, 28 * 24, percent(96), percent(50), percent(100));
+const SUP_WHITELISTED_CALLER: Curve =
+    Curve::make_reciprocal(1, 28, percent(20), percent(5), percent(50));
+const APP_TREASURY_SPENDER: Curve = Curve::make_linear(23, 28, percent(50), percent(100));
+const SUP_TREASURY_SPENDER: Curve =
+    Curve::make_reciprocal(16, 28, percent(1), percent(0), percent(50));
+const APP_RUNTIME_UPGRADER: Curve =
+    Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
+const SUP_RUNTIME_UPGRADER: Curve = Curve::make_linear(28, 28, percent(0), percent(50));
+const APP_UOMI_ENGINE_ADMIN: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
+const SUP_UOMI_ENGINE_ADMIN: Curve =
+    Curve::make_reciprocal(12, 28, percent(1), percent(0), percent(50));
+
+const TRACKS_DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 5] = [
+    (
+        0,
+        pallet_referenda::TrackInfo {
+            name: "root",
+            max_deciding: 1,
+            decision_deposit: 1_000 * AST,
+            prepare_period: 2 * MINUTES,
+            decision_period: 20 * MINUTES,
+            confirm_period: 5 * MINUTES,
+            min_enactment_period: 2 * MINUTES,
+            min_approval: APP_ROOT,
+            min_support: SUP_ROOT,
+        },
+    ),
+    (
+        1,
+        pallet_referenda::TrackInfo {
+            name: "whitelisted_caller",
+            max_deciding: 10,
+            decision_deposit: 100 * AST,
+            prepare_period: 1 * MINUTES,
+            decision_period: 20 * MINUTES,
+            confirm_period: 1 * MINUTES,
+            min_enactment_period: 1 * MINUTES,
+            min_approval: APP_WHITELISTED_CALLER,
+            min_support: SUP_WHITELISTED_CALLER,
+        },
+    ),
+    (
+        2,
+        pallet_referenda::TrackInfo {
+            name: "treasury_spender",
+            max_deciding: 10,
+            decision_deposit: 500 * AST,
+            prepare_period: 2 * MINUTES,
+            decision_period: 20 * MINUTES,
+            confirm_period: 5 * MINUTES,
+            min_enactment_period: 2 * MINUTES,
+            min_approval: APP_TREASURY_SPENDER,
+            min_support: SUP_TREASURY_SPENDER,
+        },
+    ),
+    (
+        3,
+        pallet_referenda::TrackInfo {
+            name: "runtime_upgrader",
+            max_deciding: 1,
+            decision_deposit: 1_000 * AST,
+            prepare_period: 2 * MINUTES,
+            decision_period: 20 * MINUTES,
+            confirm_period: 5 * MINUTES,
+            min_enactment_period: 5 * MINUTES,
+            min_approval: APP_RUNTIME_UPGRADER,
+            min_support: SUP_RUNTIME_UPGRADER,
+        },
+    ),
+    (
+        4,
+        pallet_referenda::TrackInfo {
+            name: "uomi_engine_admin",
+            max_deciding: 10,
+            decision_deposit: 100 * AST,
+            prepare_period: 1 * MINUTES,
+            decision_period: 10 * MINUTES,
+            confirm_period: 2 * MINUTES,
+            min_enactment_period: 1 * MINUTES,
+            min_approval: APP_UOMI_ENGINE_ADMIN,
+            min_support: SUP_UOMI_ENGINE_ADMIN,
+        },
+    ),
+];
+
+pub struct TracksInfo;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
+    type Id = u16;
+    type RuntimeOrigin = <RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin;
+
+    fn tracks() -> &'static [(Self::Id, pallet_referenda::TrackInfo<Balance, BlockNumber>)] {
+        &TRACKS_DATA[..]
+    }
+
+    fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+        if let Ok(system_origin) = frame_system::RawOrigin::try_from(id.clone()) {
+            match system_origin {
+                frame_system::RawOrigin::Root => Ok(0),
+                _ => Err(()),
+            }
+        } else if let Ok(custom_origin) = origins::Origin::try_from(id.clone()) {
+            match custom_origin {
+                origins::Origin::WhitelistedCaller => Ok(1),
+                origins::Origin::TreasurySpender => Ok(2),
+                origins::Origin::RuntimeUpgrader => Ok(3),
+                origins::Origin::UomiEngineAdmin => Ok(4),
+            }
+        } else {
+            Err(())
+        }
+    }
+}
+pallet_referenda::impl_tracksinfo_get!(TracksInfo, Balance, BlockNumber);
@@ -29,10 +29,9 @@ use frame_support::{
     genesis_builder_helper::{build_config, create_default_config},
     parameter_types,
     traits::{
-        fungible::HoldConsideration,
-        tokens::{PayFromAccount, UnityAssetBalanceConversion},
-        AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, Currency, EqualPrivilegeOnly,
-        FindAuthor, Get, InstanceFilter, LinearStoragePrice, Nothing, OnFinalize, WithdrawReasons,
+        fungible::HoldConsideration, tokens::currency::ActiveIssuanceOf, AsEnsureOriginWithArg,
+        ConstU128, ConstU32, ConstU64, Currency, EitherOf, EqualPrivilegeOnly, FindAuthor, Get,
+        InstanceFilter, LinearStoragePrice, Nothing, OnFinalize, OnUnbalanced, WithdrawReasons,
     },
     weights::{
         constants::{ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
@@ -43,10 +42,10 @@ use frame_support::{
 };
 use frame_system::{
     limits::{BlockLength, BlockWeights},
-    EnsureRoot, EnsureSigned,
+    EnsureRoot, EnsureRootWithSuccess, EnsureSigned, EnsureWithSuccess,
 };
 use pallet_ethereum::PostLogContent;
-use pallet_evm::{FeeCalculator, GasWeightMapping, Runner};
+use pallet_evm::{FeeCalculator, GasWeightMapping, OnChargeEVMTransaction, Runner};
 use pallet_evm_precompile_assets_erc20::AddressToAssetId;
 use pallet_grandpa::{fg_primitives, AuthorityList as GrandpaAuthorityList};
 use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
@@ -56,8 +55,8 @@ use sp_core::{crypto::KeyTypeId, ConstBool, OpaqueMetadata, H160, H256, U256};
 use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{
-        AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto,
-        DispatchInfoOf, Dispatchable, IdentityLookup, NumberFor, PostDispatchInfoOf,
+        AccountIdConversion, BlakeTwo256, Block as BlockT, ConvertInto, DispatchInfoOf,
+        Dispatchable, IdentityLookup, NumberFor, PostDispatchInfoOf, StaticLookup,
         UniqueSaturatedInto,
     },
     transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
@@ -69,15 +68,16 @@ use astar_primitives::{
     inflation::{
         CycleConfiguration, EraNumber, PeriodNumber,
     },
-    evm::{EvmRevertCodeHandler, HashedDefaultMappings},
+    evm::{EvmRevertCodeHandler, HashedDefaultMappings, UnifiedAddressMapper},
     governance::{
         CommunityCouncilCollectiveInst, CommunityCouncilMembershipInst, CommunityTreasuryInst,
         EnsureRootOrAllMainCouncil, EnsureRootOrAllTechnicalCommittee,
         EnsureRootOrTwoThirdsCommunityCouncil, EnsureRootOrTwoThirdsMainCouncil,
         EnsureRootOrTwoThirdsTechnicalCommittee, MainCouncilCollectiveInst,
-        MainCouncilMembershipInst, MainTreasuryInst, TechnicalCommitteeCollectiveInst,
-        TechnicalCommitteeMembershipInst,
+        MainCouncilMembershipInst, MainTreasuryInst, SignedTransactMembershipInst,
+        TechnicalCommitteeCollectiveInst, TechnicalCommitteeMembershipInst,
     },
+    treasury::{NativeOrAssetBalanceConversion, PayFromAccountOrAsset, TreasuryAssetKind},
     Address, AssetId, Balance, BlockNumber, Hash, Header, Nonce,
 };
 
@@ -116,7 +116,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_version: 1,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -136,6 +136,17 @@ pub use chain_extensions::LocalChainExtensions;
 
 mod weights;
 
+pub mod collective_proxy;
+pub mod ethereum_checked;
+pub mod governance;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod treasury;
+pub mod unified_accounts;
+use governance::{pallet_custom_origins, TreasurySpender, UomiEngineAdmin, WhitelistedCaller};
+
 /// Constant values used within the runtime.
 pub const MICROAST: Balance = 1_000_000_000_000;
 pub const MILLIAST: Balance = 1_000 * MICROAST;
@@ -220,7 +231,7 @@ impl frame_system::Config for Runtime {
     /// The aggregated dispatch type that is available for extrinsics.
     type RuntimeCall = RuntimeCall;
     /// The lookup mechanism to get account ID from whatever is passed in dispatchers.
-    type Lookup = (AccountIdLookup<AccountId, ()>, UnifiedAccounts);
+    type Lookup = pallet_unified_accounts::UnifiedAccountsLookup<Runtime>;
     /// The nonce type for storing how many extrinsics an account has signed.
     type Nonce = Nonce;
     /// The type for blocks.
@@ -397,7 +408,7 @@ impl WeightToFeePolynomial for WeightToFee {
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+    type OnChargeTransaction = CurrencyAdapter<Balances, FeeDistribution>;
     type WeightToFee = WeightToFee;
     type OperationalFeeMultiplier = OperationalFeeMultiplier;
     type FeeMultiplierUpdate = TargetedFeeAdjustment<
@@ -425,6 +436,28 @@ impl Get<Multiplier> for AdjustmentFactorGetter {
     }
 }
 
+/// EVM gas usage of the current block, as tracked by `pallet-ethereum`.
+///
+/// Must be read after `pallet-ethereum` has finalized the current block.
+pub struct EthereumBlockGasUsage;
+impl pallet_dynamic_evm_base_fee::BlockGasUsage for EthereumBlockGasUsage {
+    fn gas_used() -> U256 {
+        pallet_ethereum::CurrentBlock::<Runtime>::get()
+            .map(|block| block.header.gas_used)
+            .unwrap_or_default()
+    }
+
+    fn gas_target() -> U256 {
+        let block_gas_limit: u128 = BlockGasLimit::get().unique_saturated_into();
+        U256::from(TargetBlockFullness::get() * block_gas_limit)
+    }
+}
+
+parameter_types! {
+    pub const EvmBaseFeeMode: pallet_dynamic_evm_base_fee::BaseFeeMode =
+        pallet_dynamic_evm_base_fee::BaseFeeMode::AdjustmentFactor;
+}
+
 impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DefaultBaseFeePerGas = DefaultBaseFeePerGas;
@@ -433,6 +466,8 @@ impl pallet_dynamic_evm_base_fee::Config for Runtime {
     type AdjustmentFactor = AdjustmentFactorGetter;
     type WeightFactor = WeightFeeFactor;
     type StepLimitRatio = StepLimitRatio;
+    type BaseFeeMode = EvmBaseFeeMode;
+    type BlockGasUsage = EthereumBlockGasUsage;
     type WeightInfo = pallet_dynamic_evm_base_fee::weights::SubstrateWeight<Runtime>;
 }
 
@@ -460,8 +495,36 @@ impl pallet_inflation::PayoutPerBlock<NegativeImbalance> for InflationPayoutPerB
         Balances::resolve_creating(&TreasuryPalletId::get().into_account_truncating(), reward);
     }
 
-    fn collators(_reward: NegativeImbalance) {
-        // no collators for local dev node
+    fn collators(reward: NegativeImbalance) {
+        // Paid to the Aura block author, or to the treasury if the author cannot be found.
+        pallet_inflation::ToBlockAuthor::<
+            Runtime,
+            FindAuthorAccountId<Aura>,
+            pallet_inflation::ToPot<Runtime, TreasuryPalletId>,
+        >::on_unbalanced(reward);
+    }
+}
+
+/// No dApp staking on the local dev node, so the reward pools are routed to the treasuries instead.
+pub struct InflationPayoutPerEra;
+impl pallet_inflation::PayoutPerEra<NegativeImbalance> for InflationPayoutPerEra {
+    fn dapps(reward: NegativeImbalance) {
+        Balances::resolve_creating(
+            &CommunityTreasuryPalletId::get().into_account_truncating(),
+            reward,
+        );
+    }
+
+    fn base_stakers(reward: NegativeImbalance) {
+        Balances::resolve_creating(&TreasuryPalletId::get().into_account_truncating(), reward);
+    }
+
+    fn adjustable_stakers(_reward: NegativeImbalance) {
+        // Adjustable part depends on the staking rate, which is always zero here - burned.
+    }
+
+    fn bonus(reward: NegativeImbalance) {
+        Balances::resolve_creating(&TreasuryPalletId::get().into_account_truncating(), reward);
     }
 }
 
@@ -487,6 +550,7 @@ impl CycleConfiguration for InflationCycleConfig {
 impl pallet_inflation::Config for Runtime {
     type Currency = Balances;
     type PayoutPerBlock = InflationPayoutPerBlock;
+    type PayoutPerEra = InflationPayoutPerEra;
     type CycleConfiguration = InflationCycleConfig;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_inflation::weights::SubstrateWeight<Runtime>;
@@ -502,14 +566,26 @@ impl pallet_utility::Config for Runtime {
 parameter_types! {
     // 2 storage items with value size 20 and 32
     pub const AccountMappingStorageFee: u128 = deposit(2, 32 + 20);
+    pub const UnlinkDelay: BlockNumber = 2 * DAYS;
+    /// Gas limit of EIP-1271 `isValidSignature` calls for smart contract wallet claims.
+    pub const ContractSignatureGasLimit: u64 = 500_000;
 }
 
 impl pallet_unified_accounts::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type UnlinkDelay = UnlinkDelay;
+    type UnlinkRecoveryOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+    type AssetId = AssetId;
+    type ClaimAssetsMigrator = unified_accounts::UnifiedAccountsClaimMigrator;
+    type MaxClaimAssets = ConstU32<16>;
+    type ContractSignatureValidator =
+        pallet_unified_accounts::EvmContractSignatureValidator<Self, ContractSignatureGasLimit>;
+    type MaxContractSignatureLength = ConstU32<1024>;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
@@ -526,7 +602,27 @@ impl pallet_ethereum_checked::Config for Runtime {
     type ValidatedTransaction = pallet_ethereum::ValidatedTransaction<Self>;
     type AddressMapper = UnifiedAccounts;
     type XcmTransactOrigin = pallet_ethereum_checked::EnsureXcmEthereumTx<AccountId>;
+    type SignedTransactFilter = ethereum_checked::SignedTransactFilter;
     type WeightInfo = pallet_ethereum_checked::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ethereum_checked::EthereumCheckedBenchmarkHelper;
+}
+
+parameter_types! {
+    pub const SignedTransactMaxMembers: u32 = 100;
+}
+
+impl pallet_membership::Config<SignedTransactMembershipInst> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AddOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type RemoveOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type SwapOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type ResetOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type PrimeOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type MembershipInitialized = ();
+    type MembershipChanged = ();
+    type MaxMembers = SignedTransactMaxMembers;
+    type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_xvm::Config for Runtime {
@@ -561,6 +657,72 @@ impl<F: FindAuthor<u32>> FindAuthor<H160> for FindAuthorTruncated<F> {
     }
 }
 
+/// Block author's native account, i.e. the account of the Aura authority key found by `F`.
+///
+/// Collators are expected to use their account key as Aura key, as the dev chain spec does.
+pub struct FindAuthorAccountId<F>(sp_std::marker::PhantomData<F>);
+impl<F: FindAuthor<u32>> FindAuthor<AccountId> for FindAuthorAccountId<F> {
+    fn find_author<'a, I>(digests: I) -> Option<AccountId>
+    where
+        I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+    {
+        let author_index = F::find_author(digests)?;
+        Aura::authorities()
+            .get(author_index as usize)
+            .map(|authority| AccountId::from(sp_core::sr25519::Public::from(authority.clone())))
+    }
+}
+
+parameter_types! {
+    pub const BaseFeeBurnRatio: Perbill = Perbill::from_percent(80);
+}
+
+impl pallet_fee_distribution::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BaseFeeBurnRatio = BaseFeeBurnRatio;
+    type TreasuryPalletId = TreasuryPalletId;
+    type FindAuthor = FindAuthorAccountId<Aura>;
+}
+
+type EvmCurrencyAdapter = pallet_evm::EVMCurrencyAdapter<Balances, FeeDistribution>;
+
+/// EVM transaction fee handler, distributing the base fee & tips via `FeeDistribution`,
+/// the same way as for the native transactions.
+pub struct EvmFeeHandler;
+impl OnChargeEVMTransaction<Runtime> for EvmFeeHandler {
+    type LiquidityInfo = <EvmCurrencyAdapter as OnChargeEVMTransaction<Runtime>>::LiquidityInfo;
+
+    fn withdraw_fee(
+        who: &H160,
+        fee: U256,
+    ) -> Result<Self::LiquidityInfo, pallet_evm::Error<Runtime>> {
+        <EvmCurrencyAdapter as OnChargeEVMTransaction<Runtime>>::withdraw_fee(who, fee)
+    }
+
+    fn correct_and_deposit_fee(
+        who: &H160,
+        corrected_fee: U256,
+        base_fee: U256,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Self::LiquidityInfo {
+        // Base fee is handed over to `FeeDistribution`, the tip is returned
+        <EvmCurrencyAdapter as OnChargeEVMTransaction<Runtime>>::correct_and_deposit_fee(
+            who,
+            corrected_fee,
+            base_fee,
+            already_withdrawn,
+        )
+    }
+
+    fn pay_priority_fee(tip: Self::LiquidityInfo) {
+        // Unlike `EVMCurrencyAdapter`, tips go through `FeeDistribution` to be accounted for
+        if let Some(tip) = tip {
+            FeeDistribution::distribute_tips(tip);
+        }
+    }
+}
+
 parameter_types! {
     /// Ethereum-compatible chain_id:
     /// * Local: 0x1111 = 4369
@@ -594,7 +756,7 @@ impl pallet_evm::Config for Runtime {
     type PrecompilesType = Precompiles;
     type PrecompilesValue = PrecompilesValue;
     type ChainId = ChainId;
-    type OnChargeTransaction = pallet_evm::EVMCurrencyAdapter<Balances, ()>;
+    type OnChargeTransaction = EvmFeeHandler;
     type BlockGasLimit = BlockGasLimit;
     type Timestamp = Timestamp;
     type OnCreate = ();
@@ -898,6 +1060,7 @@ impl pallet_collective::Config<MainCouncilCollectiveInst> for Runtime {
 impl pallet_uomi_engine::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_uomi_engine::weights::SubstrateWeight<Runtime>;
+	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, UomiEngineAdmin>;
 }
 
 impl pallet_collective::Config<TechnicalCommitteeCollectiveInst> for Runtime {
@@ -973,9 +1136,91 @@ impl pallet_democracy::Config for Runtime {
     type WeightInfo = pallet_democracy::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_conviction_voting::Config for Runtime {
+    type WeightInfo = pallet_conviction_voting::weights::SubstrateWeight<Runtime>;
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Polls = Referenda;
+    type MaxTurnout = ActiveIssuanceOf<Balances, Self::AccountId>;
+    type MaxVotes = ConstU32<512>;
+    type VoteLockingPeriod = ConstU32<{ 10 * MINUTES }>;
+}
+
+impl pallet_referenda::Config for Runtime {
+    type WeightInfo = pallet_referenda::weights::SubstrateWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type Currency = Balances;
+    type SubmitOrigin = EnsureSigned<AccountId>;
+    type CancelOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+    type KillOrigin = EnsureRootOrAllTechnicalCommittee;
+    type Slash = Treasury;
+    type Votes = pallet_conviction_voting::VotesOf<Runtime>;
+    type Tally = pallet_conviction_voting::TallyOf<Runtime>;
+    type SubmissionDeposit = ConstU128<{ 10 * AST }>;
+    type MaxQueued = ConstU32<100>;
+    type UndecidingTimeout = ConstU32<{ 20 * MINUTES }>;
+    type AlarmInterval = ConstU32<1>;
+    type Tracks = governance::TracksInfo;
+    type Preimages = Preimage;
+}
+
+impl pallet_whitelist::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    /// A two third majority of the Technical Committee can whitelist a call, which can then be
+    /// dispatched through the whitelisted caller track.
+    type WhitelistOrigin = EnsureRootOrTwoThirdsTechnicalCommittee;
+    type DispatchWhitelistedOrigin = EitherOf<EnsureRoot<AccountId>, WhitelistedCaller>;
+    type Preimages = Preimage;
+    type WeightInfo = pallet_whitelist::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_custom_origins::Config for Runtime {
+    type RuntimeUpgradeOrigin = governance::RuntimeUpgrader;
+}
+
+impl pallet_emergency_origin::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type PalletsOrigin = OriginCaller;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    /// A two third majority of the Technical Committee can schedule a call to be dispatched as root,
+    /// replacing the sudo key once it's removed via `Sudo::remove_key` by a root track referendum.
+    type EmergencyOrigin = pallet_collective::EnsureProportionAtLeast<
+        AccountId,
+        TechnicalCommitteeCollectiveInst,
+        2,
+        3,
+    >;
+    /// A two third majority of the Council can cancel a scheduled emergency call.
+    type CancelOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type EmergencyDelay = ConstU32<{ 10 * MINUTES }>;
+    type WeightInfo = pallet_emergency_origin::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_asset_rate::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// A two third majority of the Council manages the price table used to value treasury
+    /// asset spends in native currency.
+    type CreateOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type RemoveOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type UpdateOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type Currency = Balances;
+    type AssetKind = TreasuryAssetKind<AssetId>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = astar_primitives::benchmarks::TreasuryBenchmarkHelper;
+    type WeightInfo = pallet_asset_rate::weights::SubstrateWeight<Runtime>;
+}
+
 parameter_types! {
     pub const ProposalBond: Permill = Permill::from_percent(5);
     pub MainTreasuryAccount: AccountId = Treasury::account_id();
+    pub const MaxBalance: Balance = Balance::MAX;
+    pub const MaxTreasurySpend: Balance = governance::origins::MAX_TREASURY_SPEND;
+    pub const MaxCouncilSpend: Balance = 10_000 * AST;
+    pub const MaxCommunityCouncilSpend: Balance = 5_000 * AST;
 }
 
 impl pallet_treasury::Config<MainTreasuryInst> for Runtime {
@@ -999,18 +1244,23 @@ impl pallet_treasury::Config<MainTreasuryInst> for Runtime {
     type SpendFunds = ();
 
     type MaxApprovals = ConstU32<64>;
-    type AssetKind = (); // Only native asset is supported
+    type AssetKind = TreasuryAssetKind<AssetId>;
     type Beneficiary = AccountId;
     type BeneficiaryLookup = IdentityLookup<Self::Beneficiary>;
-    type Paymaster = PayFromAccount<Balances, MainTreasuryAccount>;
-    type BalanceConverter = UnityAssetBalanceConversion;
-
-    // New approach to using treasury, useful for OpenGov but not necessarily for us.
-    type SpendOrigin = frame_support::traits::NeverEnsureOrigin<Balance>;
-    // Only used by 'spend' approach which is disabled
-    type PayoutPeriod = ConstU32<0>;
+    type Paymaster = PayFromAccountOrAsset<Balances, Assets, MainTreasuryAccount>;
+    type BalanceConverter = NativeOrAssetBalanceConversion<AssetRate>;
+
+    // Maximum native value of a single spend, depending on the origin:
+    // - root is unlimited,
+    // - the treasury spender referenda track can spend up to `MaxTreasurySpend`,
+    // - a two third majority of the Council can spend up to `MaxCouncilSpend`.
+    type SpendOrigin = EitherOf<
+        EitherOf<EnsureRootWithSuccess<AccountId, MaxBalance>, TreasurySpender>,
+        EnsureWithSuccess<EnsureRootOrTwoThirdsMainCouncil, AccountId, MaxCouncilSpend>,
+    >;
+    type PayoutPeriod = ConstU32<{ 30 * MINUTES }>;
     #[cfg(feature = "runtime-benchmarks")]
-    type BenchmarkHelper = ();
+    type BenchmarkHelper = astar_primitives::benchmarks::TreasuryBenchmarkHelper;
     type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1039,18 +1289,26 @@ impl pallet_treasury::Config<CommunityTreasuryInst> for Runtime {
     type SpendFunds = ();
 
     type MaxApprovals = ConstU32<64>;
-    type AssetKind = (); // Only native asset is supported
+    type AssetKind = TreasuryAssetKind<AssetId>;
     type Beneficiary = AccountId;
     type BeneficiaryLookup = IdentityLookup<Self::Beneficiary>;
-    type Paymaster = PayFromAccount<Balances, MainTreasuryAccount>;
-    type BalanceConverter = UnityAssetBalanceConversion;
-
-    // New approach to using treasury, useful for OpenGov but not necessarily for us.
-    type SpendOrigin = frame_support::traits::NeverEnsureOrigin<Balance>;
-    // Only used by 'spend' approach which is disabled
-    type PayoutPeriod = ConstU32<0>;
+    type Paymaster = PayFromAccountOrAsset<Balances, Assets, CommunityTreasuryAccountId>;
+    type BalanceConverter = NativeOrAssetBalanceConversion<AssetRate>;
+
+    // Maximum native value of a single spend, depending on the origin:
+    // - root is unlimited,
+    // - a two third majority of the Community Council can spend up to `MaxCommunityCouncilSpend`.
+    type SpendOrigin = EitherOf<
+        EnsureRootWithSuccess<AccountId, MaxBalance>,
+        EnsureWithSuccess<
+            EnsureRootOrTwoThirdsCommunityCouncil,
+            AccountId,
+            MaxCommunityCouncilSpend,
+        >,
+    >;
+    type PayoutPeriod = ConstU32<{ 30 * MINUTES }>;
     #[cfg(feature = "runtime-benchmarks")]
-    type BenchmarkHelper = ();
+    type BenchmarkHelper = astar_primitives::benchmarks::TreasuryBenchmarkHelper;
     type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1058,25 +1316,22 @@ parameter_types! {
     pub CommunityTreasuryAccountId: AccountId = CommunityTreasuryPalletId::get().into_account_truncating();
 }
 
-#[derive(Default)]
-pub struct CommunityCouncilCallFilter;
-impl InstanceFilter<RuntimeCall> for CommunityCouncilCallFilter {
-    fn filter(&self, c: &RuntimeCall) -> bool {
-        matches!(
-            c,
-             RuntimeCall::System(frame_system::Call::remark { .. })
-                | RuntimeCall::Utility(..)
-        )
-    }
-}
-
 impl pallet_collective_proxy::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
-    type CollectiveProxy = EnsureRootOrTwoThirdsCommunityCouncil;
-    type ProxyAccountId = CommunityTreasuryAccountId;
-    type CallFilter = CommunityCouncilCallFilter;
+    type AliasOrigin = collective_proxy::CollectiveProxyOrigin;
+    type ProxyOrigin = collective_proxy::EnsureCollectiveProxyOrigin;
+    type CallFilter = collective_proxy::CollectiveProxyCallFilter;
+    type AssetKind = TreasuryAssetKind<AssetId>;
+    type Balance = Balance;
+    type TransferInspector = collective_proxy::CollectiveProxyTransfers;
+    type MaxSpendingCaps = ConstU32<4>;
+    type ContractCallInspector = collective_proxy::CollectiveProxyContractCalls;
+    type ContractCallManager = EnsureRootOrTwoThirdsMainCouncil;
+    type AliasManager = EnsureRootOrTwoThirdsMainCouncil;
     type WeightInfo = pallet_collective_proxy::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = collective_proxy::CollectiveProxyBenchmarkHelper;
 }
 
 construct_runtime!(
@@ -1091,6 +1346,7 @@ construct_runtime!(
         TransactionPayment: pallet_transaction_payment = 30,
         Balances: pallet_balances = 31,
         Vesting: pallet_vesting = 32,
+        FeeDistribution: pallet_fee_distribution = 33,
         Inflation: pallet_inflation = 35,
         Assets: pallet_assets = 36,
 
@@ -1119,7 +1375,14 @@ construct_runtime!(
         Treasury: pallet_treasury::<Instance1> = 107,
         CommunityTreasury: pallet_treasury::<Instance2> = 108,
         CollectiveProxy: pallet_collective_proxy = 109,
-        UomiEngine: pallet_uomi_engine = 110
+        UomiEngine: pallet_uomi_engine = 110,
+        ConvictionVoting: pallet_conviction_voting = 111,
+        Referenda: pallet_referenda = 112,
+        Whitelist: pallet_whitelist = 113,
+        Origins: pallet_custom_origins::{Pallet, Call, Origin} = 114,
+        EmergencyOrigin: pallet_emergency_origin = 115,
+        AssetRate: pallet_asset_rate = 116,
+        SignedTransactMembership: pallet_membership::<Instance5> = 118,
     }
 );
 
@@ -1158,7 +1421,12 @@ pub type Executive = frame_executive::Executive<
     Migrations,
 >;
 
-pub type Migrations = ();
+/// All migrations that will run on the next runtime upgrade.
+pub type Migrations = (
+    pallet_unified_accounts::migration::HoldStorageDeposits<Runtime>,
+    governance::MigrateDemocracyProposals,
+    treasury::FundCommunityTreasurySpends,
+);
 
 type EventRecord = frame_system::EventRecord<
     <Runtime as frame_system::Config>::RuntimeEvent,
@@ -1239,6 +1507,7 @@ mod benches {
         [pallet_inflation, Inflation]
         [pallet_dynamic_evm_base_fee, DynamicEvmBaseFee]
         [pallet_uomi_engine, TemplateModule]
+        [pallet_emergency_origin, EmergencyOrigin]
     );
 }
 
@@ -1726,6 +1995,102 @@ impl_runtime_apis! {
         }
     }
 
+    impl xvm_runtime_api::XvmApi<Block, AccountId> for Runtime {
+        fn dry_run(
+            source_vm_id: astar_primitives::xvm::VmId,
+            target_vm_id: astar_primitives::xvm::VmId,
+            source: AccountId,
+            target: Vec<u8>,
+            input: Vec<u8>,
+            value: Balance,
+            weight_limit: Option<Weight>,
+            storage_deposit_limit: Option<Balance>,
+            access_list: Option<astar_primitives::ethereum_checked::AccessList>,
+        ) -> astar_primitives::xvm::CallResult {
+            let context = astar_primitives::xvm::Context {
+                source_vm_id,
+                weight_limit: weight_limit.unwrap_or(RuntimeBlockWeights::get().max_block),
+            };
+            Xvm::dry_run(
+                context,
+                target_vm_id,
+                source,
+                target,
+                input,
+                value,
+                storage_deposit_limit,
+                access_list,
+            )
+        }
+    }
+
+    impl dynamic_evm_base_fee_runtime_api::DynamicEvmBaseFeeApi<Block> for Runtime {
+        fn base_fee_info() -> dynamic_evm_base_fee_runtime_api::BaseFeeInfo {
+            let (min_next_base_fee_per_gas, max_next_base_fee_per_gas) =
+                DynamicEvmBaseFee::next_base_fee_per_gas_bounds();
+
+            dynamic_evm_base_fee_runtime_api::BaseFeeInfo {
+                base_fee_per_gas: pallet_dynamic_evm_base_fee::BaseFeePerGas::<Runtime>::get(),
+                next_base_fee_per_gas: DynamicEvmBaseFee::predicted_next_base_fee_per_gas(),
+                min_next_base_fee_per_gas,
+                max_next_base_fee_per_gas,
+            }
+        }
+    }
+
+    impl inflation_runtime_api::InflationApi<Block> for Runtime {
+        fn current_era_info() -> pallet_inflation::EraInfo {
+            pallet_inflation::CurrentEraInfo::<Runtime>::get()
+        }
+
+        fn upcoming_inflation_rates(
+            count: u32,
+        ) -> Vec<(pallet_inflation::CycleNumber, Perquintill)> {
+            Inflation::upcoming_inflation_rates(count)
+        }
+
+        fn inflation_info() -> inflation_runtime_api::InflationInfo {
+            let era_info = pallet_inflation::CurrentEraInfo::<Runtime>::get();
+
+            inflation_runtime_api::InflationInfo {
+                config: pallet_inflation::ActiveInflationConfig::<Runtime>::get(),
+                params: pallet_inflation::InflationParams::<Runtime>::get(),
+                era_info,
+                cycle: Inflation::cycle_of_era(era_info.era),
+                blocks_until_recalculation: Inflation::blocks_until_recalculation(),
+            }
+        }
+
+        fn projected_issuance(count: u32) -> Vec<(pallet_inflation::CycleNumber, Balance)> {
+            Inflation::projected_issuance(count)
+        }
+    }
+
+    impl unified_accounts_runtime_api::UnifiedAccountsApi<Block, AccountId, BlockNumber> for Runtime {
+        fn claim_typed_data(account_id: AccountId) -> Vec<u8> {
+            UnifiedAccounts::build_claim_typed_data(&account_id)
+        }
+
+        fn verify_claim_signature(
+            account_id: AccountId,
+            signature: [u8; 65],
+        ) -> Option<astar_primitives::evm::EvmAddress> {
+            UnifiedAccounts::verify_signature(&account_id, &signature)
+        }
+
+        fn account_mapping(
+            account_id: AccountId,
+        ) -> unified_accounts_runtime_api::AccountMapping<BlockNumber> {
+            unified_accounts_runtime_api::AccountMapping {
+                evm_address: pallet_unified_accounts::NativeToEvm::<Runtime>::get(&account_id),
+                default_evm_address: UnifiedAccounts::to_default_h160(&account_id),
+                unlink_announced_at: pallet_unified_accounts::PendingUnlinks::<Runtime>::get(
+                    &account_id,
+                ),
+            }
+        }
+    }
+
 
     impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
         fn create_default_config() -> Vec<u8> {
@@ -0,0 +1,82 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests of the runtime wiring which doesn't belong to a runtime module.
+
+use crate::{
+    mock::new_test_ext, AccountId, AuraId, Balances, FeeDistribution, InflationPayoutPerBlock,
+    Runtime, System, TreasuryPalletId, AST,
+};
+use frame_support::traits::{Currency, Get, OnUnbalanced};
+use pallet_inflation::PayoutPerBlock;
+use parity_scale_codec::Encode;
+use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+use sp_runtime::{traits::AccountIdConversion, BuildStorage, DigestItem};
+
+const AUTHOR: AccountId = AccountId::new([7; 32]);
+
+/// Externalities where the Aura key of `AUTHOR` is the only authority, and authored the block.
+fn new_test_ext_with_author() -> sp_io::TestExternalities {
+    let mut ext = new_test_ext(vec![]);
+    let aura = pallet_aura::GenesisConfig::<Runtime> {
+        authorities: vec![AuraId::from(sp_core::sr25519::Public::from_raw([7; 32]))],
+    }
+    .build_storage()
+    .unwrap();
+    for (key, value) in aura.top {
+        ext.insert(key, value);
+    }
+
+    ext.execute_with(|| {
+        System::deposit_log(DigestItem::PreRuntime(
+            AURA_ENGINE_ID,
+            Slot::from(0).encode(),
+        ))
+    });
+    ext
+}
+
+#[test]
+fn tips_are_paid_to_the_block_author() {
+    new_test_ext_with_author().execute_with(|| {
+        let fees = Balances::issue(10 * AST);
+        let tips = Balances::issue(AST);
+        FeeDistribution::on_unbalanceds(vec![fees, tips].into_iter());
+
+        assert_eq!(Balances::free_balance(AUTHOR), AST);
+    });
+}
+
+#[test]
+fn collator_rewards_are_paid_to_the_block_author() {
+    new_test_ext_with_author().execute_with(|| {
+        InflationPayoutPerBlock::collators(Balances::issue(AST));
+
+        assert_eq!(Balances::free_balance(AUTHOR), AST);
+    });
+}
+
+#[test]
+fn collator_rewards_go_to_treasury_without_author() {
+    new_test_ext(vec![]).execute_with(|| {
+        InflationPayoutPerBlock::collators(Balances::issue(AST));
+
+        let treasury: AccountId = TreasuryPalletId::get().into_account_truncating();
+        assert_eq!(Balances::free_balance(treasury), AST);
+    });
+}
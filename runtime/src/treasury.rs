@@ -0,0 +1,508 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Treasury helpers: invariant check that approved spends are backed by the paying pot, and the
+//! migration moving community treasury payouts to the community treasury account, which runs the
+//! check in its `post_upgrade`.
+
+use crate::{
+    AccountId, AssetId, Assets, Balance, Balances, BlockNumber, CommunityTreasuryAccountId,
+    MainTreasuryAccount, Runtime, System,
+};
+use astar_primitives::{governance::CommunityTreasuryInst, treasury::TreasuryAssetKind};
+use frame_support::{
+    storage::migration::storage_key_iter,
+    traits::{
+        fungible, fungibles,
+        tokens::{Fortitude, Preservation},
+        Get, OnRuntimeUpgrade, PalletInfoAccess,
+    },
+    weights::{constants::RocksDbWeight, Weight},
+    Twox64Concat,
+};
+use sp_runtime::DispatchError;
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+
+#[cfg(feature = "try-runtime")]
+use astar_primitives::governance::MainTreasuryInst;
+
+/// `PaymentState::Attempted` variant index, i.e. the spend has been paid out.
+const PAYMENT_ATTEMPTED: u8 = 1;
+
+/// Encoding of `pallet_treasury::SpendStatus`, whose fields aren't public:
+/// `(asset_kind, amount, beneficiary, valid_from, expire_at, status)`.
+///
+/// Matches `pallet_treasury` of polkadot-sdk `release-polkadot-v1.9.0`, the layout must be
+/// checked again when upgrading it.
+///
+/// The payment id of `PayFromAccountOrAsset` is `()`, so the status is encoded as its variant index.
+type RawSpendStatus = (
+    TreasuryAssetKind<AssetId>,
+    Balance,
+    AccountId,
+    BlockNumber,
+    BlockNumber,
+    u8,
+);
+
+/// Amounts of the spends approved by treasury instance `I` which haven't been paid out yet and
+/// haven't expired, summed per asset kind.
+pub fn unpaid_spends<I: 'static>() -> BTreeMap<TreasuryAssetKind<AssetId>, Balance>
+where
+    Runtime: pallet_treasury::Config<I>,
+{
+    let now = System::block_number();
+    let pallet_name = <pallet_treasury::Pallet<Runtime, I> as PalletInfoAccess>::name();
+
+    storage_key_iter::<pallet_treasury::SpendIndex, RawSpendStatus, Twox64Concat>(
+        pallet_name.as_bytes(),
+        b"Spends",
+    )
+    // `status` is only the variant index of `PaymentState` because the payment id is `()`, see
+    // `RawSpendStatus`; the layout is pinned by `raw_spend_status_matches_spends_encoding`.
+    .filter(|(_, (_, _, _, _, expire_at, status))| *status != PAYMENT_ATTEMPTED && *expire_at > now)
+    .fold(
+        BTreeMap::new(),
+        |mut unpaid, (_, (asset_kind, amount, ..))| {
+            let total: &mut Balance = unpaid.entry(asset_kind).or_default();
+            *total = total.saturating_add(amount);
+            unpaid
+        },
+    )
+}
+
+/// Amount of `asset_kind` which can be paid out from `pot`.
+fn available(pot: &AccountId, asset_kind: TreasuryAssetKind<AssetId>) -> Balance {
+    match asset_kind {
+        TreasuryAssetKind::Native => <Balances as fungible::Inspect<_>>::reducible_balance(
+            pot,
+            Preservation::Expendable,
+            Fortitude::Polite,
+        ),
+        TreasuryAssetKind::Asset(asset_id) => <Assets as fungibles::Inspect<_>>::reducible_balance(
+            asset_id,
+            pot,
+            Preservation::Expendable,
+            Fortitude::Polite,
+        ),
+    }
+}
+
+/// Ensure the unpaid spends approved by treasury instance `I` are backed by the funds of `pot`,
+/// the account its paymaster pays from.
+pub fn ensure_spends_are_backed<I: 'static>(pot: &AccountId) -> Result<(), DispatchError>
+where
+    Runtime: pallet_treasury::Config<I>,
+{
+    for (asset_kind, amount) in unpaid_spends::<I>() {
+        frame_support::ensure!(
+            available(pot, asset_kind) >= amount,
+            "Approved treasury spends are not backed by the treasury pot"
+        );
+    }
+    Ok(())
+}
+
+/// Community treasury spends used to be paid out from the main treasury account.
+///
+/// Since they are now paid out from the community treasury account, the main treasury transfers
+/// whatever the community treasury lacks to pay out the spends it already approved.
+pub struct FundCommunityTreasurySpends;
+impl OnRuntimeUpgrade for FundCommunityTreasurySpends {
+    fn on_runtime_upgrade() -> Weight {
+        let unpaid = unpaid_spends::<CommunityTreasuryInst>();
+        let community_pot = CommunityTreasuryAccountId::get();
+        let main_pot = MainTreasuryAccount::get();
+        let mut weight = RocksDbWeight::get().reads(1 + unpaid.len() as u64);
+
+        for (asset_kind, amount) in unpaid {
+            let shortfall = amount.saturating_sub(available(&community_pot, asset_kind));
+            if shortfall == 0 {
+                continue;
+            }
+
+            let result = match asset_kind {
+                TreasuryAssetKind::Native => <Balances as fungible::Mutate<_>>::transfer(
+                    &main_pot,
+                    &community_pot,
+                    shortfall,
+                    Preservation::Preserve,
+                ),
+                TreasuryAssetKind::Asset(asset_id) => <Assets as fungibles::Mutate<_>>::transfer(
+                    asset_id,
+                    &main_pot,
+                    &community_pot,
+                    shortfall,
+                    Preservation::Preserve,
+                ),
+            };
+            if let Err(e) = result {
+                log::error!("Failed to fund community treasury spends of {asset_kind:?}: {e:?}");
+            }
+            weight.saturating_accrue(RocksDbWeight::get().reads_writes(2, 2));
+        }
+
+        weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        ensure_spends_are_backed::<MainTreasuryInst>(&MainTreasuryAccount::get())?;
+        ensure_spends_are_backed::<CommunityTreasuryInst>(&CommunityTreasuryAccountId::get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        governance::pallet_custom_origins::{self, MAX_TREASURY_SPEND},
+        mock::new_test_ext,
+        AssetRate, CommunityTreasury, MaxCommunityCouncilSpend, MaxCouncilSpend, RuntimeOrigin,
+        Treasury, AST,
+    };
+    use astar_primitives::governance::{
+        CommunityCouncilCollectiveInst, MainCouncilCollectiveInst, MainTreasuryInst,
+    };
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{
+            fungible::Inspect,
+            fungibles::{Create, Mutate},
+        },
+    };
+    use parity_scale_codec::{DecodeAll, Encode};
+    use sp_runtime::{traits::BadOrigin, DispatchResult, FixedU128};
+
+    const BENEFICIARY: AccountId = AccountId::new([2; 32]);
+    const INIT_POT: Balance = 1_000 * AST;
+    const ASSET_ID: AssetId = 1;
+
+    fn endowed_accounts() -> Vec<(AccountId, Balance)> {
+        vec![
+            (MainTreasuryAccount::get(), INIT_POT),
+            (CommunityTreasuryAccountId::get(), INIT_POT),
+        ]
+    }
+
+    fn spend_native(community: bool, amount: Balance) {
+        let asset_kind = Box::new(TreasuryAssetKind::Native);
+        let beneficiary = Box::new(BENEFICIARY);
+        if community {
+            assert_ok!(CommunityTreasury::spend(
+                RuntimeOrigin::root(),
+                asset_kind,
+                amount,
+                beneficiary,
+                None
+            ));
+        } else {
+            assert_ok!(Treasury::spend(
+                RuntimeOrigin::root(),
+                asset_kind,
+                amount,
+                beneficiary,
+                None
+            ));
+        }
+    }
+
+    fn main_council() -> RuntimeOrigin {
+        pallet_collective::RawOrigin::<AccountId, MainCouncilCollectiveInst>::Members(2, 3).into()
+    }
+
+    fn community_council() -> RuntimeOrigin {
+        pallet_collective::RawOrigin::<AccountId, CommunityCouncilCollectiveInst>::Members(2, 3)
+            .into()
+    }
+
+    fn treasury_spender() -> RuntimeOrigin {
+        pallet_custom_origins::Origin::TreasurySpender.into()
+    }
+
+    fn main_spend(
+        origin: RuntimeOrigin,
+        asset_kind: TreasuryAssetKind<AssetId>,
+        amount: Balance,
+    ) -> DispatchResult {
+        Treasury::spend(
+            origin,
+            Box::new(asset_kind),
+            amount,
+            Box::new(BENEFICIARY),
+            None,
+        )
+    }
+
+    fn community_spend(origin: RuntimeOrigin, amount: Balance) -> DispatchResult {
+        CommunityTreasury::spend(
+            origin,
+            Box::new(TreasuryAssetKind::Native),
+            amount,
+            Box::new(BENEFICIARY),
+            None,
+        )
+    }
+
+    /// Create the asset and fund the main treasury with `amount` of it.
+    fn create_treasury_asset(amount: Balance) {
+        assert_ok!(<Assets as Create<AccountId>>::create(
+            ASSET_ID,
+            MainTreasuryAccount::get(),
+            true,
+            1
+        ));
+        assert_ok!(<Assets as Mutate<AccountId>>::mint_into(
+            ASSET_ID,
+            &MainTreasuryAccount::get(),
+            amount
+        ));
+    }
+
+    #[test]
+    fn asset_spend_is_valued_with_its_rate() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            let asset_kind = TreasuryAssetKind::Asset(ASSET_ID);
+            create_treasury_asset(10_000 * AST);
+            // one unit of the asset is worth two units of native currency
+            assert_ok!(AssetRate::create(
+                RuntimeOrigin::root(),
+                Box::new(asset_kind),
+                FixedU128::from_u32(2)
+            ));
+
+            let max_amount = MaxCouncilSpend::get() / 2;
+            assert_noop!(
+                main_spend(main_council(), asset_kind, max_amount + 1),
+                pallet_treasury::Error::<Runtime, MainTreasuryInst>::InsufficientPermission
+            );
+            assert_ok!(main_spend(main_council(), asset_kind, max_amount));
+
+            assert_ok!(Treasury::payout(RuntimeOrigin::signed(BENEFICIARY), 0));
+            assert_eq!(Assets::balance(ASSET_ID, &BENEFICIARY), max_amount);
+            assert_eq!(
+                Assets::balance(ASSET_ID, &MainTreasuryAccount::get()),
+                10_000 * AST - max_amount
+            );
+        });
+    }
+
+    #[test]
+    fn asset_spend_fails_without_rate() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            create_treasury_asset(10_000 * AST);
+
+            assert_noop!(
+                main_spend(
+                    RuntimeOrigin::root(),
+                    TreasuryAssetKind::Asset(ASSET_ID),
+                    AST
+                ),
+                pallet_treasury::Error::<Runtime, MainTreasuryInst>::FailedToConvertBalance
+            );
+        });
+    }
+
+    #[test]
+    fn spends_are_capped_per_origin() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            let native = TreasuryAssetKind::Native;
+
+            // root is unlimited
+            assert_ok!(main_spend(
+                RuntimeOrigin::root(),
+                native,
+                100 * MAX_TREASURY_SPEND
+            ));
+            assert_ok!(community_spend(
+                RuntimeOrigin::root(),
+                100 * MAX_TREASURY_SPEND
+            ));
+
+            // treasury spender track
+            assert_ok!(main_spend(treasury_spender(), native, MAX_TREASURY_SPEND));
+            assert_noop!(
+                main_spend(treasury_spender(), native, MAX_TREASURY_SPEND + 1),
+                pallet_treasury::Error::<Runtime, MainTreasuryInst>::InsufficientPermission
+            );
+
+            // council
+            assert_ok!(main_spend(main_council(), native, MaxCouncilSpend::get()));
+            assert_noop!(
+                main_spend(main_council(), native, MaxCouncilSpend::get() + 1),
+                pallet_treasury::Error::<Runtime, MainTreasuryInst>::InsufficientPermission
+            );
+
+            // community council
+            assert_ok!(community_spend(
+                community_council(),
+                MaxCommunityCouncilSpend::get()
+            ));
+            assert_noop!(
+                community_spend(community_council(), MaxCommunityCouncilSpend::get() + 1),
+                pallet_treasury::Error::<Runtime, CommunityTreasuryInst>::InsufficientPermission
+            );
+
+            // origins can only spend from their own treasury
+            assert_noop!(main_spend(community_council(), native, 1), BadOrigin);
+            assert_noop!(community_spend(main_council(), 1), BadOrigin);
+            assert_noop!(community_spend(treasury_spender(), 1), BadOrigin);
+            assert_noop!(
+                main_spend(RuntimeOrigin::signed(BENEFICIARY), native, 1),
+                BadOrigin
+            );
+        });
+    }
+
+    /// Pins `RawSpendStatus` to the `SpendStatus` encoding of `pallet_treasury` from polkadot-sdk
+    /// `release-polkadot-v1.9.0`.
+    #[test]
+    fn raw_spend_status_matches_spends_encoding() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            let raw_status = || {
+                let encoded = pallet_treasury::Spends::<Runtime, MainTreasuryInst>::get(0)
+                    .unwrap()
+                    .encode();
+                RawSpendStatus::decode_all(&mut &encoded[..]).unwrap()
+            };
+            let payout_period =
+                <Runtime as pallet_treasury::Config<MainTreasuryInst>>::PayoutPeriod::get();
+
+            spend_native(false, 100 * AST);
+            assert_eq!(
+                raw_status(),
+                (
+                    TreasuryAssetKind::Native,
+                    100 * AST,
+                    BENEFICIARY,
+                    1,
+                    1 + payout_period,
+                    0
+                )
+            );
+
+            assert_ok!(Treasury::payout(RuntimeOrigin::signed(BENEFICIARY), 0));
+            assert_eq!(raw_status().5, PAYMENT_ATTEMPTED);
+        });
+    }
+
+    #[test]
+    fn main_treasury_pays_from_its_own_account() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            spend_native(false, 100 * AST);
+            assert_ok!(Treasury::payout(RuntimeOrigin::signed(BENEFICIARY), 0));
+
+            assert_eq!(Balances::balance(&BENEFICIARY), 100 * AST);
+            assert_eq!(
+                Balances::balance(&MainTreasuryAccount::get()),
+                INIT_POT - 100 * AST
+            );
+            assert_eq!(
+                Balances::balance(&CommunityTreasuryAccountId::get()),
+                INIT_POT
+            );
+            assert!(unpaid_spends::<MainTreasuryInst>().is_empty());
+        });
+    }
+
+    #[test]
+    fn community_treasury_pays_from_its_own_account() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            spend_native(true, 100 * AST);
+            assert_ok!(CommunityTreasury::payout(
+                RuntimeOrigin::signed(BENEFICIARY),
+                0
+            ));
+
+            assert_eq!(Balances::balance(&BENEFICIARY), 100 * AST);
+            assert_eq!(Balances::balance(&MainTreasuryAccount::get()), INIT_POT);
+            assert_eq!(
+                Balances::balance(&CommunityTreasuryAccountId::get()),
+                INIT_POT - 100 * AST
+            );
+            assert!(unpaid_spends::<CommunityTreasuryInst>().is_empty());
+        });
+    }
+
+    #[test]
+    fn spends_are_checked_against_own_pot() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            spend_native(false, 600 * AST);
+            spend_native(true, 600 * AST);
+            assert_ok!(ensure_spends_are_backed::<MainTreasuryInst>(
+                &MainTreasuryAccount::get()
+            ));
+            assert_ok!(ensure_spends_are_backed::<CommunityTreasuryInst>(
+                &CommunityTreasuryAccountId::get()
+            ));
+
+            // Each instance's spends are summed & checked against its own pot only.
+            spend_native(false, 600 * AST);
+            assert!(
+                ensure_spends_are_backed::<MainTreasuryInst>(&MainTreasuryAccount::get()).is_err()
+            );
+            assert_ok!(ensure_spends_are_backed::<CommunityTreasuryInst>(
+                &CommunityTreasuryAccountId::get()
+            ));
+
+            spend_native(true, 600 * AST);
+            assert!(ensure_spends_are_backed::<CommunityTreasuryInst>(
+                &CommunityTreasuryAccountId::get()
+            )
+            .is_err());
+        });
+    }
+
+    #[test]
+    fn migration_funds_community_spends_from_main_treasury() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            spend_native(true, 1_500 * AST);
+            assert!(ensure_spends_are_backed::<CommunityTreasuryInst>(
+                &CommunityTreasuryAccountId::get()
+            )
+            .is_err());
+
+            FundCommunityTreasurySpends::on_runtime_upgrade();
+
+            assert_eq!(
+                Balances::balance(&MainTreasuryAccount::get()),
+                INIT_POT - 500 * AST
+            );
+            assert_eq!(
+                Balances::balance(&CommunityTreasuryAccountId::get()),
+                1_500 * AST
+            );
+            assert_ok!(ensure_spends_are_backed::<CommunityTreasuryInst>(
+                &CommunityTreasuryAccountId::get()
+            ));
+
+            // Nothing left to fund.
+            FundCommunityTreasurySpends::on_runtime_upgrade();
+            assert_eq!(
+                Balances::balance(&MainTreasuryAccount::get()),
+                INIT_POT - 500 * AST
+            );
+
+            assert_ok!(CommunityTreasury::payout(
+                RuntimeOrigin::signed(BENEFICIARY),
+                0
+            ));
+            assert_eq!(Balances::balance(&BENEFICIARY), 1_500 * AST);
+        });
+    }
+}
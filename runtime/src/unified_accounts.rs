@@ -0,0 +1,257 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Migration of the default account's assets to the account claiming its evm address.
+
+use crate::{AccountId, AssetId, Assets, Balances, MaxLocks, Runtime, RuntimeHoldReason, Vesting};
+use frame_support::{
+    ensure,
+    traits::{
+        fungible::MutateHold,
+        fungibles::{Inspect, Mutate},
+        tokens::{Fortitude, Precision, Preservation, Restriction},
+        VestingSchedule,
+    },
+    weights::{constants::RocksDbWeight, Weight},
+};
+use pallet_unified_accounts::Error;
+use sp_runtime::DispatchResult;
+
+/// Migrates the XC20 balances, vesting schedules and holds of the default account to the account
+/// claiming its evm address.
+///
+/// The claim is refused while the default account has funds on hold for a reason which can't be
+/// moved, see [`is_movable`].
+pub struct UnifiedAccountsClaimMigrator;
+impl pallet_unified_accounts::ClaimAssetsMigrator<AccountId, AssetId>
+    for UnifiedAccountsClaimMigrator
+{
+    fn migrate(from: &AccountId, to: &AccountId, assets: &[AssetId]) -> DispatchResult {
+        let holds = pallet_balances::Holds::<Runtime>::get(from);
+        ensure!(
+            holds.iter().all(|hold| is_movable(&hold.id)),
+            Error::<Runtime>::FundsOnHold
+        );
+
+        // Check the claiming account can receive all the schedules before moving anything.
+        let schedules = pallet_vesting::Vesting::<Runtime>::get(from).unwrap_or_default();
+        let existing_schedules =
+            pallet_vesting::Vesting::<Runtime>::decode_len(to).unwrap_or_default();
+        ensure!(
+            existing_schedules.saturating_add(schedules.len())
+                <= <Runtime as pallet_vesting::Config>::MAX_VESTING_SCHEDULES as usize,
+            Error::<Runtime>::TooManyVestingSchedules
+        );
+
+        // XC20 balances
+        for asset_id in assets {
+            let balance = <Assets as Inspect<AccountId>>::reducible_balance(
+                *asset_id,
+                from,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            );
+            if balance > 0 {
+                <Assets as Mutate<AccountId>>::transfer(
+                    *asset_id,
+                    from,
+                    to,
+                    balance,
+                    Preservation::Expendable,
+                )?;
+            }
+        }
+
+        // Holds
+        for hold in holds {
+            <Balances as MutateHold<AccountId>>::transfer_on_hold(
+                &hold.id,
+                from,
+                to,
+                hold.amount,
+                Precision::Exact,
+                Restriction::OnHold,
+                Fortitude::Polite,
+            )?;
+        }
+
+        // Vesting schedules are removed to unlock the funds, which are then transferred
+        // with rest of the native balance, and re-created for the claiming account.
+        for index in (0..schedules.len() as u32).rev() {
+            <Vesting as VestingSchedule<AccountId>>::remove_vesting_schedule(from, index)?;
+        }
+        for schedule in schedules {
+            <Vesting as VestingSchedule<AccountId>>::add_vesting_schedule(
+                to,
+                schedule.locked(),
+                schedule.per_block(),
+                schedule.starting_block(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn migrate_weight(assets: u32) -> Weight {
+        use pallet_assets::WeightInfo as _;
+        use pallet_vesting::WeightInfo as _;
+
+        let max_schedules = <Runtime as pallet_vesting::Config>::MAX_VESTING_SCHEDULES;
+        <Runtime as pallet_assets::Config>::WeightInfo::transfer()
+            .saturating_mul(assets.into())
+            // remove & add each schedule
+            .saturating_add(
+                <Runtime as pallet_vesting::Config>::WeightInfo::force_remove_vesting_schedule(
+                    MaxLocks::get(),
+                    max_schedules,
+                )
+                .saturating_mul(2 * max_schedules as u64),
+            )
+            // Holds of the default account & vesting schedules of the claiming account.
+            // Holds are not moved as long as none of the hold reasons is movable.
+            .saturating_add(RocksDbWeight::get().reads(2))
+    }
+}
+
+/// Whether funds held for `reason` can be moved from the default account to the claiming account.
+///
+/// Holds can only be moved if the pallet which placed them doesn't record the holder. The holds
+/// placed by the runtime pallets can't be moved, as their release would still target the default
+/// account and leave the moved funds on hold forever:
+/// - `Contracts`: code upload deposits are released to the code owner, storage deposits are held
+///   by the contract accounts.
+/// - `Preimage`: deposits are released to the account which noted the preimage.
+/// - `UnifiedAccounts`: deposits are released to the account unlinking its mapping.
+///
+/// The match is exhaustive so that holds of new pallets have to be classified.
+fn is_movable(reason: &RuntimeHoldReason) -> bool {
+    match reason {
+        RuntimeHoldReason::Contracts(_)
+        | RuntimeHoldReason::Preimage(_)
+        | RuntimeHoldReason::UnifiedAccounts(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mock::new_test_ext, Balance, AST};
+    use frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::InspectHold, fungibles::Create},
+    };
+    use pallet_unified_accounts::ClaimAssetsMigrator;
+
+    const FROM: AccountId = AccountId::new([1; 32]);
+    const TO: AccountId = AccountId::new([2; 32]);
+    const ASSET_ID: AssetId = 1;
+
+    fn endowed_accounts() -> Vec<(AccountId, Balance)> {
+        vec![(FROM, 1_000 * AST), (TO, 1_000 * AST)]
+    }
+
+    fn add_schedule(who: &AccountId, locked: u128) {
+        assert_ok!(
+            <Vesting as VestingSchedule<AccountId>>::add_vesting_schedule(who, locked, AST, 10)
+        );
+    }
+
+    #[test]
+    fn xc20_balances_are_migrated() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            assert_ok!(<Assets as Create<AccountId>>::create(ASSET_ID, TO, true, 1));
+            assert_ok!(<Assets as Mutate<AccountId>>::mint_into(
+                ASSET_ID, &FROM, 100
+            ));
+
+            assert_ok!(UnifiedAccountsClaimMigrator::migrate(
+                &FROM,
+                &TO,
+                &[ASSET_ID]
+            ));
+
+            assert_eq!(Assets::balance(ASSET_ID, FROM), 0);
+            assert_eq!(Assets::balance(ASSET_ID, TO), 100);
+        });
+    }
+
+    #[test]
+    fn vesting_schedules_are_migrated() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            add_schedule(&FROM, 100 * AST);
+            add_schedule(&TO, 50 * AST);
+
+            assert_ok!(UnifiedAccountsClaimMigrator::migrate(&FROM, &TO, &[]));
+
+            assert!(pallet_vesting::Vesting::<Runtime>::get(FROM).is_none());
+            let locked: Vec<_> = pallet_vesting::Vesting::<Runtime>::get(TO)
+                .unwrap()
+                .iter()
+                .map(|schedule| schedule.locked())
+                .collect();
+            assert_eq!(locked, vec![50 * AST, 100 * AST]);
+            // default account funds are unlocked to be transferred with the native balance
+            assert_eq!(
+                <Vesting as VestingSchedule<AccountId>>::vesting_balance(&FROM),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn migration_fails_if_vesting_schedules_do_not_fit() {
+        new_test_ext(endowed_accounts()).execute_with(|| {
+            let max_schedules = <Runtime as pallet_vesting::Config>::MAX_VESTING_SCHEDULES;
+            for _ in 0..max_schedules {
+                add_schedule(&TO, 10 * AST);
+            }
+            add_schedule(&FROM, 100 * AST);
+
+            assert_noop!(
+                UnifiedAccountsClaimMigrator::migrate(&FROM, &TO, &[]),
+                Error::<Runtime>::TooManyVestingSchedules
+            );
+        });
+    }
+
+    #[test]
+    fn migration_fails_if_funds_are_on_hold_for_unmovable_reason() {
+        let reasons: Vec<RuntimeHoldReason> = vec![
+            pallet_contracts::HoldReason::CodeUploadDepositReserve.into(),
+            pallet_contracts::HoldReason::StorageDepositReserve.into(),
+            pallet_preimage::HoldReason::Preimage.into(),
+            pallet_unified_accounts::HoldReason::AccountMapping.into(),
+        ];
+        for reason in reasons {
+            new_test_ext(endowed_accounts()).execute_with(|| {
+                assert!(!is_movable(&reason));
+                assert_ok!(Balances::hold(&reason, &FROM, 10 * AST));
+
+                assert_noop!(
+                    UnifiedAccountsClaimMigrator::migrate(&FROM, &TO, &[]),
+                    Error::<Runtime>::FundsOnHold
+                );
+
+                // migration works once the hold is released
+                assert_ok!(Balances::release_all(&reason, &FROM, Precision::Exact));
+                assert_ok!(UnifiedAccountsClaimMigrator::migrate(&FROM, &TO, &[]));
+                assert_eq!(Balances::total_balance_on_hold(&TO), 0);
+            });
+        }
+    }
+}